@@ -4,15 +4,15 @@ use std::io::{self, Write};
 fn main() -> Result<(), LinguaError> {
     // Initialize with the default language.
     println!("Initializing i18n...");
-    Lingua::new("examples/basic/languages").init()?;
+    let lingua = Lingua::new("examples/basic/languages").init()?;
 
     // List all available languages.
-    let languages = Lingua::get_languages()?;
+    let languages = lingua.get_languages()?;
     println!("Available languages: {:?}", languages);
-    println!("Current language: {}", Lingua::get_language()?);
+    println!("Current language: {}", lingua.get_language()?);
 
     // Show translations for the current language.
-    show_translations();
+    show_translations(&lingua);
 
     // Language selection loop
     loop {
@@ -30,9 +30,9 @@ fn main() -> Result<(), LinguaError> {
             break;
         }
 
-        if Lingua::set_language(input)? {
+        if lingua.set_language(input)? {
             println!("Language changed to: {}", input);
-            show_translations();
+            show_translations(&lingua);
         } else {
             println!("Language '{}' not available", input);
         }
@@ -43,26 +43,28 @@ fn main() -> Result<(), LinguaError> {
     Ok(())
 }
 
-fn show_translations() {
+fn show_translations(lingua: &Lingua) {
     println!(
         "\n--- Translations in {} ---",
-        Lingua::get_language().expect("Failed to get current language")
+        lingua
+            .get_language()
+            .expect("Failed to get current language")
     );
-    println!("Welcome message: {}", Lingua::t("welcome", &[]).unwrap());
+    println!("Welcome message: {}", lingua.t("welcome", &[]).unwrap());
     println!("File menu:");
-    println!("  Open: {}", Lingua::t("menu.file.open", &[]).unwrap());
-    println!("  Save: {}", Lingua::t("menu.file.save", &[]).unwrap());
-    println!("  Exit: {}", Lingua::t("menu.file.exit", &[]).unwrap());
+    println!("  Open: {}", lingua.t("menu.file.open", &[]).unwrap());
+    println!("  Save: {}", lingua.t("menu.file.save", &[]).unwrap());
+    println!("  Exit: {}", lingua.t("menu.file.exit", &[]).unwrap());
     println!("Edit menu:");
-    println!("  Copy: {}", Lingua::t("menu.edit.copy", &[]).unwrap());
-    println!("  Paste: {}", Lingua::t("menu.edit.paste", &[]).unwrap());
+    println!("  Copy: {}", lingua.t("menu.edit.copy", &[]).unwrap());
+    println!("  Paste: {}", lingua.t("menu.edit.paste", &[]).unwrap());
     println!("With parameters:");
     println!(
         "  Greeting: {}",
-        Lingua::t("greeting", &[("name", "Alice")]).unwrap()
+        lingua.t("greeting", &[("name", "Alice")]).unwrap()
     );
     println!(
         "  Items: {}",
-        Lingua::t("items_count", &[("count", "5")]).unwrap()
+        lingua.t("items_count", &[("count", "5")]).unwrap()
     );
 }
@@ -21,17 +21,17 @@ fn App() -> impl IntoView {
     spawn_local(async move {
         match Lingua::new("/languages")
             .with_languages(vec!["en".to_string(), "de".to_string()])
-            .init()
+            .init_global()
             .await
         {
             Ok(_) => {
                 set_initialized.set(true);
                 
-                Lingua::on_language_change(move |lang| {
+                global::on_language_change(move |lang| {
                     set_current_lang.set(lang.to_string());
                 });
                 
-                if let Ok(lang) = Lingua::get_language() {
+                if let Ok(lang) = global::get_language() {
                     set_current_lang.set(lang);
                 }
             }
@@ -68,11 +68,11 @@ fn LanguageSwitcher(
     set_current_lang: WriteSignal<String>,
 ) -> impl IntoView {
     let languages = move || {
-        Lingua::get_languages().unwrap_or_default()
+        global::get_languages().unwrap_or_default()
     };
     
     let switch_language = move |lang: String| {
-        if let Ok(_) = Lingua::set_language(&lang) {
+        if let Ok(_) = global::set_language(&lang) {
             set_current_lang.set(lang);
         }
     };
@@ -106,22 +106,22 @@ fn LanguageSwitcher(
 fn Translations(current_lang: ReadSignal<String>) -> impl IntoView {
     let welcome = move || {
         let _ = current_lang.get();
-        Lingua::t("welcome", &[]).unwrap_or_else(|_| "Translation not found".to_string())
+        global::t("welcome", &[]).unwrap_or_else(|_| "Translation not found".to_string())
     };
     
     let greeting = move || {
         let _ = current_lang.get();
-        Lingua::t("greeting", &[("name", "Leptos User")]).unwrap_or_else(|_| "Translation not found".to_string())
+        global::t("greeting", &[("name", "Leptos User")]).unwrap_or_else(|_| "Translation not found".to_string())
     };
     
     let save = move || {
         let _ = current_lang.get();
-        Lingua::t("menu.file.save", &[]).unwrap_or_else(|_| "Translation not found".to_string())
+        global::t("menu.file.save", &[]).unwrap_or_else(|_| "Translation not found".to_string())
     };
     
     let open = move || {
         let _ = current_lang.get();
-        Lingua::t("menu.file.open", &[]).unwrap_or_else(|_| "Translation not found".to_string())
+        global::t("menu.file.open", &[]).unwrap_or_else(|_| "Translation not found".to_string())
     };
     
     view! {
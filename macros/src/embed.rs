@@ -0,0 +1,142 @@
+//! Embedding of catalog files, see `include_translations!`.
+use lingua_i18n_rs_shared::CATALOG_EXTENSIONS;
+use quote::quote;
+use std::path::{Path, PathBuf};
+use syn::LitStr;
+
+pub(crate) fn embed(dir: &LitStr) -> syn::Result<proc_macro2::TokenStream> {
+    let manifest_dir = std::env::var("CARGO_MANIFEST_DIR").unwrap_or_else(|_| ".".to_string());
+    let path = PathBuf::from(manifest_dir).join(dir.value());
+    let files = catalog_files(&path).map_err(|message| syn::Error::new(dir.span(), message))?;
+
+    let mut embedded = Vec::new();
+    for (lang_code, extension, file) in files {
+        let file = file.to_string_lossy().into_owned();
+        embedded.push(quote! {
+            ::lingua_i18n_rs::prelude::EmbeddedFile {
+                lang_code: #lang_code,
+                extension: #extension,
+                content: include_bytes!(#file),
+            }
+        });
+    }
+
+    Ok(quote! {
+        &[#(#embedded),*]
+    })
+}
+
+/// Find the catalog files of a language directory, sorted by language and extension.
+///
+/// JSON files are parsed to fail the build on syntax errors. The other formats need the
+/// parsers of `lingua-i18n-rs` and are only checked when they are loaded.
+fn catalog_files(path: &Path) -> Result<Vec<(String, String, PathBuf)>, String> {
+    let entries = std::fs::read_dir(path).map_err(|e| {
+        format!(
+            "Failed to access language directory '{}': {}",
+            path.display(),
+            e
+        )
+    })?;
+
+    let mut files = Vec::new();
+    for entry in entries {
+        let file = entry.map_err(|e| e.to_string())?.path();
+        let (Some(lang_code), Some(extension)) = (
+            file.file_stem().and_then(|stem| stem.to_str()),
+            file.extension().and_then(|ext| ext.to_str()),
+        ) else {
+            continue;
+        };
+        if file.is_file() && CATALOG_EXTENSIONS.contains(&extension) {
+            files.push((lang_code.to_string(), extension.to_string(), file.clone()));
+        }
+    }
+    files.sort();
+
+    if files.is_empty() {
+        return Err(format!("No language files found in '{}'", path.display()));
+    }
+
+    for (_, extension, file) in &files {
+        if extension == "json" {
+            let content = std::fs::read(file)
+                .map_err(|e| format!("Failed to read '{}': {}", file.display(), e))?;
+            serde_json::from_slice::<serde_json::Map<String, serde_json::Value>>(&content)
+                .map_err(|e| format!("Failed to parse language file {}: {}", file.display(), e))?;
+        }
+    }
+    Ok(files)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write_dir(name: &str, files: &[(&str, &str)]) -> PathBuf {
+        let dir = std::env::temp_dir().join(name);
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        for (file, content) in files {
+            std::fs::write(dir.join(file), content).unwrap();
+        }
+        dir
+    }
+
+    #[test]
+    fn test_catalog_files() {
+        let dir = write_dir(
+            "lingua_macros_embed",
+            &[
+                ("en.json", r#"{"hello": "Hello"}"#),
+                ("de.ftl", "hello = Hallo"),
+                ("de.json", "{}"),
+                ("fr.JSON", "not checked"),
+                ("notes.txt", "not a catalog"),
+            ],
+        );
+        let files: Vec<_> = catalog_files(&dir)
+            .unwrap()
+            .into_iter()
+            .map(|(lang_code, extension, _)| format!("{}.{}", lang_code, extension))
+            .collect();
+        assert_eq!(files, ["de.ftl", "de.json", "en.json"]);
+
+        let tokens = embed(&LitStr::new(
+            dir.to_str().unwrap(),
+            proc_macro2::Span::call_site(),
+        ))
+        .unwrap()
+        .to_string();
+        assert!(tokens.contains("lang_code : \"de\" , extension : \"ftl\""));
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_catalog_errors() {
+        let dir = write_dir("lingua_macros_embed_broken", &[("en.json", "{")]);
+        let error = catalog_files(&dir).unwrap_err();
+        assert!(
+            error.starts_with("Failed to parse language file"),
+            "{}",
+            error
+        );
+
+        // Broken catalogs in other formats are left to the runtime.
+        let dir = write_dir("lingua_macros_embed_broken", &[("en.yaml", "menu: [")]);
+        assert_eq!(catalog_files(&dir).unwrap().len(), 1);
+
+        let dir = write_dir("lingua_macros_embed_broken", &[("readme.md", "")]);
+        assert!(
+            catalog_files(&dir)
+                .unwrap_err()
+                .starts_with("No language files")
+        );
+        std::fs::remove_dir_all(&dir).unwrap();
+        assert!(
+            catalog_files(&dir)
+                .unwrap_err()
+                .starts_with("Failed to access")
+        );
+    }
+}
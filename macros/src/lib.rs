@@ -0,0 +1,66 @@
+//! Procedural macros for `lingua-i18n-rs`.
+//!
+//! Use them through the `macros` feature of `lingua-i18n-rs`, which re-exports them.
+use proc_macro::TokenStream;
+use syn::{LitStr, parse_macro_input};
+
+mod embed;
+mod t;
+
+/// Embed every catalog file of a language directory into the binary.
+///
+/// The path is relative to the `Cargo.toml` of the crate using the macro. Only JSON files
+/// are parsed during compilation, so a broken JSON file fails the build; files in the other
+/// formats are validated when they are loaded. The macro expands to a
+/// `&'static [EmbeddedFile]` for `LinguaBuilder::with_embedded`.
+///
+/// Files added to or removed from the directory are only picked up when the crate is
+/// rebuilt; changes to existing files trigger a rebuild automatically.
+///
+/// ```rust,ignore
+/// let lingua = Lingua::new("languages")
+///     .with_embedded(include_translations!("languages"))
+///     .init()?;
+/// ```
+#[proc_macro]
+pub fn include_translations(input: TokenStream) -> TokenStream {
+    let dir = parse_macro_input!(input as LitStr);
+    match embed::embed(&dir) {
+        Ok(tokens) => tokens.into(),
+        Err(error) => compile_error(error),
+    }
+}
+
+/// Translate a key whose existence and parameters are checked at compile time.
+///
+/// The key is looked up in the reference catalog `<dir>/<lang>.json`, where `dir` is
+/// taken from the `LINGUA_I18N_DIR` environment variable (default `languages`, relative
+/// to `Cargo.toml`) and `lang` from `LINGUA_I18N_REFERENCE` (default `en`); set them in
+/// `.cargo/config.toml` under `[env]`. A missing key, a key naming a group of keys
+/// instead of a message, a missing `{{...}}` placeholder parameter or an unknown
+/// parameter is a compiler error with a "did you mean" suggestion. Plural and select
+/// objects require their `$plural` or `$ordinal` (default `count`) and `$select`
+/// parameters. For catalogs using `MessageFormat::Icu`, set `LINGUA_I18N_MESSAGE_FORMAT`
+/// to `icu` so that ICU arguments like `{name}` are checked as well.
+///
+/// Without an instance the global instance is used. Parameter values can be anything
+/// implementing `ToString`. The macro evaluates to `Result<String, LinguaError>`.
+///
+/// ```rust,ignore
+/// let saved = t!("menu.file.saved", name = user.name)?;
+/// let items = t!(lingua, "items_count", count = items.len())?;
+/// ```
+#[proc_macro]
+pub fn t(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as t::Input);
+    match t::expand(input) {
+        Ok(tokens) => tokens.into(),
+        Err(error) => compile_error(error),
+    }
+}
+
+/// Turn errors into `compile_error!` invocations usable in expression position.
+fn compile_error(error: syn::Error) -> TokenStream {
+    let errors = error.to_compile_error();
+    quote::quote!({ #errors }).into()
+}
@@ -0,0 +1,296 @@
+//! Compile-time checked translation calls, see `t!`.
+use lingua_i18n_rs_shared::message::{collect_params, is_message};
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use serde_json::{Map, Value};
+use std::collections::BTreeSet;
+use std::path::PathBuf;
+use syn::parse::{Parse, ParseStream};
+use syn::{Expr, Ident, LitStr, Token};
+
+/// Environment variable naming the language directory, relative to `Cargo.toml`.
+const DIR_VAR: &str = "LINGUA_I18N_DIR";
+/// Environment variable naming the reference language.
+const REFERENCE_VAR: &str = "LINGUA_I18N_REFERENCE";
+/// Environment variable naming the message format of the catalogs, `simple` or `icu`.
+const MESSAGE_FORMAT_VAR: &str = "LINGUA_I18N_MESSAGE_FORMAT";
+
+/// The input of `t!`: an optional instance, the key and named parameters.
+pub(crate) struct Input {
+    instance: Option<Expr>,
+    key: LitStr,
+    params: Vec<(Ident, Expr)>,
+}
+
+impl Parse for Input {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let instance = if input.peek(LitStr) {
+            None
+        } else {
+            let instance = input.parse::<Expr>()?;
+            input.parse::<Token![,]>()?;
+            Some(instance)
+        };
+        let key = input.parse::<LitStr>()?;
+
+        let mut params = Vec::new();
+        while !input.is_empty() {
+            input.parse::<Token![,]>()?;
+            if input.is_empty() {
+                break;
+            }
+            let name = input.parse::<Ident>()?;
+            input.parse::<Token![=]>()?;
+            params.push((name, input.parse::<Expr>()?));
+        }
+
+        Ok(Input {
+            instance,
+            key,
+            params,
+        })
+    }
+}
+
+pub(crate) fn expand(input: Input) -> syn::Result<TokenStream> {
+    let manifest_dir = std::env::var("CARGO_MANIFEST_DIR").unwrap_or_else(|_| ".".to_string());
+    let dir = std::env::var(DIR_VAR).unwrap_or_else(|_| "languages".to_string());
+    let reference = std::env::var(REFERENCE_VAR).unwrap_or_else(|_| "en".to_string());
+    let path = PathBuf::from(manifest_dir)
+        .join(dir)
+        .join(format!("{}.json", reference));
+    let icu = match std::env::var(MESSAGE_FORMAT_VAR).as_deref() {
+        Err(_) | Ok("simple") => false,
+        Ok("icu") => true,
+        Ok(other) => {
+            return Err(syn::Error::new(
+                input.key.span(),
+                format!(
+                    "Unknown message format '{}' in {}; expected 'simple' or 'icu'",
+                    other, MESSAGE_FORMAT_VAR
+                ),
+            ));
+        }
+    };
+
+    let catalog =
+        read_catalog(&path).map_err(|message| syn::Error::new(input.key.span(), message))?;
+    check(&catalog, &input, icu)?;
+
+    let key = &input.key;
+    let names = input.params.iter().map(|(name, _)| name.to_string());
+    let values = input.params.iter().map(|(_, value)| value);
+    let params = quote! {
+        &[#((#names, ::std::string::ToString::to_string(&(#values)).as_str())),*]
+    };
+    let call = match &input.instance {
+        Some(instance) => quote! { (#instance).t(#key, #params) },
+        None => quote! { ::lingua_i18n_rs::global::t(#key, #params) },
+    };
+
+    // Including the reference file makes cargo recompile when it changes.
+    let path = path.to_string_lossy().into_owned();
+    Ok(quote! {
+        {
+            const _: &[u8] = include_bytes!(#path);
+            #call
+        }
+    })
+}
+
+fn read_catalog(path: &PathBuf) -> Result<Map<String, Value>, String> {
+    let content = std::fs::read(path).map_err(|e| {
+        format!(
+            "Failed to read reference language file '{}': {} (set {} and {} to configure it)",
+            path.display(),
+            e,
+            DIR_VAR,
+            REFERENCE_VAR
+        )
+    })?;
+    serde_json::from_slice(&content)
+        .map_err(|e| format!("Failed to parse language file {}: {}", path.display(), e))
+}
+
+/// Verify that the key names a message and that the parameters match its placeholders.
+/// With `icu`, ICU arguments count as placeholders too.
+fn check(catalog: &Map<String, Value>, input: &Input, icu: bool) -> syn::Result<()> {
+    let key = input.key.value();
+    let Some(value) = lookup(catalog, &key) else {
+        let mut keys = Vec::new();
+        collect_keys(catalog, "", &mut keys);
+        let mut message = format!("Translation key '{}' not found", key);
+        if let Some(suggestion) = closest(&key, keys.iter().map(String::as_str)) {
+            message.push_str(&format!("; did you mean '{}'?", suggestion));
+        }
+        return Err(syn::Error::new(input.key.span(), message));
+    };
+
+    if let Value::Object(object) = value
+        && !object.contains_key("$value")
+        && !is_message(object)
+    {
+        return Err(syn::Error::new(
+            input.key.span(),
+            format!("Key '{}' is a group of keys, not a message", key),
+        ));
+    }
+
+    let mut expected = BTreeSet::new();
+    collect_params(value, icu, &mut expected);
+
+    let mut errors: Option<syn::Error> = None;
+    let mut push = |span: Span, message: String| {
+        let error = syn::Error::new(span, message);
+        match &mut errors {
+            Some(errors) => errors.combine(error),
+            None => errors = Some(error),
+        }
+    };
+
+    let given: BTreeSet<String> = input
+        .params
+        .iter()
+        .map(|(name, _)| name.to_string())
+        .collect();
+    for (name, _) in &input.params {
+        let name_str = name.to_string();
+        if !expected.contains(&name_str) {
+            let missing = expected.iter().filter(|p| !given.contains(*p));
+            let mut message = format!("Unknown parameter '{}' for key '{}'", name_str, key);
+            if let Some(suggestion) = closest(&name_str, missing.map(String::as_str)) {
+                message.push_str(&format!("; did you mean '{}'?", suggestion));
+            }
+            push(name.span(), message);
+        }
+    }
+    // The formality defaults to the register configured with `with_formality`.
+    for name in expected
+        .iter()
+        .filter(|name| !given.contains(*name) && *name != "formality")
+    {
+        push(
+            input.key.span(),
+            format!("Missing parameter '{}' for key '{}'", name, key),
+        );
+    }
+
+    match errors {
+        Some(errors) => Err(errors),
+        None => Ok(()),
+    }
+}
+
+/// Look up a key like `Lingua::translate` does: a flat key first, then dot traversal.
+fn lookup<'a>(catalog: &'a Map<String, Value>, key: &str) -> Option<&'a Value> {
+    if let Some(value) = catalog.get(key) {
+        return Some(value);
+    }
+    let mut current = catalog;
+    let mut parts = key.split('.').peekable();
+    while let Some(part) = parts.next() {
+        let value = current.get(part)?;
+        if parts.peek().is_none() {
+            return Some(value);
+        }
+        current = value.as_object()?;
+    }
+    None
+}
+
+fn collect_keys(map: &Map<String, Value>, prefix: &str, keys: &mut Vec<String>) {
+    for (key, value) in map {
+        if key.starts_with('$') {
+            continue;
+        }
+        let path = if prefix.is_empty() {
+            key.clone()
+        } else {
+            format!("{}.{}", prefix, key)
+        };
+        if let Value::Object(children) = value {
+            collect_keys(children, &path, keys);
+        }
+        keys.push(path);
+    }
+}
+
+/// Find the candidate closest to `target`, if any is reasonably close.
+fn closest<'a>(target: &str, candidates: impl Iterator<Item = &'a str>) -> Option<&'a str> {
+    candidates
+        .map(|candidate| (levenshtein(target, candidate), candidate))
+        .filter(|(distance, candidate)| *distance <= target.len().max(candidate.len()) / 3 + 1)
+        .min()
+        .map(|(_, candidate)| candidate)
+}
+
+fn levenshtein(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut previous = row[0];
+        row[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let current = row[j + 1];
+            row[j + 1] = if ca == *cb {
+                previous
+            } else {
+                1 + previous.min(row[j]).min(row[j + 1])
+            };
+            previous = current;
+        }
+    }
+    row[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn check_str(catalog: Value, input: &str, icu: bool) -> Result<(), String> {
+        let input = syn::parse_str::<Input>(input).unwrap();
+        check(catalog.as_object().unwrap(), &input, icu).map_err(|e| e.to_string())
+    }
+
+    #[test]
+    fn test_check() {
+        let catalog = json!({
+            "menu": {"other": "Other", "file": "File"},
+            "items": {"one": "{{count}} item", "other": "{{count}} items"},
+            "greeting": "Hello {name}",
+        });
+        assert_eq!(
+            check_str(catalog.clone(), r#""menu""#, false),
+            Err("Key 'menu' is a group of keys, not a message".to_string())
+        );
+        assert!(check_str(catalog.clone(), r#""menu.other""#, false).is_ok());
+        assert!(check_str(catalog.clone(), r#""items", count = 2"#, false).is_ok());
+        assert_eq!(
+            check_str(catalog.clone(), r#""items""#, false),
+            Err("Missing parameter 'count' for key 'items'".to_string())
+        );
+
+        // ICU arguments are only placeholders in ICU catalogs.
+        assert!(check_str(catalog.clone(), r#""greeting", name = "Ann""#, true).is_ok());
+        assert!(check_str(catalog.clone(), r#""greeting""#, true).is_err());
+        assert!(check_str(catalog, r#""greeting", name = "Ann""#, false).is_err());
+    }
+
+    #[test]
+    fn test_lookup_and_suggestions() {
+        let catalog = json!({"menu": {"file": {"save": "Save"}}, "a.b": "Flat"});
+        let catalog = catalog.as_object().unwrap();
+        assert_eq!(lookup(catalog, "menu.file.save"), Some(&json!("Save")));
+        assert_eq!(lookup(catalog, "a.b"), Some(&json!("Flat")));
+        assert_eq!(lookup(catalog, "menu.file.sve"), None);
+
+        let mut keys = Vec::new();
+        collect_keys(catalog, "", &mut keys);
+        assert_eq!(
+            closest("menu.file.sve", keys.iter().map(String::as_str)),
+            Some("menu.file.save")
+        );
+        assert_eq!(closest("unrelated", keys.iter().map(String::as_str)), None);
+    }
+}
@@ -0,0 +1,14 @@
+//! Catalog conventions shared by `lingua-i18n-rs` and `lingua-i18n-rs-macros`.
+//!
+//! The runtime, the generated keys and the compile-time checks of the macros must agree on
+//! which files are catalogs and which values are messages, so both crates use this one.
+//! It is an implementation detail of `lingua-i18n-rs`; use that crate instead.
+pub mod message;
+
+/// File extensions of the catalog formats `lingua-i18n-rs` can load, in merge order.
+///
+/// Formats behind cargo features of `lingua-i18n-rs` are included whether or not the
+/// features are enabled.
+pub const CATALOG_EXTENSIONS: &[&str] = &[
+    "json", "ftl", "po", "mo", "yaml", "yml", "toml", "xlf", "xliff",
+];
@@ -0,0 +1,234 @@
+//! The shape of catalog values: which objects are messages rather than groups of keys,
+//! and which parameters a message uses.
+//!
+//! The runtime, the generated keys and the `t!` macro all answer both questions here.
+use serde_json::{Map, Value};
+use std::collections::BTreeSet;
+
+/// The CLDR plural categories.
+const PLURAL_CATEGORIES: [&str; 6] = ["zero", "one", "two", "few", "many", "other"];
+
+/// Whether an object is a plural object: plural category and `=N` branches with an
+/// `other` branch, optionally naming its parameter with `$plural` or `$ordinal`.
+pub fn is_plural_object(object: &Map<String, Value>) -> bool {
+    object.contains_key("other")
+        && object.keys().all(|key| {
+            key == "$plural"
+                || key == "$ordinal"
+                || PLURAL_CATEGORIES.contains(&key.as_str())
+                || key
+                    .strip_prefix('=')
+                    .is_some_and(|n| n.parse::<f64>().is_ok())
+        })
+}
+
+/// Whether an object is a single message (plural, select or gettext plural object)
+/// rather than a group of keys.
+pub fn is_message(object: &Map<String, Value>) -> bool {
+    object.contains_key("$select")
+        || object.contains_key("$plural_forms")
+        || is_plural_object(object)
+}
+
+/// Collect the placeholders of a message and the parameters selecting its plural and
+/// select branches.
+///
+/// With `icu`, ICU arguments (`{name}`, `{n, plural, ...}`) are collected as well as
+/// `{{name}}` placeholders.
+pub fn collect_params(value: &Value, icu: bool, params: &mut BTreeSet<String>) {
+    match value {
+        Value::String(text) if icu => collect_icu(text, params),
+        Value::String(text) => collect_placeholders(text, params),
+        Value::Object(object) => {
+            if let Some(param) = object.get("$select").and_then(Value::as_str) {
+                params.insert(param.to_string());
+            }
+            if let Some(param) = object
+                .get("$ordinal")
+                .or_else(|| object.get("$plural"))
+                .and_then(Value::as_str)
+            {
+                params.insert(param.to_string());
+            } else if is_message(object) && !object.contains_key("$select") {
+                params.insert("count".to_string());
+            }
+            for (name, branch) in object {
+                if !name.starts_with('$') || name == "$value" {
+                    collect_params(branch, icu, params);
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Collect the names of `{{name}}` and `{{name, format}}` placeholders.
+fn collect_placeholders(text: &str, params: &mut BTreeSet<String>) {
+    let mut rest = text;
+    while let Some(start) = rest.find("{{") {
+        let Some(end) = rest[start + 2..].find("}}") else {
+            break;
+        };
+        insert_name(&rest[start + 2..start + 2 + end], params);
+        rest = &rest[start + 2 + end + 2..];
+    }
+}
+
+/// Collect the argument names of an ICU message, including those nested in plural and
+/// select branches.
+fn collect_icu(text: &str, params: &mut BTreeSet<String>) {
+    let mut i = 0;
+    while i < text.len() {
+        if let Some(end) = quoted(text, i) {
+            i = end;
+        } else if text[i..].starts_with("{{") {
+            let Some(end) = text[i + 2..].find("}}") else {
+                return;
+            };
+            insert_name(&text[i + 2..i + 2 + end], params);
+            i += end + 4;
+        } else if text[i..].starts_with('{') {
+            let Some(end) = matching_brace(text, i) else {
+                return;
+            };
+            collect_argument(&text[i + 1..end], params);
+            i = end + 1;
+        } else {
+            i += text[i..].chars().next().map_or(1, char::len_utf8);
+        }
+    }
+}
+
+/// Collect the name of an ICU argument (`name, type, style`) and the arguments of its
+/// plural or select branches.
+fn collect_argument(argument: &str, params: &mut BTreeSet<String>) {
+    let mut parts = argument.splitn(3, ',');
+    insert_name(parts.next().unwrap_or_default(), params);
+    let kind = parts.next().unwrap_or_default().trim();
+    if !matches!(kind, "plural" | "selectordinal" | "select") {
+        return;
+    }
+
+    let branches = parts.next().unwrap_or_default();
+    let mut i = 0;
+    while let Some(start) = branches[i..].find('{').map(|start| start + i) {
+        let Some(end) = matching_brace(branches, start) else {
+            return;
+        };
+        collect_icu(&branches[start + 1..end], params);
+        i = end + 1;
+    }
+}
+
+/// Find the brace closing the one at `open`, skipping quoted text.
+fn matching_brace(text: &str, open: usize) -> Option<usize> {
+    let mut depth = 0;
+    let mut i = open;
+    while i < text.len() {
+        if let Some(end) = quoted(text, i) {
+            i = end;
+            continue;
+        }
+        match text.as_bytes()[i] {
+            b'{' => depth += 1,
+            b'}' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(i);
+                }
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+    None
+}
+
+/// Find the end of ICU apostrophe quoting starting at `i`: `''` is a literal apostrophe,
+/// and an apostrophe before `{`, `}`, `#` or `|` quotes up to the next single apostrophe.
+fn quoted(text: &str, i: usize) -> Option<usize> {
+    let rest = text[i..].strip_prefix('\'')?;
+    if rest.starts_with('\'') {
+        return Some(i + 2);
+    }
+    if !rest.starts_with(['{', '}', '#', '|']) {
+        return None;
+    }
+    let mut j = i + 1;
+    loop {
+        match text[j..].find('\'') {
+            Some(offset) if text[j + offset + 1..].starts_with('\'') => j += offset + 2,
+            Some(offset) => return Some(j + offset + 1),
+            None => return Some(text.len()),
+        }
+    }
+}
+
+fn insert_name(placeholder: &str, params: &mut BTreeSet<String>) {
+    let name = placeholder.split(',').next().unwrap_or_default().trim();
+    if !name.is_empty() {
+        params.insert(name.to_string());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn params(value: Value, icu: bool) -> Vec<String> {
+        let mut params = BTreeSet::new();
+        collect_params(&value, icu, &mut params);
+        params.into_iter().collect()
+    }
+
+    #[test]
+    fn test_is_message() {
+        let object = |value: Value| value.as_object().unwrap().clone();
+        assert!(is_message(&object(
+            json!({"one": "A", "=0": "None", "other": "B"})
+        )));
+        assert!(is_message(&object(
+            json!({"$select": "gender", "other": "B"})
+        )));
+        assert!(is_message(&object(
+            json!({"$plural_forms": "n != 1", "0": "A"})
+        )));
+        assert!(!is_message(&object(
+            json!({"other": "Other", "file": "File"})
+        )));
+        assert!(!is_plural_object(&object(json!({"one": "A"}))));
+    }
+
+    #[test]
+    fn test_collect_params() {
+        assert_eq!(
+            params(
+                json!({"$select": "gender", "female": "{{name}} has {{ count, number }}", "other": "Hi"}),
+                false
+            ),
+            ["count", "gender", "name"]
+        );
+        assert_eq!(
+            params(json!({"one": "One", "other": "Items"}), false),
+            ["count"]
+        );
+        assert_eq!(params(json!("Hello {name}"), false), Vec::<String>::new());
+        assert_eq!(
+            params(
+                json!(
+                    "{host} invited {guests, plural, offset:1 =0 {nobody} one {{guest}} other {# people, '{'quoted'}' {{legacy}}}} on {day, date, short}"
+                ),
+                true
+            ),
+            ["day", "guest", "guests", "host", "legacy"]
+        );
+        assert_eq!(
+            params(
+                json!("It''s {n, selectordinal, one {#st} other {#th}}"),
+                true
+            ),
+            ["n"]
+        );
+    }
+}
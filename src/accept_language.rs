@@ -0,0 +1,150 @@
+//! HTTP `Accept-Language` header parsing.
+//!
+//! A header like `de-CH, fr;q=0.8, en;q=0.5, *;q=0.1` lists language ranges with optional
+//! quality values. [`parse`] ranks the ranges by quality and returns them as [`Locale`]s,
+//! ready for [`Lingua::negotiate_language`] or, one at a time, [`Lingua::set_language`].
+//!
+//! The parser is lenient, since headers come from arbitrary clients: malformed entries,
+//! invalid language tags and invalid quality values are skipped instead of rejecting the
+//! whole header. Entries with `q=0` are not acceptable to the client and are dropped, as is
+//! the wildcard `*`, which accepts any language and is served by the default language of a
+//! negotiation. Only the first [`MAX_ENTRIES`] entries are considered.
+//!
+//! [`Lingua::negotiate_language`]: crate::prelude::Lingua::negotiate_language
+//! [`Lingua::set_language`]: crate::prelude::Lingua::set_language
+use crate::locale::Locale;
+
+/// The maximum number of entries of a header that are considered.
+pub const MAX_ENTRIES: usize = 32;
+
+/// Parse an `Accept-Language` header into locales, most preferred first.
+///
+/// Entries with equal quality keep the order of the header, and a repeated locale is
+/// ranked by its highest quality.
+///
+/// # Arguments
+///
+/// * `header` - The value of the header.
+///
+/// # Example
+///
+/// ```rust
+/// use lingua_i18n_rs::accept_language;
+///
+/// let locales = accept_language::parse("fr;q=0.8, de-CH, en;q=0.5, *;q=0.1, es;q=0");
+/// let tags: Vec<String> = locales.iter().map(|locale| locale.to_string()).collect();
+/// assert_eq!(tags, ["de-CH", "fr", "en"]);
+/// ```
+pub fn parse(header: &str) -> Vec<Locale> {
+    parse_weighted(header)
+        .into_iter()
+        .map(|(locale, _)| locale)
+        .collect()
+}
+
+/// Parse an `Accept-Language` header into locales and their quality values, most
+/// preferred first.
+///
+/// # Arguments
+///
+/// * `header` - The value of the header.
+///
+/// # Example
+///
+/// ```rust
+/// use lingua_i18n_rs::accept_language;
+///
+/// let locales = accept_language::parse_weighted("en-US,en;q=0.9");
+/// assert_eq!(locales[1].0.to_string(), "en");
+/// assert_eq!(locales[1].1, 0.9);
+/// ```
+pub fn parse_weighted(header: &str) -> Vec<(Locale, f32)> {
+    let mut ranges: Vec<(Locale, f32)> = Vec::new();
+    for entry in header.split(',').take(MAX_ENTRIES) {
+        let mut parts = entry.split(';');
+        let range = parts.next().unwrap_or_default().trim();
+        if range.is_empty() || range == "*" {
+            continue;
+        }
+        let Some(quality) = quality(parts) else {
+            continue;
+        };
+        let Ok(locale) = Locale::parse(range) else {
+            continue;
+        };
+        if quality <= 0.0 {
+            continue;
+        }
+        match ranges.iter_mut().find(|(existing, _)| *existing == locale) {
+            Some((_, existing)) => *existing = existing.max(quality),
+            None => ranges.push((locale, quality)),
+        }
+    }
+    // A stable sort keeps the header order for equal qualities.
+    ranges.sort_by(|a, b| b.1.total_cmp(&a.1));
+    ranges
+}
+
+/// Find the quality value among the parameters of an entry, `1` if there is none.
+fn quality<'a>(params: impl Iterator<Item = &'a str>) -> Option<f32> {
+    let mut quality = 1.0;
+    for param in params {
+        let (name, value) = param.split_once('=')?;
+        if name.trim().eq_ignore_ascii_case("q") {
+            let value = value.trim();
+            // Quality values have at most three decimals and lie between 0 and 1.
+            let valid = value.len() <= 5
+                && value.bytes().all(|b| b.is_ascii_digit() || b == b'.')
+                && !value.starts_with('.');
+            quality = value
+                .parse::<f32>()
+                .ok()
+                .filter(|q| valid && (0.0..=1.0).contains(q))?;
+        }
+    }
+    Some(quality)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tags(header: &str) -> Vec<String> {
+        parse(header).iter().map(Locale::to_string).collect()
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            tags("de-CH;q=0.9, fr;q=0.8, en;q=0.5"),
+            ["de-CH", "fr", "en"]
+        );
+        assert_eq!(
+            tags(" en-us , EN ;Q=0.7,zh_hant;q=0.7 ,pt-BR;q=1.0"),
+            ["en-US", "pt-BR", "en", "zh-Hant"]
+        );
+        assert_eq!(tags("fr;q=0.5, de;q=0.7, FR;q=0.9"), ["fr", "de"]);
+        assert_eq!(
+            parse_weighted("it;q=0.25")
+                .into_iter()
+                .map(|(locale, q)| (locale.to_string(), q))
+                .collect::<Vec<_>>(),
+            [("it".to_string(), 0.25)]
+        );
+        assert!(parse("").is_empty());
+        assert!(parse("*").is_empty());
+    }
+
+    #[test]
+    fn test_malformed_input() {
+        assert_eq!(
+            tags("de;q=2, fr;q=abc, es;q=, it;q=0.00001, nl;foo, x!;q=1, ,,ja;q=0.5;level=1, en"),
+            ["en", "ja"]
+        );
+        assert_eq!(tags("da;q=.5, sv;q=-1, nb;q=0.000"), Vec::<String>::new());
+
+        // Entries beyond the limit are ignored.
+        let header = vec!["fr"; MAX_ENTRIES].join(",") + ",de";
+        assert_eq!(tags(&header), ["fr"]);
+    }
+}
@@ -0,0 +1,384 @@
+//! Typed translation keys generated from the reference catalog.
+//!
+//! A build script generates a module with one constant per key, nested like the catalog,
+//! and a parameter struct for every key with `{{...}}` placeholders (or ICU arguments, for
+//! catalogs in [`MessageFormat::Icu`]) or plural and select parameters. Removed keys and
+//! parameters then become compile errors:
+//!
+//! ```rust,ignore
+//! // build.rs
+//! use lingua_i18n_rs::prelude::MessageFormat;
+//!
+//! fn main() {
+//!     let out = std::path::Path::new(&std::env::var("OUT_DIR").unwrap()).join("keys.rs");
+//!     lingua_i18n_rs::codegen::write_keys("languages/en.json", out, MessageFormat::Simple)
+//!         .unwrap();
+//! }
+//!
+//! // main.rs
+//! #[allow(dead_code)]
+//! mod keys {
+//!     include!(concat!(env!("OUT_DIR"), "/keys.rs"));
+//! }
+//!
+//! let save = keys::menu::file::SAVE.translate(&lingua, ())?;
+//! let greeting = keys::GREETING.t(keys::GreetingParams { name: "Ann".into() })?;
+//! ```
+use crate::error::LinguaError;
+use crate::format::CatalogFormat;
+use crate::icu::MessageFormat;
+use crate::lingua::Lingua;
+use lingua_i18n_rs_shared::message::{collect_params, is_message};
+use serde_json::{Map, Value};
+use std::collections::{BTreeSet, HashSet};
+use std::marker::PhantomData;
+use std::path::Path;
+
+/// Reserved words that cannot be used as module names.
+const KEYWORDS: &[&str] = &[
+    "as", "async", "await", "break", "const", "continue", "crate", "dyn", "else", "enum", "extern",
+    "false", "fn", "for", "gen", "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut",
+    "pub", "ref", "return", "self", "Self", "static", "struct", "super", "trait", "true", "type",
+    "unsafe", "use", "where", "while", "abstract", "become", "box", "do", "final", "macro",
+    "override", "priv", "try", "typeof", "unsized", "virtual", "yield",
+];
+
+/// The parameters of a translation key.
+pub trait Params {
+    /// Get the parameters as name and value pairs.
+    fn to_params(&self) -> Vec<(&'static str, String)>;
+}
+
+impl Params for () {
+    fn to_params(&self) -> Vec<(&'static str, String)> {
+        Vec::new()
+    }
+}
+
+/// A translation key with its parameter type.
+#[derive(Debug)]
+pub struct Key<P = ()> {
+    key: &'static str,
+    params: PhantomData<fn(P)>,
+}
+
+impl<P> Clone for Key<P> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<P> Copy for Key<P> {}
+
+impl<P: Params> Key<P> {
+    /// Create a key; used by the generated code.
+    pub const fn new(key: &'static str) -> Self {
+        Self {
+            key,
+            params: PhantomData,
+        }
+    }
+
+    /// Get the dot-separated key.
+    pub fn key(&self) -> &'static str {
+        self.key
+    }
+
+    /// Translate the key with a catalog. See [`Lingua::translate`].
+    pub fn translate(&self, lingua: &Lingua, params: P) -> Result<String, LinguaError> {
+        let params = params.to_params();
+        let params: Vec<(&str, &str)> = params
+            .iter()
+            .map(|(name, value)| (*name, value.as_str()))
+            .collect();
+        lingua.translate(self.key, &params)
+    }
+
+    /// Translate the key with the global instance. See [`crate::global::translate`].
+    pub fn t(&self, params: P) -> Result<String, LinguaError> {
+        self.translate(&crate::global::instance(), params)
+    }
+}
+
+/// Generate the typed key module for a reference catalog file and write it to `out`.
+///
+/// The catalog format is chosen by the file extension. Intended for build scripts, so it
+/// also tells cargo to rerun the build script when the reference catalog changes.
+///
+/// # Arguments
+///
+/// * `reference` - The reference language catalog, e.g. `languages/en.json`.
+/// * `out` - The file to write, usually inside `OUT_DIR`.
+/// * `message_format` - The message format the catalogs are loaded with.
+pub fn write_keys(
+    reference: impl AsRef<Path>,
+    out: impl AsRef<Path>,
+    message_format: MessageFormat,
+) -> Result<(), LinguaError> {
+    let reference = reference.as_ref();
+    println!("cargo:rerun-if-changed={}", reference.display());
+
+    let format = reference
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .and_then(CatalogFormat::from_extension)
+        .unwrap_or(CatalogFormat::Json);
+    let content = std::fs::read(reference)
+        .map_err(|_| LinguaError::LanguageFileNotFound(reference.display().to_string()))?;
+    let catalog = format.parse(&reference.display().to_string(), &content)?;
+
+    std::fs::write(out, generate(&catalog, message_format))?;
+    Ok(())
+}
+
+/// Generate the source of the typed key module for a catalog.
+///
+/// With [`MessageFormat::Icu`], ICU arguments like `{name}` are parameters as well as
+/// `{{name}}` placeholders.
+///
+/// # Example
+///
+/// ```rust
+/// use lingua_i18n_rs::codegen;
+/// use lingua_i18n_rs::prelude::MessageFormat;
+///
+/// let catalog = serde_json::json!({"menu": {"file": {"save": "Save"}}, "hi": "Hi {name}"});
+/// let source = codegen::generate(catalog.as_object().unwrap(), MessageFormat::Icu);
+/// assert!(source.contains("pub const SAVE"));
+/// assert!(source.contains("pub struct HiParams"));
+/// ```
+pub fn generate(catalog: &Map<String, Value>, message_format: MessageFormat) -> String {
+    let mut out = String::from("// Generated by lingua_i18n_rs::codegen. Do not edit.\n");
+    let icu = message_format == MessageFormat::Icu;
+    generate_module(catalog, "", 0, icu, &mut out);
+    out
+}
+
+fn generate_module(
+    map: &Map<String, Value>,
+    prefix: &str,
+    depth: usize,
+    icu: bool,
+    out: &mut String,
+) {
+    let pad = "    ".repeat(depth);
+    let mut constants = HashSet::new();
+    let mut modules = HashSet::new();
+
+    for (segment, value) in map {
+        if segment.starts_with('$') {
+            continue;
+        }
+        let key = if prefix.is_empty() {
+            segment.clone()
+        } else {
+            format!("{}.{}", prefix, segment)
+        };
+
+        let children = value
+            .as_object()
+            .filter(|object| !is_message(object))
+            .map(|object| {
+                object
+                    .iter()
+                    .filter(|(name, _)| !name.starts_with('$'))
+                    .count()
+            })
+            .unwrap_or(0);
+        let is_module = children > 0;
+        let has_text = !is_module || value.get("$value").is_some();
+
+        if has_text {
+            let text = value.get("$value").unwrap_or(value);
+            let name = match identifier(segment).to_uppercase() {
+                name if name == "_" => "__".to_string(),
+                name => name,
+            };
+            let name = unique(name, &mut constants);
+            let mut params = BTreeSet::new();
+            collect_params(text, icu, &mut params);
+
+            if params.is_empty() {
+                out.push_str(&format!("{}#[doc = {:?}]\n", pad, doc(&key, text)));
+                out.push_str(&format!(
+                    "{}pub const {}: ::lingua_i18n_rs::codegen::Key = ::lingua_i18n_rs::codegen::Key::new({:?});\n",
+                    pad, name, key
+                ));
+            } else {
+                let params_type = unique(format!("{}Params", camel_case(segment)), &mut constants);
+                write_params(out, &pad, &key, &params_type, &params);
+                out.push_str(&format!("{}#[doc = {:?}]\n", pad, doc(&key, text)));
+                out.push_str(&format!(
+                    "{}pub const {}: ::lingua_i18n_rs::codegen::Key<{}> = ::lingua_i18n_rs::codegen::Key::new({:?});\n",
+                    pad, name, params_type, key
+                ));
+            }
+        }
+
+        if is_module {
+            let name = unique(module_name(segment), &mut modules);
+            out.push_str(&format!("{}pub mod {} {{\n", pad, name));
+            generate_module(
+                value.as_object().expect("modules are objects"),
+                &key,
+                depth + 1,
+                icu,
+                out,
+            );
+            out.push_str(&format!("{}}}\n", pad));
+        }
+    }
+}
+
+fn write_params(out: &mut String, pad: &str, key: &str, name: &str, params: &BTreeSet<String>) {
+    let mut taken = HashSet::new();
+    let fields: Vec<(String, &String)> = params
+        .iter()
+        .map(|param| (unique(module_name(param), &mut taken), param))
+        .collect();
+
+    out.push_str(&format!(
+        "{}#[doc = {:?}]\n",
+        pad,
+        format!("Parameters of `{}`.", key)
+    ));
+    out.push_str(&format!(
+        "{}#[derive(Debug, Clone, Default, PartialEq, Eq)]\n{}pub struct {} {{\n",
+        pad, pad, name
+    ));
+    for (field, _) in &fields {
+        out.push_str(&format!("{}    pub {}: String,\n", pad, field));
+    }
+    out.push_str(&format!("{}}}\n", pad));
+
+    out.push_str(&format!(
+        "{}impl ::lingua_i18n_rs::codegen::Params for {} {{\n{}    fn to_params(&self) -> Vec<(&'static str, String)> {{\n{}        vec![",
+        pad, name, pad, pad
+    ));
+    let pairs: Vec<String> = fields
+        .iter()
+        .map(|(field, param)| format!("({:?}, self.{}.clone())", param, field))
+        .collect();
+    out.push_str(&pairs.join(", "));
+    out.push_str(&format!("]\n{}    }}\n{}}}\n", pad, pad));
+}
+
+/// The doc comment of a key: the key and its reference text.
+fn doc(key: &str, value: &Value) -> String {
+    match value {
+        Value::String(text) => format!(" `{}`: {}", key, text),
+        _ => format!(" `{}`", key),
+    }
+}
+
+/// Turn a key segment into a valid snake case identifier.
+fn identifier(segment: &str) -> String {
+    let mut ident: String = segment
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect();
+    if ident.is_empty() || ident.starts_with(|c: char| c.is_ascii_digit()) {
+        ident.insert(0, '_');
+    }
+    ident
+}
+
+fn module_name(segment: &str) -> String {
+    let ident = identifier(segment).to_lowercase();
+    if ident == "_" {
+        "__".to_string()
+    } else if matches!(ident.as_str(), "self" | "super" | "crate") {
+        // These keywords cannot be raw identifiers.
+        format!("{}_", ident)
+    } else if KEYWORDS.contains(&ident.as_str()) {
+        format!("r#{}", ident)
+    } else {
+        ident
+    }
+}
+
+fn camel_case(segment: &str) -> String {
+    let camel: String = identifier(segment)
+        .split('_')
+        .filter(|part| !part.is_empty())
+        .map(|part| {
+            let mut chars = part.chars();
+            chars
+                .next()
+                .map(|first| first.to_ascii_uppercase().to_string() + chars.as_str())
+                .unwrap_or_default()
+        })
+        .collect();
+    if camel.is_empty() || camel.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("Key{}", camel)
+    } else {
+        camel
+    }
+}
+
+/// Make `name` unique among `taken` by appending a number.
+fn unique(name: String, taken: &mut HashSet<String>) -> String {
+    let mut candidate = name.clone();
+    let mut i = 2;
+    while !taken.insert(candidate.clone()) {
+        candidate = format!("{}_{}", name, i);
+        i += 1;
+    }
+    candidate
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_generate() {
+        let catalog = json!({
+            "greeting": "Hello, {{name}}!",
+            "menu": {"file": {"save": "Save", "type": {"new": "New"}}},
+            "items_count": {"one": "{{count}} item", "other": "{{count}} items"},
+            "$context": {"button": {"Open": "Open"}},
+        });
+        let source = generate(catalog.as_object().unwrap(), MessageFormat::Simple);
+
+        assert!(source.contains(
+            "pub const GREETING: ::lingua_i18n_rs::codegen::Key<GreetingParams> = ::lingua_i18n_rs::codegen::Key::new(\"greeting\");"
+        ));
+        assert!(source.contains("pub struct ItemsCountParams {\n    pub count: String,\n}"));
+        assert!(source.contains("pub mod menu {\n    pub mod file {"));
+        assert!(source.contains("pub mod r#type {"));
+        assert!(source.contains("Key::new(\"menu.file.type.new\")"));
+        assert!(!source.contains("context"));
+
+        let catalog = json!({"self": {"hi": "Hi, {{self}} and {{userName}} or {{username}}"}});
+        let source = generate(catalog.as_object().unwrap(), MessageFormat::Simple);
+        assert!(source.contains("pub mod self_ {"));
+        assert!(source.contains("pub self_: String,"));
+        assert!(source.contains("pub username: String,\n        pub username_2: String,"));
+    }
+
+    #[test]
+    fn test_key_translate() {
+        let lingua = Lingua::default();
+        lingua
+            .load_translations_from_str("en", r#"{"greeting": "Hello, {{name}}!"}"#)
+            .unwrap();
+
+        struct GreetingParams {
+            name: String,
+        }
+        impl Params for GreetingParams {
+            fn to_params(&self) -> Vec<(&'static str, String)> {
+                vec![("name", self.name.clone())]
+            }
+        }
+
+        const GREETING: Key<GreetingParams> = Key::new("greeting");
+        let params = GreetingParams {
+            name: "Ann".to_string(),
+        };
+        assert_eq!(GREETING.key(), "greeting");
+        assert_eq!(GREETING.translate(&lingua, params).unwrap(), "Hello, Ann!");
+    }
+}
@@ -0,0 +1,550 @@
+//! Locale-aware currency formatting.
+//!
+//! Amounts are rounded to the minor unit digits of their ISO 4217 currency and placed in
+//! the CLDR currency pattern of the language, with its number symbols: "$1,234.56",
+//! "1.234,56 €", "€ -1.234,56". Symbols are localized where the language has its own
+//! ("US$" in Spanish, "$" for Canadian dollars in `en-CA`), and a space is inserted between
+//! the number and a symbol or code that ends in a letter ("CHF 12.00"). Common languages
+//! are built in and matched by their full tag first (e.g. `de-CH`), then by their primary
+//! language subtag; unknown languages use the English patterns.
+//!
+//! In translation strings, `{{name, currency:EUR}}` formats an amount, optionally with a
+//! display and sign joined with `-` (`{{name, currency:USD-code}}`,
+//! `{{name, currency:EUR-accounting}}`). ICU messages accept the skeleton
+//! `{name, number, ::currency/EUR}` with the `unit-width-narrow`, `unit-width-iso-code`
+//! and `sign-accounting` options.
+use crate::locale::find_locale;
+use crate::number;
+use std::fmt;
+
+/// How the currency is shown.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CurrencyDisplay {
+    /// The symbol of the currency in the language: `US$`.
+    #[default]
+    Symbol,
+    /// The shortest symbol, which may be ambiguous: `$`.
+    Narrow,
+    /// The ISO 4217 code: `USD`.
+    Code,
+}
+
+impl CurrencyDisplay {
+    /// The keyword used for the display in placeholders.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CurrencyDisplay::Symbol => "symbol",
+            CurrencyDisplay::Narrow => "narrow",
+            CurrencyDisplay::Code => "code",
+        }
+    }
+
+    /// Parse a placeholder keyword.
+    pub fn from_keyword(keyword: &str) -> Option<Self> {
+        match keyword.trim() {
+            "symbol" => Some(CurrencyDisplay::Symbol),
+            "narrow" | "narrowSymbol" => Some(CurrencyDisplay::Narrow),
+            "code" => Some(CurrencyDisplay::Code),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for CurrencyDisplay {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// How negative amounts are shown.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CurrencySign {
+    /// With a minus sign: `-$5.00`.
+    #[default]
+    Standard,
+    /// With the accounting pattern of the language, e.g. in parentheses: `($5.00)`.
+    Accounting,
+}
+
+impl CurrencySign {
+    /// The keyword used for the sign in placeholders.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CurrencySign::Standard => "standard",
+            CurrencySign::Accounting => "accounting",
+        }
+    }
+
+    /// Parse a placeholder keyword.
+    pub fn from_keyword(keyword: &str) -> Option<Self> {
+        match keyword.trim() {
+            "standard" => Some(CurrencySign::Standard),
+            "accounting" => Some(CurrencySign::Accounting),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for CurrencySign {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// ISO 4217 data of a currency.
+struct Currency {
+    code: &'static str,
+    /// Minor unit digits.
+    digits: usize,
+    /// The symbol used where a language has none of its own.
+    symbol: &'static str,
+    narrow: &'static str,
+}
+
+const fn currency(
+    code: &'static str,
+    digits: usize,
+    symbol: &'static str,
+    narrow: &'static str,
+) -> Currency {
+    Currency {
+        code,
+        digits,
+        symbol,
+        narrow,
+    }
+}
+
+/// Known currencies, by code.
+const CURRENCIES: &[Currency] = &[
+    currency("AED", 2, "AED", "AED"),
+    currency("ARS", 2, "ARS", "$"),
+    currency("AUD", 2, "A$", "$"),
+    currency("BHD", 3, "BHD", "BHD"),
+    currency("BRL", 2, "R$", "R$"),
+    currency("CAD", 2, "CA$", "$"),
+    currency("CHF", 2, "CHF", "CHF"),
+    currency("CLP", 0, "CLP", "$"),
+    currency("CNY", 2, "CN¥", "¥"),
+    currency("COP", 2, "COP", "$"),
+    currency("CZK", 2, "CZK", "Kč"),
+    currency("DKK", 2, "DKK", "kr"),
+    currency("EGP", 2, "EGP", "E£"),
+    currency("EUR", 2, "€", "€"),
+    currency("GBP", 2, "£", "£"),
+    currency("HKD", 2, "HK$", "$"),
+    currency("HUF", 2, "HUF", "Ft"),
+    currency("IDR", 2, "IDR", "Rp"),
+    currency("ILS", 2, "₪", "₪"),
+    currency("INR", 2, "₹", "₹"),
+    currency("ISK", 0, "ISK", "kr"),
+    currency("JOD", 3, "JOD", "JOD"),
+    currency("JPY", 0, "¥", "¥"),
+    currency("KRW", 0, "₩", "₩"),
+    currency("KWD", 3, "KWD", "KWD"),
+    currency("MXN", 2, "MX$", "$"),
+    currency("NOK", 2, "NOK", "kr"),
+    currency("NZD", 2, "NZ$", "$"),
+    currency("OMR", 3, "OMR", "OMR"),
+    currency("PHP", 2, "₱", "₱"),
+    currency("PLN", 2, "PLN", "zł"),
+    currency("RUB", 2, "RUB", "₽"),
+    currency("SAR", 2, "SAR", "SAR"),
+    currency("SEK", 2, "SEK", "kr"),
+    currency("SGD", 2, "SGD", "$"),
+    currency("THB", 2, "THB", "฿"),
+    currency("TND", 3, "TND", "TND"),
+    currency("TRY", 2, "TRY", "₺"),
+    currency("TWD", 2, "NT$", "$"),
+    currency("UAH", 2, "UAH", "₴"),
+    currency("USD", 2, "$", "$"),
+    currency("VND", 0, "₫", "₫"),
+    currency("ZAR", 2, "ZAR", "R"),
+];
+
+/// The number of minor unit digits of a currency per ISO 4217; unknown currencies have two.
+///
+/// # Example
+///
+/// ```rust
+/// use lingua_i18n_rs::currency::minor_units;
+///
+/// assert_eq!(minor_units("EUR"), 2);
+/// assert_eq!(minor_units("JPY"), 0);
+/// assert_eq!(minor_units("kwd"), 3);
+/// ```
+pub fn minor_units(code: &str) -> usize {
+    let code = code.trim().to_ascii_uppercase();
+    CURRENCIES
+        .iter()
+        .find(|currency| currency.code == code)
+        .map_or(2, |currency| currency.digits)
+}
+
+/// A positive and a negative pattern, where `¤` is the currency, `#` the number and `-`
+/// the minus sign.
+type Pattern = (&'static str, &'static str);
+
+/// The currency patterns and symbols of a language.
+struct Locale {
+    standard: Pattern,
+    accounting: Pattern,
+    /// Symbols that differ from the default symbol of the currency, by code.
+    symbols: &'static [(&'static str, &'static str)],
+}
+
+const EN: Locale = Locale {
+    standard: ("¤#", "-¤#"),
+    accounting: ("¤#", "(¤#)"),
+    symbols: &[],
+};
+
+/// Symbol after the number, negative amounts with a minus sign in both patterns.
+const SUFFIXED: Locale = Locale {
+    standard: ("#\u{a0}¤", "-#\u{a0}¤"),
+    accounting: ("#\u{a0}¤", "-#\u{a0}¤"),
+    symbols: &[],
+};
+
+const FR: Locale = Locale {
+    accounting: ("#\u{a0}¤", "(#\u{a0}¤)"),
+    symbols: &[
+        ("AUD", "$AU"),
+        ("CAD", "$CA"),
+        ("GBP", "£GB"),
+        ("HKD", "$HK"),
+        ("JPY", "JPY"),
+        ("NZD", "$NZ"),
+        ("USD", "$US"),
+    ],
+    ..SUFFIXED
+};
+
+const PT: Locale = Locale {
+    standard: ("¤\u{a0}#", "-¤\u{a0}#"),
+    accounting: ("¤\u{a0}#", "-¤\u{a0}#"),
+    symbols: &[("USD", "US$")],
+};
+
+/// Built-in languages, by lowercase tag.
+const LOCALES: &[(&str, Locale)] = &[
+    (
+        "cs",
+        Locale {
+            symbols: &[("CZK", "Kč")],
+            ..SUFFIXED
+        },
+    ),
+    (
+        "da",
+        Locale {
+            symbols: &[("DKK", "kr.")],
+            ..SUFFIXED
+        },
+    ),
+    ("de", SUFFIXED),
+    (
+        "de-at",
+        Locale {
+            standard: ("¤\u{a0}#", "-¤\u{a0}#"),
+            accounting: ("¤\u{a0}#", "-¤\u{a0}#"),
+            symbols: &[],
+        },
+    ),
+    (
+        "de-ch",
+        Locale {
+            standard: ("¤\u{a0}#", "¤-#"),
+            accounting: ("¤\u{a0}#", "¤-#"),
+            symbols: &[],
+        },
+    ),
+    ("en", EN),
+    (
+        "en-au",
+        Locale {
+            symbols: &[("AUD", "$"), ("USD", "US$")],
+            ..EN
+        },
+    ),
+    (
+        "en-ca",
+        Locale {
+            symbols: &[("CAD", "$"), ("USD", "US$")],
+            ..EN
+        },
+    ),
+    (
+        "en-nz",
+        Locale {
+            symbols: &[("NZD", "$"), ("USD", "US$")],
+            ..EN
+        },
+    ),
+    (
+        "es",
+        Locale {
+            symbols: &[("USD", "US$")],
+            ..SUFFIXED
+        },
+    ),
+    (
+        "es-419",
+        Locale {
+            standard: ("¤#", "-¤#"),
+            accounting: ("¤#", "-¤#"),
+            symbols: &[("USD", "US$")],
+        },
+    ),
+    (
+        "es-mx",
+        Locale {
+            standard: ("¤#", "-¤#"),
+            accounting: ("¤#", "-¤#"),
+            symbols: &[("MXN", "$"), ("USD", "USD")],
+        },
+    ),
+    ("fr", FR),
+    (
+        "fr-ca",
+        Locale {
+            symbols: &[("CAD", "$"), ("USD", "$\u{a0}US")],
+            ..FR
+        },
+    ),
+    (
+        "hi",
+        Locale {
+            symbols: &[("USD", "$")],
+            ..EN
+        },
+    ),
+    (
+        "hu",
+        Locale {
+            symbols: &[("HUF", "Ft")],
+            ..SUFFIXED
+        },
+    ),
+    ("it", SUFFIXED),
+    (
+        "ja",
+        Locale {
+            symbols: &[("CNY", "元"), ("JPY", "￥")],
+            ..EN
+        },
+    ),
+    (
+        "ko",
+        Locale {
+            symbols: &[("USD", "US$")],
+            ..EN
+        },
+    ),
+    (
+        "nb",
+        Locale {
+            symbols: &[("NOK", "kr")],
+            accounting: ("#\u{a0}¤", "(#\u{a0}¤)"),
+            ..SUFFIXED
+        },
+    ),
+    (
+        "nl",
+        Locale {
+            standard: ("¤\u{a0}#", "¤\u{a0}-#"),
+            accounting: ("¤\u{a0}#", "(¤\u{a0}#)"),
+            symbols: &[("USD", "US$")],
+        },
+    ),
+    (
+        "pl",
+        Locale {
+            symbols: &[("PLN", "zł")],
+            ..SUFFIXED
+        },
+    ),
+    ("pt", PT),
+    (
+        "pt-pt",
+        Locale {
+            accounting: ("#\u{a0}¤", "(#\u{a0}¤)"),
+            ..SUFFIXED
+        },
+    ),
+    (
+        "ru",
+        Locale {
+            symbols: &[("RUB", "₽"), ("USD", "$")],
+            ..SUFFIXED
+        },
+    ),
+    (
+        "sv",
+        Locale {
+            symbols: &[("SEK", "kr")],
+            ..SUFFIXED
+        },
+    ),
+    (
+        "tr",
+        Locale {
+            symbols: &[("TRY", "₺")],
+            ..EN
+        },
+    ),
+    (
+        "uk",
+        Locale {
+            symbols: &[("UAH", "₴")],
+            ..SUFFIXED
+        },
+    ),
+    (
+        "zh",
+        Locale {
+            symbols: &[("CNY", "¥"), ("JPY", "JP¥"), ("USD", "US$")],
+            ..EN
+        },
+    ),
+];
+
+/// Find the currency data of a language.
+fn locale(lang: &str) -> &'static Locale {
+    find_locale(lang, LOCALES, &EN)
+}
+
+/// Format an amount of money for a language.
+///
+/// # Arguments
+///
+/// * `lang` - A language code, e.g. `"de"` or `"de-CH"`.
+/// * `value` - The amount in major units, e.g. `12.5` for 12.50 €; infinite and NaN values
+///   are written as they are.
+/// * `code` - The ISO 4217 code of the currency, e.g. `"EUR"`.
+/// * `display` - Whether to show the symbol, the narrow symbol or the code.
+/// * `sign` - How to show negative amounts.
+///
+/// # Example
+///
+/// ```rust
+/// use lingua_i18n_rs::currency::{format_currency, CurrencyDisplay, CurrencySign};
+///
+/// let standard = CurrencySign::Standard;
+/// assert_eq!(format_currency("en", 1234.5, "USD", CurrencyDisplay::Symbol, standard), "$1,234.50");
+/// assert_eq!(format_currency("de", 1234.5, "EUR", CurrencyDisplay::Symbol, standard), "1.234,50\u{a0}€");
+/// assert_eq!(format_currency("ja", 1234.5, "JPY", CurrencyDisplay::Symbol, standard), "￥1,234");
+/// assert_eq!(format_currency("en", 12.0, "CHF", CurrencyDisplay::Code, standard), "CHF\u{a0}12.00");
+/// assert_eq!(
+///     format_currency("en", -5.0, "USD", CurrencyDisplay::Symbol, CurrencySign::Accounting),
+///     "($5.00)"
+/// );
+/// ```
+pub fn format_currency(
+    lang: &str,
+    value: f64,
+    code: &str,
+    display: CurrencyDisplay,
+    sign: CurrencySign,
+) -> String {
+    if !value.is_finite() {
+        return value.to_string();
+    }
+    let code = code.trim().to_ascii_uppercase();
+    let locale = locale(lang);
+    let currency = CURRENCIES.iter().find(|currency| currency.code == code);
+
+    let symbol = match display {
+        CurrencyDisplay::Code => code.as_str(),
+        CurrencyDisplay::Narrow => currency.map_or(code.as_str(), |currency| currency.narrow),
+        CurrencyDisplay::Symbol => locale
+            .symbols
+            .iter()
+            .find(|(symbol_code, _)| *symbol_code == code)
+            .map(|(_, symbol)| *symbol)
+            .or(currency.map(|currency| currency.symbol))
+            .unwrap_or(code.as_str()),
+    };
+    let digits = currency.map_or(2, |currency| currency.digits);
+    let (number, negative) = number::format_unsigned(lang, value, digits);
+
+    let (positive, negative_pattern) = match sign {
+        CurrencySign::Standard => locale.standard,
+        CurrencySign::Accounting => locale.accounting,
+    };
+    let pattern = if negative { negative_pattern } else { positive };
+
+    let mut out = String::new();
+    let mut chars = pattern.chars().peekable();
+    let mut previous = None;
+    while let Some(c) = chars.next() {
+        match c {
+            '¤' => {
+                // A symbol ending or starting with a letter is kept apart from the number.
+                if previous == Some('#') && symbol.starts_with(char::is_alphanumeric) {
+                    out.push('\u{a0}');
+                }
+                out.push_str(symbol);
+                if chars.peek() == Some(&'#') && symbol.ends_with(char::is_alphanumeric) {
+                    out.push('\u{a0}');
+                }
+            }
+            '#' => out.push_str(&number),
+            '-' => out.push_str(number::minus_sign(lang)),
+            c => out.push(c),
+        }
+        previous = Some(c);
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn symbol(lang: &str, value: f64, code: &str) -> String {
+        format_currency(
+            lang,
+            value,
+            code,
+            CurrencyDisplay::Symbol,
+            CurrencySign::Standard,
+        )
+    }
+
+    #[test]
+    fn test_symbols_and_placement() {
+        assert_eq!(symbol("en", 1234.567, "USD"), "$1,234.57");
+        assert_eq!(symbol("en", -1234.5, "EUR"), "-€1,234.50");
+        assert_eq!(symbol("en-CA", 5.0, "CAD"), "$5.00");
+        assert_eq!(symbol("en", 5.0, "CAD"), "CA$5.00");
+        assert_eq!(symbol("fr", 1234.5, "USD"), "1\u{202f}234,50\u{a0}$US");
+        assert_eq!(symbol("es", 1234.5, "USD"), "1234,50\u{a0}US$");
+        assert_eq!(symbol("nl", -5.0, "EUR"), "€\u{a0}-5,00");
+        assert_eq!(symbol("de-CH", -5.0, "CHF"), "CHF-5.00");
+        assert_eq!(symbol("pt-BR", 5.0, "BRL"), "R$\u{a0}5,00");
+        assert_eq!(symbol("en", 1.2346, "KWD"), "KWD\u{a0}1.235");
+        assert_eq!(symbol("en", 10.0, "XYZ"), "XYZ\u{a0}10.00");
+        assert_eq!(symbol("en", -0.001, "USD"), "$0.00");
+    }
+
+    #[test]
+    fn test_display_and_sign() {
+        let format =
+            |lang, value, code, display, sign| format_currency(lang, value, code, display, sign);
+        use CurrencyDisplay::*;
+        use CurrencySign::*;
+        assert_eq!(format("en", 5.0, "usd", Code, Standard), "USD\u{a0}5.00");
+        assert_eq!(format("de", 5.0, "USD", Code, Standard), "5,00\u{a0}USD");
+        assert_eq!(format("en", 5.0, "AUD", Narrow, Standard), "$5.00");
+        assert_eq!(format("en", -5.0, "USD", Symbol, Accounting), "($5.00)");
+        assert_eq!(
+            format("fr", -5.0, "EUR", Symbol, Accounting),
+            "(5,00\u{a0}€)"
+        );
+        assert_eq!(
+            format("de", -5.0, "EUR", Symbol, Accounting),
+            "-5,00\u{a0}€"
+        );
+    }
+}
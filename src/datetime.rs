@@ -0,0 +1,1425 @@
+//! Locale-aware date and time formatting.
+//!
+//! Timestamps are formatted with the CLDR Gregorian calendar data of the language: the
+//! `full`, `long`, `medium` and `short` date and time patterns, month and weekday names,
+//! day periods and the language's 12 or 24 hour clock. Skeletons such as `yMMMd` or `jm`
+//! pick the language's pattern for the requested fields, as in ICU. Common languages are
+//! built in and matched by their full tag first (e.g. `en-GB`), then by their primary
+//! language subtag; unknown languages use ISO-style patterns with English names.
+//!
+//! In translation strings, timestamps are formatted with `{{name, date}}`,
+//! `{{name, time}}` and `{{name, datetime}}` placeholders, optionally followed by a length
+//! or skeleton (`{{name, date:long}}`, `{{name, date:yMMMd}}`), or with `{name, date}`,
+//! `{name, time, short}` and `{name, date, ::yMMMd}` in ICU messages.
+use crate::locale::find_locale;
+use std::fmt;
+use std::str::FromStr;
+
+/// The length of a date or time pattern.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DateLength {
+    /// E.g. `Tuesday, March 5, 2024` or `2:30:00 PM GMT+01:00`.
+    Full,
+    /// E.g. `March 5, 2024` or `2:30:00 PM GMT+1`.
+    Long,
+    /// E.g. `Mar 5, 2024` or `2:30:00 PM`.
+    #[default]
+    Medium,
+    /// E.g. `3/5/24` or `2:30 PM`.
+    Short,
+}
+
+impl DateLength {
+    /// The keyword used for the length in placeholders.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DateLength::Full => "full",
+            DateLength::Long => "long",
+            DateLength::Medium => "medium",
+            DateLength::Short => "short",
+        }
+    }
+
+    /// Parse a placeholder keyword.
+    pub fn from_keyword(keyword: &str) -> Option<Self> {
+        match keyword.trim() {
+            "full" => Some(DateLength::Full),
+            "long" => Some(DateLength::Long),
+            "medium" => Some(DateLength::Medium),
+            "short" => Some(DateLength::Short),
+            _ => None,
+        }
+    }
+
+    fn index(self) -> usize {
+        self as usize
+    }
+}
+
+impl fmt::Display for DateLength {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// An instant with the UTC offset it is displayed in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DateTime {
+    /// Seconds since the Unix epoch.
+    timestamp: i64,
+    /// Offset from UTC in minutes.
+    offset: i32,
+}
+
+/// A timestamp that could not be parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError(String);
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Invalid timestamp '{}'", self.0)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+impl DateTime {
+    /// Create a UTC date and time from seconds since the Unix epoch.
+    pub fn from_timestamp(timestamp: i64) -> Self {
+        Self {
+            timestamp,
+            offset: 0,
+        }
+    }
+
+    /// Display the same instant at a UTC offset, in minutes.
+    pub fn with_offset(self, offset: i32) -> Self {
+        Self { offset, ..self }
+    }
+
+    /// Seconds since the Unix epoch.
+    pub fn timestamp(&self) -> i64 {
+        self.timestamp
+    }
+
+    /// The offset from UTC in minutes.
+    pub fn offset(&self) -> i32 {
+        self.offset
+    }
+
+    fn fields(&self) -> Fields {
+        let local = self.timestamp + i64::from(self.offset) * 60;
+        let days = local.div_euclid(86_400);
+        let seconds = local.rem_euclid(86_400);
+        let (year, month, day) = civil_from_days(days);
+        Fields {
+            year,
+            month,
+            day,
+            weekday: (days + 4).rem_euclid(7) as usize,
+            hour: (seconds / 3600) as u32,
+            minute: (seconds / 60 % 60) as u32,
+            second: (seconds % 60) as u32,
+            offset: self.offset,
+        }
+    }
+}
+
+/// The largest year accepted when parsing dates, which keeps the arithmetic on days and
+/// seconds far from overflowing.
+const MAX_YEAR: i64 = 1_000_000;
+
+impl FromStr for DateTime {
+    type Err = ParseError;
+
+    /// Parse seconds since the Unix epoch (`1709649000`, fractions are truncated) or an
+    /// ISO 8601 date or date and time (`2024-03-05`, `2024-03-05T14:30:00+01:00`). Times
+    /// without an offset are taken as UTC. Years beyond 1,000,000 are rejected.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let error = || ParseError(s.to_string());
+
+        if let Ok(seconds) = s.parse::<f64>()
+            && seconds.is_finite()
+        {
+            return Ok(Self::from_timestamp(seconds.trunc() as i64));
+        }
+
+        let (date, time) = match s.split_once(['T', 't', ' ']) {
+            Some((date, time)) => (date, Some(time)),
+            None => (s, None),
+        };
+        let mut parts = date.splitn(3, '-');
+        let mut next = || parts.next().and_then(|part| part.parse::<i64>().ok());
+        let (year, month, day) = (
+            next().ok_or_else(error)?,
+            next().ok_or_else(error)?,
+            next().ok_or_else(error)?,
+        );
+        if !(-MAX_YEAR..=MAX_YEAR).contains(&year)
+            || !(1..=12).contains(&month)
+            || !(1..=days_in_month(year, month)).contains(&day)
+        {
+            return Err(error());
+        }
+
+        let (mut seconds, mut offset) = (0, 0);
+        if let Some(time) = time {
+            let (clock, zone) = match time.find(['Z', 'z', '+', '-']) {
+                Some(index) => time.split_at(index),
+                None => (time, ""),
+            };
+            let clock: Vec<&str> = clock.split(':').collect();
+            let digits =
+                |value: &str| !value.is_empty() && value.bytes().all(|b| b.is_ascii_digit());
+            // Only the last field may have a fraction, which is truncated.
+            let field = |index: usize, max: i64| match clock.get(index) {
+                Some(value) => {
+                    let (value, fraction) = match value.split_once('.') {
+                        Some((value, fraction)) => (value, Some(fraction)),
+                        None => (*value, None),
+                    };
+                    let fraction_valid = fraction
+                        .is_none_or(|fraction| index == clock.len() - 1 && digits(fraction));
+                    if !fraction_valid || !digits(value) {
+                        return None;
+                    }
+                    value
+                        .parse::<i64>()
+                        .ok()
+                        .filter(|value| (0..=max).contains(value))
+                }
+                None => Some(0),
+            };
+            if clock.len() < 2 || clock.len() > 3 {
+                return Err(error());
+            }
+            let hour = field(0, 23).ok_or_else(error)?;
+            let minute = field(1, 59).ok_or_else(error)?;
+            let second = field(2, 60).ok_or_else(error)?;
+            seconds = hour * 3600 + minute * 60 + second;
+
+            offset = match zone {
+                "" | "Z" | "z" => 0,
+                zone => {
+                    let sign = if zone.starts_with('-') { -1 } else { 1 };
+                    let digits: String = zone[1..].chars().filter(|c| *c != ':').collect();
+                    if (digits.len() != 2 && digits.len() != 4)
+                        || !digits.bytes().all(|b| b.is_ascii_digit())
+                    {
+                        return Err(error());
+                    }
+                    let hours = digits[..2].parse::<i32>().map_err(|_| error())?;
+                    let minutes = digits[2..].parse::<i32>().unwrap_or(0);
+                    if hours > 23 || minutes > 59 {
+                        return Err(error());
+                    }
+                    sign * (hours * 60 + minutes)
+                }
+            };
+        }
+
+        let local = days_from_civil(year, month, day) * 86_400 + seconds;
+        Ok(Self::from_timestamp(local - i64::from(offset) * 60).with_offset(offset))
+    }
+}
+
+/// The calendar fields of a `DateTime` in its offset.
+struct Fields {
+    year: i64,
+    month: i64,
+    day: i64,
+    /// 0 is Sunday.
+    weekday: usize,
+    hour: u32,
+    minute: u32,
+    second: u32,
+    offset: i32,
+}
+
+/// Days since 1970-01-01 of a proleptic Gregorian date.
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// The proleptic Gregorian date of a day since 1970-01-01.
+fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days - era * 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+fn days_in_month(year: i64, month: i64) -> i64 {
+    match month {
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// The Gregorian calendar data of a language.
+struct Calendar {
+    months: [&'static str; 12],
+    months_abbr: [&'static str; 12],
+    /// Stand-alone month names (`LLLL`, `LLL`), where they differ from the format names.
+    months_standalone: Option<([&'static str; 12], [&'static str; 12])>,
+    weekdays: [&'static str; 7],
+    weekdays_abbr: [&'static str; 7],
+    am_pm: [&'static str; 2],
+    /// Date patterns, from `full` to `short`.
+    date: [&'static str; 4],
+    /// Time patterns, from `full` to `short`.
+    time: [&'static str; 4],
+    /// Patterns combining a date `{1}` and a time `{0}`, by the length of the date.
+    datetime: [&'static str; 4],
+    /// Prefix of localized GMT offsets.
+    gmt: &'static str,
+    /// Patterns by skeleton.
+    skeletons: &'static [(&'static str, &'static str)],
+}
+
+const ENGLISH_MONTHS: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+const ENGLISH_MONTHS_ABBR: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+const ENGLISH_WEEKDAYS: [&str; 7] = [
+    "Sunday",
+    "Monday",
+    "Tuesday",
+    "Wednesday",
+    "Thursday",
+    "Friday",
+    "Saturday",
+];
+const ENGLISH_WEEKDAYS_ABBR: [&str; 7] = ["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"];
+
+const ROOT: Calendar = Calendar {
+    months: ENGLISH_MONTHS,
+    months_abbr: ENGLISH_MONTHS_ABBR,
+    months_standalone: None,
+    weekdays: ENGLISH_WEEKDAYS,
+    weekdays_abbr: ENGLISH_WEEKDAYS_ABBR,
+    am_pm: ["AM", "PM"],
+    date: ["y MMMM d, EEEE", "y MMMM d", "y MMM d", "y-MM-dd"],
+    time: ["HH:mm:ss zzzz", "HH:mm:ss z", "HH:mm:ss", "HH:mm"],
+    datetime: ["{1} {0}", "{1} {0}", "{1} {0}", "{1} {0}"],
+    gmt: "GMT",
+    skeletons: &[],
+};
+
+/// Patterns of skeletons that are the same in most languages.
+const COMMON_SKELETONS: &[(&str, &str)] = &[
+    ("y", "y"),
+    ("d", "d"),
+    ("M", "L"),
+    ("MMM", "LLL"),
+    ("MMMM", "LLLL"),
+    ("E", "ccc"),
+    ("EEEE", "cccc"),
+    ("H", "HH"),
+    ("Hm", "HH:mm"),
+    ("Hms", "HH:mm:ss"),
+    ("h", "h a"),
+    ("hm", "h:mm a"),
+    ("hms", "h:mm:ss a"),
+    ("ms", "mm:ss"),
+];
+
+const EN: Calendar = Calendar {
+    date: ["EEEE, MMMM d, y", "MMMM d, y", "MMM d, y", "M/d/yy"],
+    time: [
+        "h:mm:ss\u{202f}a zzzz",
+        "h:mm:ss\u{202f}a z",
+        "h:mm:ss\u{202f}a",
+        "h:mm\u{202f}a",
+    ],
+    datetime: ["{1} 'at' {0}", "{1} 'at' {0}", "{1}, {0}", "{1}, {0}"],
+    skeletons: &[
+        ("h", "h\u{202f}a"),
+        ("hm", "h:mm\u{202f}a"),
+        ("hms", "h:mm:ss\u{202f}a"),
+        ("Ed", "d E"),
+        ("Md", "M/d"),
+        ("MEd", "E, M/d"),
+        ("MMMd", "MMM d"),
+        ("MMMEd", "E, MMM d"),
+        ("MMMMd", "MMMM d"),
+        ("yM", "M/y"),
+        ("yMd", "M/d/y"),
+        ("yMEd", "E, M/d/y"),
+        ("yMMM", "MMM y"),
+        ("yMMMd", "MMM d, y"),
+        ("yMMMEd", "E, MMM d, y"),
+        ("yMMMM", "MMMM y"),
+    ],
+    ..ROOT
+};
+
+const EN_GB: Calendar = Calendar {
+    am_pm: ["am", "pm"],
+    date: ["EEEE d MMMM y", "d MMMM y", "d MMM y", "dd/MM/y"],
+    time: ["HH:mm:ss zzzz", "HH:mm:ss z", "HH:mm:ss", "HH:mm"],
+    skeletons: &[
+        ("h", "h\u{202f}a"),
+        ("hm", "h:mm\u{202f}a"),
+        ("hms", "h:mm:ss\u{202f}a"),
+        ("Ed", "E d"),
+        ("Md", "dd/MM"),
+        ("MEd", "E dd/MM"),
+        ("MMMd", "d MMM"),
+        ("MMMEd", "E d MMM"),
+        ("MMMMd", "d MMMM"),
+        ("yM", "MM/y"),
+        ("yMd", "dd/MM/y"),
+        ("yMEd", "E, dd/MM/y"),
+        ("yMMM", "MMM y"),
+        ("yMMMd", "d MMM y"),
+        ("yMMMEd", "E, d MMM y"),
+        ("yMMMM", "MMMM y"),
+    ],
+    ..EN
+};
+
+const DE: Calendar = Calendar {
+    months: [
+        "Januar",
+        "Februar",
+        "März",
+        "April",
+        "Mai",
+        "Juni",
+        "Juli",
+        "August",
+        "September",
+        "Oktober",
+        "November",
+        "Dezember",
+    ],
+    months_abbr: [
+        "Jan.", "Feb.", "März", "Apr.", "Mai", "Juni", "Juli", "Aug.", "Sept.", "Okt.", "Nov.",
+        "Dez.",
+    ],
+    months_standalone: Some((
+        [
+            "Januar",
+            "Februar",
+            "März",
+            "April",
+            "Mai",
+            "Juni",
+            "Juli",
+            "August",
+            "September",
+            "Oktober",
+            "November",
+            "Dezember",
+        ],
+        [
+            "Jan", "Feb", "Mär", "Apr", "Mai", "Jun", "Jul", "Aug", "Sep", "Okt", "Nov", "Dez",
+        ],
+    )),
+    weekdays: [
+        "Sonntag",
+        "Montag",
+        "Dienstag",
+        "Mittwoch",
+        "Donnerstag",
+        "Freitag",
+        "Samstag",
+    ],
+    weekdays_abbr: ["So.", "Mo.", "Di.", "Mi.", "Do.", "Fr.", "Sa."],
+    date: ["EEEE, d. MMMM y", "d. MMMM y", "dd.MM.y", "dd.MM.yy"],
+    datetime: ["{1} 'um' {0}", "{1} 'um' {0}", "{1}, {0}", "{1}, {0}"],
+    skeletons: &[
+        ("Ed", "E, d."),
+        ("Md", "d.M."),
+        ("MEd", "E, d.M."),
+        ("MMMd", "d. MMM"),
+        ("MMMEd", "E, d. MMM"),
+        ("MMMMd", "d. MMMM"),
+        ("yM", "M/y"),
+        ("yMd", "d.M.y"),
+        ("yMEd", "E, d.M.y"),
+        ("yMMM", "MMM y"),
+        ("yMMMd", "d. MMM y"),
+        ("yMMMEd", "E, d. MMM y"),
+        ("yMMMM", "MMMM y"),
+        ("H", "HH 'Uhr'"),
+    ],
+    ..ROOT
+};
+
+const ES: Calendar = Calendar {
+    months: [
+        "enero",
+        "febrero",
+        "marzo",
+        "abril",
+        "mayo",
+        "junio",
+        "julio",
+        "agosto",
+        "septiembre",
+        "octubre",
+        "noviembre",
+        "diciembre",
+    ],
+    months_abbr: [
+        "ene", "feb", "mar", "abr", "may", "jun", "jul", "ago", "sept", "oct", "nov", "dic",
+    ],
+    weekdays: [
+        "domingo",
+        "lunes",
+        "martes",
+        "miércoles",
+        "jueves",
+        "viernes",
+        "sábado",
+    ],
+    weekdays_abbr: ["dom", "lun", "mar", "mié", "jue", "vie", "sáb"],
+    am_pm: ["a.\u{a0}m.", "p.\u{a0}m."],
+    date: [
+        "EEEE, d 'de' MMMM 'de' y",
+        "d 'de' MMMM 'de' y",
+        "d MMM y",
+        "d/M/yy",
+    ],
+    time: ["H:mm:ss (zzzz)", "H:mm:ss z", "H:mm:ss", "H:mm"],
+    datetime: ["{1}, {0}", "{1}, {0}", "{1}, {0}", "{1}, {0}"],
+    skeletons: &[
+        ("H", "H"),
+        ("Hm", "H:mm"),
+        ("Hms", "H:mm:ss"),
+        ("Ed", "E d"),
+        ("Md", "d/M"),
+        ("MEd", "E, d/M"),
+        ("MMMd", "d MMM"),
+        ("MMMEd", "E, d MMM"),
+        ("MMMMd", "d 'de' MMMM"),
+        ("yM", "M/y"),
+        ("yMd", "d/M/y"),
+        ("yMEd", "EEE, d/M/y"),
+        ("yMMM", "MMM y"),
+        ("yMMMd", "d MMM y"),
+        ("yMMMEd", "EEE, d MMM y"),
+        ("yMMMM", "MMMM 'de' y"),
+    ],
+    ..ROOT
+};
+
+const FR: Calendar = Calendar {
+    months: [
+        "janvier",
+        "février",
+        "mars",
+        "avril",
+        "mai",
+        "juin",
+        "juillet",
+        "août",
+        "septembre",
+        "octobre",
+        "novembre",
+        "décembre",
+    ],
+    months_abbr: [
+        "janv.", "févr.", "mars", "avr.", "mai", "juin", "juil.", "août", "sept.", "oct.", "nov.",
+        "déc.",
+    ],
+    weekdays: [
+        "dimanche", "lundi", "mardi", "mercredi", "jeudi", "vendredi", "samedi",
+    ],
+    weekdays_abbr: ["dim.", "lun.", "mar.", "mer.", "jeu.", "ven.", "sam."],
+    date: ["EEEE d MMMM y", "d MMMM y", "d MMM y", "dd/MM/y"],
+    datetime: ["{1} 'à' {0}", "{1} 'à' {0}", "{1}, {0}", "{1} {0}"],
+    gmt: "UTC",
+    skeletons: &[
+        ("H", "HH 'h'"),
+        ("Ed", "E d"),
+        ("Md", "dd/MM"),
+        ("MEd", "E dd/MM"),
+        ("MMMd", "d MMM"),
+        ("MMMEd", "E d MMM"),
+        ("MMMMd", "d MMMM"),
+        ("yM", "MM/y"),
+        ("yMd", "dd/MM/y"),
+        ("yMEd", "E dd/MM/y"),
+        ("yMMM", "MMM y"),
+        ("yMMMd", "d MMM y"),
+        ("yMMMEd", "E d MMM y"),
+        ("yMMMM", "MMMM y"),
+    ],
+    ..ROOT
+};
+
+const IT: Calendar = Calendar {
+    months: [
+        "gennaio",
+        "febbraio",
+        "marzo",
+        "aprile",
+        "maggio",
+        "giugno",
+        "luglio",
+        "agosto",
+        "settembre",
+        "ottobre",
+        "novembre",
+        "dicembre",
+    ],
+    months_abbr: [
+        "gen", "feb", "mar", "apr", "mag", "giu", "lug", "ago", "set", "ott", "nov", "dic",
+    ],
+    weekdays: [
+        "domenica",
+        "lunedì",
+        "martedì",
+        "mercoledì",
+        "giovedì",
+        "venerdì",
+        "sabato",
+    ],
+    weekdays_abbr: ["dom", "lun", "mar", "mer", "gio", "ven", "sab"],
+    date: ["EEEE d MMMM y", "d MMMM y", "d MMM y", "dd/MM/yy"],
+    datetime: ["{1} {0}", "{1} {0}", "{1}, {0}", "{1}, {0}"],
+    skeletons: &[
+        ("Ed", "E d"),
+        ("Md", "d/M"),
+        ("MEd", "E d/M"),
+        ("MMMd", "d MMM"),
+        ("MMMEd", "E d MMM"),
+        ("MMMMd", "d MMMM"),
+        ("yM", "M/y"),
+        ("yMd", "d/M/y"),
+        ("yMEd", "E d/M/y"),
+        ("yMMM", "MMM y"),
+        ("yMMMd", "d MMM y"),
+        ("yMMMEd", "E d MMM y"),
+        ("yMMMM", "MMMM y"),
+    ],
+    ..ROOT
+};
+
+const JA: Calendar = Calendar {
+    months: [
+        "1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月",
+    ],
+    months_abbr: [
+        "1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月",
+    ],
+    weekdays: [
+        "日曜日",
+        "月曜日",
+        "火曜日",
+        "水曜日",
+        "木曜日",
+        "金曜日",
+        "土曜日",
+    ],
+    weekdays_abbr: ["日", "月", "火", "水", "木", "金", "土"],
+    am_pm: ["午前", "午後"],
+    date: ["y年M月d日EEEE", "y年M月d日", "y/MM/dd", "y/MM/dd"],
+    time: ["H時mm分ss秒 zzzz", "H:mm:ss z", "H:mm:ss", "H:mm"],
+    skeletons: &[
+        ("d", "d日"),
+        ("y", "y年"),
+        ("H", "H時"),
+        ("Hm", "H:mm"),
+        ("Hms", "H:mm:ss"),
+        ("h", "aK時"),
+        ("hm", "aK:mm"),
+        ("hms", "aK:mm:ss"),
+        ("Ed", "d日(E)"),
+        ("Md", "M/d"),
+        ("MEd", "M/d(E)"),
+        ("MMMd", "M月d日"),
+        ("MMMEd", "M月d日(E)"),
+        ("MMMMd", "M月d日"),
+        ("yM", "y/M"),
+        ("yMd", "y/M/d"),
+        ("yMEd", "y/M/d(E)"),
+        ("yMMM", "y年M月"),
+        ("yMMMd", "y年M月d日"),
+        ("yMMMEd", "y年M月d日(E)"),
+        ("yMMMM", "y年M月"),
+    ],
+    ..ROOT
+};
+
+const NL: Calendar = Calendar {
+    months: [
+        "januari",
+        "februari",
+        "maart",
+        "april",
+        "mei",
+        "juni",
+        "juli",
+        "augustus",
+        "september",
+        "oktober",
+        "november",
+        "december",
+    ],
+    months_abbr: [
+        "jan", "feb", "mrt", "apr", "mei", "jun", "jul", "aug", "sep", "okt", "nov", "dec",
+    ],
+    weekdays: [
+        "zondag",
+        "maandag",
+        "dinsdag",
+        "woensdag",
+        "donderdag",
+        "vrijdag",
+        "zaterdag",
+    ],
+    weekdays_abbr: ["zo", "ma", "di", "wo", "do", "vr", "za"],
+    am_pm: ["a.m.", "p.m."],
+    date: ["EEEE d MMMM y", "d MMMM y", "d MMM y", "dd-MM-y"],
+    datetime: ["{1} 'om' {0}", "{1} 'om' {0}", "{1} {0}", "{1} {0}"],
+    skeletons: &[
+        ("Ed", "E d"),
+        ("Md", "d-M"),
+        ("MEd", "E d-M"),
+        ("MMMd", "d MMM"),
+        ("MMMEd", "E d MMM"),
+        ("MMMMd", "d MMMM"),
+        ("yM", "M-y"),
+        ("yMd", "d-M-y"),
+        ("yMEd", "E d-M-y"),
+        ("yMMM", "MMM y"),
+        ("yMMMd", "d MMM y"),
+        ("yMMMEd", "E d MMM y"),
+        ("yMMMM", "MMMM y"),
+    ],
+    ..ROOT
+};
+
+const PL: Calendar = Calendar {
+    months: [
+        "stycznia",
+        "lutego",
+        "marca",
+        "kwietnia",
+        "maja",
+        "czerwca",
+        "lipca",
+        "sierpnia",
+        "września",
+        "października",
+        "listopada",
+        "grudnia",
+    ],
+    months_abbr: [
+        "sty", "lut", "mar", "kwi", "maj", "cze", "lip", "sie", "wrz", "paź", "lis", "gru",
+    ],
+    months_standalone: Some((
+        [
+            "styczeń",
+            "luty",
+            "marzec",
+            "kwiecień",
+            "maj",
+            "czerwiec",
+            "lipiec",
+            "sierpień",
+            "wrzesień",
+            "październik",
+            "listopad",
+            "grudzień",
+        ],
+        [
+            "sty", "lut", "mar", "kwi", "maj", "cze", "lip", "sie", "wrz", "paź", "lis", "gru",
+        ],
+    )),
+    weekdays: [
+        "niedziela",
+        "poniedziałek",
+        "wtorek",
+        "środa",
+        "czwartek",
+        "piątek",
+        "sobota",
+    ],
+    weekdays_abbr: ["niedz.", "pon.", "wt.", "śr.", "czw.", "pt.", "sob."],
+    date: ["EEEE, d MMMM y", "d MMMM y", "d MMM y", "d.MM.y"],
+    datetime: ["{1} 'o' {0}", "{1} 'o' {0}", "{1}, {0}", "{1}, {0}"],
+    skeletons: &[
+        ("Ed", "E, d"),
+        ("Md", "d.MM"),
+        ("MEd", "E, d.MM"),
+        ("MMMd", "d MMM"),
+        ("MMMEd", "E, d MMM"),
+        ("MMMMd", "d MMMM"),
+        ("yM", "MM.y"),
+        ("yMd", "d.MM.y"),
+        ("yMEd", "E, d.MM.y"),
+        ("yMMM", "LLL y"),
+        ("yMMMd", "d MMM y"),
+        ("yMMMEd", "E, d MMM y"),
+        ("yMMMM", "LLLL y"),
+    ],
+    ..ROOT
+};
+
+const PT: Calendar = Calendar {
+    months: [
+        "janeiro",
+        "fevereiro",
+        "março",
+        "abril",
+        "maio",
+        "junho",
+        "julho",
+        "agosto",
+        "setembro",
+        "outubro",
+        "novembro",
+        "dezembro",
+    ],
+    months_abbr: [
+        "jan.", "fev.", "mar.", "abr.", "mai.", "jun.", "jul.", "ago.", "set.", "out.", "nov.",
+        "dez.",
+    ],
+    weekdays: [
+        "domingo",
+        "segunda-feira",
+        "terça-feira",
+        "quarta-feira",
+        "quinta-feira",
+        "sexta-feira",
+        "sábado",
+    ],
+    weekdays_abbr: ["dom.", "seg.", "ter.", "qua.", "qui.", "sex.", "sáb."],
+    date: [
+        "EEEE, d 'de' MMMM 'de' y",
+        "d 'de' MMMM 'de' y",
+        "d 'de' MMM 'de' y",
+        "dd/MM/y",
+    ],
+    skeletons: &[
+        ("Ed", "E, d"),
+        ("Md", "d/M"),
+        ("MEd", "E, dd/MM"),
+        ("MMMd", "d 'de' MMM"),
+        ("MMMEd", "E, d 'de' MMM"),
+        ("MMMMd", "d 'de' MMMM"),
+        ("yM", "MM/y"),
+        ("yMd", "dd/MM/y"),
+        ("yMEd", "E, dd/MM/y"),
+        ("yMMM", "MMM 'de' y"),
+        ("yMMMd", "d 'de' MMM 'de' y"),
+        ("yMMMEd", "E, d 'de' MMM 'de' y"),
+        ("yMMMM", "MMMM 'de' y"),
+    ],
+    ..ROOT
+};
+
+const RU: Calendar = Calendar {
+    months: [
+        "января",
+        "февраля",
+        "марта",
+        "апреля",
+        "мая",
+        "июня",
+        "июля",
+        "августа",
+        "сентября",
+        "октября",
+        "ноября",
+        "декабря",
+    ],
+    months_abbr: [
+        "янв.",
+        "февр.",
+        "мар.",
+        "апр.",
+        "мая",
+        "июн.",
+        "июл.",
+        "авг.",
+        "сент.",
+        "окт.",
+        "нояб.",
+        "дек.",
+    ],
+    months_standalone: Some((
+        [
+            "январь",
+            "февраль",
+            "март",
+            "апрель",
+            "май",
+            "июнь",
+            "июль",
+            "август",
+            "сентябрь",
+            "октябрь",
+            "ноябрь",
+            "декабрь",
+        ],
+        [
+            "янв.",
+            "февр.",
+            "март",
+            "апр.",
+            "май",
+            "июнь",
+            "июль",
+            "авг.",
+            "сент.",
+            "окт.",
+            "нояб.",
+            "дек.",
+        ],
+    )),
+    weekdays: [
+        "воскресенье",
+        "понедельник",
+        "вторник",
+        "среда",
+        "четверг",
+        "пятница",
+        "суббота",
+    ],
+    weekdays_abbr: ["вс", "пн", "вт", "ср", "чт", "пт", "сб"],
+    date: [
+        "EEEE, d MMMM y 'г'.",
+        "d MMMM y 'г'.",
+        "d MMM y 'г'.",
+        "dd.MM.y",
+    ],
+    datetime: ["{1}, {0}", "{1}, {0}", "{1}, {0}", "{1}, {0}"],
+    skeletons: &[
+        ("Ed", "E, d"),
+        ("Md", "dd.MM"),
+        ("MEd", "E, dd.MM"),
+        ("MMMd", "d MMM"),
+        ("MMMEd", "E, d MMM"),
+        ("MMMMd", "d MMMM"),
+        ("yM", "MM.y"),
+        ("yMd", "dd.MM.y"),
+        ("yMEd", "E, dd.MM.y 'г'."),
+        ("yMMM", "LLL y 'г'."),
+        ("yMMMd", "d MMM y 'г'."),
+        ("yMMMEd", "E, d MMM y 'г'."),
+        ("yMMMM", "LLLL y 'г'."),
+    ],
+    ..ROOT
+};
+
+const ZH: Calendar = Calendar {
+    months: [
+        "一月",
+        "二月",
+        "三月",
+        "四月",
+        "五月",
+        "六月",
+        "七月",
+        "八月",
+        "九月",
+        "十月",
+        "十一月",
+        "十二月",
+    ],
+    months_abbr: [
+        "1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月",
+    ],
+    weekdays: [
+        "星期日",
+        "星期一",
+        "星期二",
+        "星期三",
+        "星期四",
+        "星期五",
+        "星期六",
+    ],
+    weekdays_abbr: ["周日", "周一", "周二", "周三", "周四", "周五", "周六"],
+    am_pm: ["上午", "下午"],
+    date: ["y年M月d日EEEE", "y年M月d日", "y年M月d日", "y/M/d"],
+    time: ["zzzz HH:mm:ss", "z HH:mm:ss", "HH:mm:ss", "HH:mm"],
+    skeletons: &[
+        ("d", "d日"),
+        ("y", "y年"),
+        ("H", "H时"),
+        ("h", "ah时"),
+        ("hm", "ah:mm"),
+        ("hms", "ah:mm:ss"),
+        ("Ed", "d日E"),
+        ("Md", "M/d"),
+        ("MEd", "M/dE"),
+        ("MMMd", "M月d日"),
+        ("MMMEd", "M月d日E"),
+        ("MMMMd", "M月d日"),
+        ("yM", "y/M"),
+        ("yMd", "y/M/d"),
+        ("yMEd", "y/M/dE"),
+        ("yMMM", "y年M月"),
+        ("yMMMd", "y年M月d日"),
+        ("yMMMEd", "y年M月d日E"),
+        ("yMMMM", "y年M月"),
+    ],
+    ..ROOT
+};
+
+/// Built-in languages, by lowercase tag.
+const LOCALES: &[(&str, Calendar)] = &[
+    ("de", DE),
+    ("en", EN),
+    ("en-au", EN_GB),
+    ("en-gb", EN_GB),
+    ("en-ie", EN_GB),
+    ("en-nz", EN_GB),
+    ("es", ES),
+    ("fr", FR),
+    ("it", IT),
+    ("ja", JA),
+    ("nl", NL),
+    ("pl", PL),
+    ("pt", PT),
+    ("ru", RU),
+    ("zh", ZH),
+];
+
+/// Find the calendar data of a language.
+fn calendar(lang: &str) -> &'static Calendar {
+    find_locale(lang, LOCALES, &ROOT)
+}
+
+/// Format the date of a timestamp with one of the language's date patterns.
+///
+/// # Arguments
+///
+/// * `lang` - A language code, e.g. `"de"` or `"en-GB"`.
+/// * `value` - The date and time to format.
+/// * `length` - The length of the pattern.
+///
+/// # Example
+///
+/// ```rust
+/// use lingua_i18n_rs::datetime::{format_date, DateLength, DateTime};
+///
+/// let when: DateTime = "2024-03-05T14:30:00Z".parse().unwrap();
+/// assert_eq!(format_date("en", &when, DateLength::Medium), "Mar 5, 2024");
+/// assert_eq!(format_date("de", &when, DateLength::Long), "5. März 2024");
+/// ```
+pub fn format_date(lang: &str, value: &DateTime, length: DateLength) -> String {
+    let calendar = calendar(lang);
+    format_pattern(calendar, value, calendar.date[length.index()])
+}
+
+/// Format the time of a timestamp with one of the language's time patterns, using its
+/// 12 or 24 hour clock.
+///
+/// # Example
+///
+/// ```rust
+/// use lingua_i18n_rs::datetime::{format_time, DateLength, DateTime};
+///
+/// let when: DateTime = "2024-03-05T14:30:00Z".parse().unwrap();
+/// assert_eq!(format_time("en", &when, DateLength::Short), "2:30\u{202f}PM");
+/// assert_eq!(format_time("de", &when, DateLength::Short), "14:30");
+/// ```
+pub fn format_time(lang: &str, value: &DateTime, length: DateLength) -> String {
+    let calendar = calendar(lang);
+    format_pattern(calendar, value, calendar.time[length.index()])
+}
+
+/// Format the date and time of a timestamp, combining the date and time patterns of the
+/// same length.
+pub fn format_datetime(lang: &str, value: &DateTime, length: DateLength) -> String {
+    let calendar = calendar(lang);
+    let pattern = calendar.datetime[length.index()]
+        .replace("{1}", calendar.date[length.index()])
+        .replace("{0}", calendar.time[length.index()]);
+    format_pattern(calendar, value, &pattern)
+}
+
+/// Format a timestamp with the language's pattern for a skeleton.
+///
+/// A skeleton lists the fields to show, e.g. `yMMMd` for year, abbreviated month and day,
+/// or `jm` for hour and minute in the language's preferred clock. Skeletons without a
+/// pattern of their own are matched with a shorter month or weekday and widened, or split
+/// into a date and a time part.
+///
+/// # Example
+///
+/// ```rust
+/// use lingua_i18n_rs::datetime::{format_skeleton, DateTime};
+///
+/// let when: DateTime = "2024-03-05T14:30:00Z".parse().unwrap();
+/// assert_eq!(format_skeleton("en", &when, "yMMMd"), "Mar 5, 2024");
+/// assert_eq!(format_skeleton("fr", &when, "yMMMMd"), "5 mars 2024");
+/// assert_eq!(format_skeleton("de", &when, "jm"), "14:30");
+/// ```
+pub fn format_skeleton(lang: &str, value: &DateTime, skeleton: &str) -> String {
+    let calendar = calendar(lang);
+    let pattern = skeleton_pattern(calendar, skeleton);
+    format_pattern(calendar, value, &pattern)
+}
+
+/// The order of fields in skeletons.
+const FIELD_ORDER: &str = "GyYuQqMLwWEecdDFgabBhHKkmsSAzZOvVXx";
+
+/// Whether a string is a skeleton: field letters (and `j`), each field in a single run,
+/// e.g. `yMMMd` but not `medum`.
+pub(crate) fn is_skeleton(skeleton: &str) -> bool {
+    let mut seen = Vec::new();
+    let mut previous = None;
+    for c in skeleton.chars() {
+        if !FIELD_ORDER.contains(c) && c != 'j' {
+            return false;
+        }
+        if previous != Some(c) {
+            if seen.contains(&c) {
+                return false;
+            }
+            seen.push(c);
+        }
+        previous = Some(c);
+    }
+    !seen.is_empty()
+}
+
+/// Find the pattern for a skeleton.
+fn skeleton_pattern(calendar: &Calendar, skeleton: &str) -> String {
+    // `j` is the preferred hour of the language, taken from its short time pattern.
+    let hour = calendar.time[DateLength::Short.index()]
+        .chars()
+        .find(|c| matches!(c, 'h' | 'H' | 'K' | 'k'))
+        .unwrap_or('H');
+    let hour = if hour == 'K' { 'h' } else { hour };
+    let mut fields: Vec<char> = skeleton
+        .trim()
+        .chars()
+        .filter(|c| !matches!(c, 'a' | 'b' | 'B'))
+        .map(|c| if c == 'j' { hour } else { c })
+        .collect();
+    // Skeletons are looked up with their fields in canonical order, so `dMMMy` is `yMMMd`.
+    fields.sort_by_key(|c| FIELD_ORDER.find(*c).unwrap_or(FIELD_ORDER.len()));
+    let skeleton: String = fields.into_iter().collect();
+
+    if let Some(pattern) = lookup_skeleton(calendar, &skeleton) {
+        return pattern;
+    }
+
+    let split = skeleton
+        .find(['h', 'H', 'K', 'k', 'm', 's'])
+        .unwrap_or(skeleton.len());
+    let (date, time) = skeleton.split_at(split);
+    if !date.is_empty() && !time.is_empty() {
+        let date = lookup_skeleton(calendar, date).unwrap_or_else(|| date.to_string());
+        let time = lookup_skeleton(calendar, time).unwrap_or_else(|| time.to_string());
+        let glue = calendar.datetime[if date.contains("MMMM") {
+            DateLength::Long.index()
+        } else {
+            DateLength::Medium.index()
+        }];
+        return glue.replace("{1}", &date).replace("{0}", &time);
+    }
+
+    skeleton
+}
+
+/// Look up a skeleton, falling back to the pattern of the skeleton with an abbreviated
+/// month and weekday, widened again.
+fn lookup_skeleton(calendar: &Calendar, skeleton: &str) -> Option<String> {
+    let find = |skeleton: &str| {
+        calendar
+            .skeletons
+            .iter()
+            .chain(COMMON_SKELETONS)
+            .find(|(name, _)| *name == skeleton)
+            .map(|(_, pattern)| pattern.to_string())
+    };
+    if let Some(pattern) = find(skeleton) {
+        return Some(pattern);
+    }
+
+    let months = skeleton.chars().filter(|c| *c == 'M' || *c == 'L').count();
+    let weekdays = skeleton.chars().filter(|c| *c == 'E').count();
+    let reduced = reduce_field(&reduce_field(skeleton, 'M', 3), 'E', 1);
+    let pattern = find(&reduced)?;
+
+    let pattern = if months > 3 {
+        widen_field(&widen_field(&pattern, 'M', 3, months), 'L', 3, months)
+    } else {
+        pattern
+    };
+    Some(if weekdays > 3 {
+        widen_field(&widen_field(&pattern, 'E', 1, weekdays), 'c', 1, weekdays)
+    } else {
+        pattern
+    })
+}
+
+/// Shorten runs of a field longer than `length` to `length`.
+fn reduce_field(skeleton: &str, field: char, length: usize) -> String {
+    let count = skeleton.chars().filter(|c| *c == field).count();
+    if count <= length {
+        return skeleton.to_string();
+    }
+    let run = field.to_string().repeat(count);
+    skeleton.replace(&run, &field.to_string().repeat(length))
+}
+
+/// Widen the runs of a field in a pattern with at least `min` letters to `length`.
+fn widen_field(pattern: &str, field: char, min: usize, length: usize) -> String {
+    let mut out = String::new();
+    let mut chars = pattern.chars().peekable();
+    let mut quoted = false;
+    while let Some(c) = chars.next() {
+        if c == '\'' {
+            quoted = !quoted;
+        }
+        if quoted || c != field {
+            out.push(c);
+            continue;
+        }
+        let mut count = 1;
+        while chars.peek() == Some(&field) {
+            chars.next();
+            count += 1;
+        }
+        let count = if count >= min { length } else { count };
+        out.extend(std::iter::repeat_n(field, count));
+    }
+    out
+}
+
+/// Format a timestamp with a CLDR date pattern.
+fn format_pattern(calendar: &Calendar, value: &DateTime, pattern: &str) -> String {
+    let fields = value.fields();
+    let mut out = String::new();
+    let mut chars = pattern.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c == '\'' {
+            if chars.peek() == Some(&'\'') {
+                chars.next();
+                out.push('\'');
+                continue;
+            }
+            for c in chars.by_ref() {
+                if c == '\'' {
+                    break;
+                }
+                out.push(c);
+            }
+            continue;
+        }
+        if !c.is_ascii_alphabetic() {
+            out.push(c);
+            continue;
+        }
+
+        let mut count = 1;
+        while chars.peek() == Some(&c) {
+            chars.next();
+            count += 1;
+        }
+        write_field(calendar, &fields, c, count, &mut out);
+    }
+    out
+}
+
+fn write_field(calendar: &Calendar, fields: &Fields, field: char, count: usize, out: &mut String) {
+    let number = |value: i64, out: &mut String| out.push_str(&format!("{:0count$}", value));
+    let month = (fields.month - 1) as usize;
+    match field {
+        'y' if count == 2 => out.push_str(&format!("{:02}", fields.year.rem_euclid(100))),
+        'y' => number(fields.year, out),
+        'M' | 'L' if count <= 2 => number(fields.month, out),
+        'M' | 'L' => {
+            let (wide, abbr) = match (field, calendar.months_standalone) {
+                ('L', Some(standalone)) => standalone,
+                _ => (calendar.months, calendar.months_abbr),
+            };
+            match count {
+                3 => out.push_str(abbr[month]),
+                4 => out.push_str(wide[month]),
+                _ => out.extend(wide[month].chars().next()),
+            }
+        }
+        'd' => number(fields.day, out),
+        'E' | 'c' | 'e' => match count {
+            4 => out.push_str(calendar.weekdays[fields.weekday]),
+            5 => out.extend(calendar.weekdays[fields.weekday].chars().next()),
+            _ => out.push_str(calendar.weekdays_abbr[fields.weekday]),
+        },
+        'a' | 'b' | 'B' => out.push_str(calendar.am_pm[usize::from(fields.hour >= 12)]),
+        'H' => number(i64::from(fields.hour), out),
+        'k' => number(
+            i64::from(if fields.hour == 0 { 24 } else { fields.hour }),
+            out,
+        ),
+        'K' => number(i64::from(fields.hour % 12), out),
+        'h' => number(
+            i64::from(if fields.hour.is_multiple_of(12) {
+                12
+            } else {
+                fields.hour % 12
+            }),
+            out,
+        ),
+        'm' => number(i64::from(fields.minute), out),
+        's' => number(i64::from(fields.second), out),
+        'S' => out.push_str(&"0".repeat(count)),
+        'z' | 'O' | 'v' | 'V' => {
+            out.push_str(&gmt_offset(calendar, fields.offset, count >= 4));
+        }
+        'Z' | 'x' | 'X' => {
+            let separator = if count >= 3 { ":" } else { "" };
+            if fields.offset == 0 && field != 'x' && count >= 5 {
+                out.push('Z');
+            } else {
+                let sign = if fields.offset < 0 { '-' } else { '+' };
+                let offset = fields.offset.abs();
+                out.push_str(&format!(
+                    "{}{:02}{}{:02}",
+                    sign,
+                    offset / 60,
+                    separator,
+                    offset % 60
+                ));
+            }
+        }
+        _ => {}
+    }
+}
+
+/// A localized GMT offset: `GMT+1` or `GMT+01:00` in the long form.
+fn gmt_offset(calendar: &Calendar, offset: i32, long: bool) -> String {
+    if offset == 0 {
+        return calendar.gmt.to_string();
+    }
+    let sign = if offset < 0 { '-' } else { '+' };
+    let (hours, minutes) = (offset.abs() / 60, offset.abs() % 60);
+    match (long, minutes) {
+        (true, _) => format!("{}{}{:02}:{:02}", calendar.gmt, sign, hours, minutes),
+        (false, 0) => format!("{}{}{}", calendar.gmt, sign, hours),
+        (false, _) => format!("{}{}{}:{:02}", calendar.gmt, sign, hours, minutes),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(value: &str) -> DateTime {
+        value.parse().unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(at("0"), DateTime::from_timestamp(0));
+        assert_eq!(at("1709649000.75").timestamp(), 1_709_649_000);
+        assert_eq!(at("2024-03-05T14:30:00Z").timestamp(), 1_709_649_000);
+        let offset = at("2024-03-05T15:30+01:00");
+        assert_eq!((offset.timestamp(), offset.offset()), (1_709_649_000, 60));
+        assert_eq!(at("1969-12-31").timestamp(), -86_400);
+        assert!("2024-02-30".parse::<DateTime>().is_err());
+        assert!("yesterday".parse::<DateTime>().is_err());
+        assert!("2024-03-05T10:00+aé1".parse::<DateTime>().is_err());
+        assert!("100000000000000-01-01".parse::<DateTime>().is_err());
+        assert!(at("1000000-12-31").timestamp() > 0);
+        assert!("1000001-01-01".parse::<DateTime>().is_err());
+
+        let offset = at("2024-03-05T10:00:30.250-09:30");
+        assert_eq!(offset.timestamp(), 1_709_667_030);
+        assert_eq!(offset.offset(), -570);
+        for invalid in [
+            "2024-03-05T10:00+99:99",
+            "2024-03-05T10:00+24:00",
+            "2024-03-05T10:00+01:60",
+            "2024-03-05T10:00:30.xyz",
+            "2024-03-05T10:00:30.",
+            "2024-03-05T10:00.5:30",
+            "2024-03-05T+1:00",
+        ] {
+            assert!(invalid.parse::<DateTime>().is_err(), "{}", invalid);
+        }
+    }
+
+    #[test]
+    fn test_lengths() {
+        let when = at("2024-03-05T14:30:09+01:00");
+        assert_eq!(
+            format_date("en", &when, DateLength::Full),
+            "Tuesday, March 5, 2024"
+        );
+        assert_eq!(format_date("en", &when, DateLength::Short), "3/5/24");
+        assert_eq!(format_date("en-GB", &when, DateLength::Short), "05/03/2024");
+        assert_eq!(
+            format_date("ru", &when, DateLength::Long),
+            "5 марта 2024 г."
+        );
+        assert_eq!(
+            format_date("ja", &when, DateLength::Full),
+            "2024年3月5日火曜日"
+        );
+        assert_eq!(
+            format_time("en", &when, DateLength::Long),
+            "2:30:09\u{202f}PM GMT+1"
+        );
+        assert_eq!(
+            format_time("fr", &when, DateLength::Full),
+            "14:30:09 UTC+01:00"
+        );
+        assert_eq!(
+            format_datetime("de", &when, DateLength::Short),
+            "05.03.24, 14:30"
+        );
+        assert_eq!(
+            format_datetime("en", &when, DateLength::Long),
+            "March 5, 2024 at 2:30:09\u{202f}PM GMT+1"
+        );
+    }
+
+    #[test]
+    fn test_skeletons() {
+        let when = at("2024-03-05T00:05:00Z");
+        assert_eq!(format_skeleton("en", &when, "yMMMd"), "Mar 5, 2024");
+        assert_eq!(format_skeleton("en", &when, "dMMMy"), "Mar 5, 2024");
+        assert_eq!(
+            format_skeleton("de", &when, "yMMMMEEEEd"),
+            "Dienstag, 5. März 2024"
+        );
+        assert_eq!(format_skeleton("pl", &when, "yMMMM"), "marzec 2024");
+        assert_eq!(format_skeleton("pl", &when, "MMMMd"), "5 marca");
+        assert_eq!(format_skeleton("en", &when, "jm"), "12:05\u{202f}AM");
+        assert_eq!(format_skeleton("ja", &when, "hm"), "午前0:05");
+        assert_eq!(
+            format_skeleton("en", &when, "yMdjm"),
+            "3/5/2024, 12:05\u{202f}AM"
+        );
+    }
+}
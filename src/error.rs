@@ -10,10 +10,60 @@ pub enum LinguaError {
         #[source]
         error: serde_json::Error,
     },
+    #[error("Failed to parse Fluent file {file}: {error}")]
+    FluentParse {
+        file: String,
+        #[source]
+        error: crate::fluent::ParseError,
+    },
+    #[error("Failed to parse gettext file {file}: {error}")]
+    GettextParse {
+        file: String,
+        #[source]
+        error: crate::gettext::ParseError,
+    },
+    #[cfg(feature = "yaml")]
+    #[error("Failed to parse YAML file {file}: {error}")]
+    YamlParse {
+        file: String,
+        #[source]
+        error: serde_yaml::Error,
+    },
+    #[cfg(feature = "toml")]
+    #[error("Failed to parse TOML file {file}: {error}")]
+    TomlParse {
+        file: String,
+        #[source]
+        error: toml::de::Error,
+    },
+    #[cfg(feature = "xliff")]
+    #[error("Failed to parse XLIFF file {file}: {error}")]
+    XliffParse {
+        file: String,
+        #[source]
+        error: crate::xliff::ParseError,
+    },
     #[error("Language '{0}' is not available")]
     LanguageNotAvailable(String),
     #[error("Translation key '{0}' not found")]
     KeyNotFound(String),
+    #[error("Missing or non-numeric plural count for key '{0}'")]
+    InvalidPluralCount(String),
+    #[error("Invalid value '{value}' for parameter '{name}' of key '{key}'")]
+    InvalidArgument {
+        key: String,
+        name: String,
+        value: String,
+    },
+    #[error("Invalid message format for key '{key}' in language '{file}': {error}")]
+    MessageFormat {
+        file: String,
+        key: String,
+        #[source]
+        error: crate::icu::ParseError,
+    },
+    #[error("Invalid language tag '{0}'")]
+    InvalidLocale(String),
     #[error("Language file for '{0}' not found")]
     LanguageFileNotFound(String),
     #[error("Lingua library has not been initialized")]
@@ -0,0 +1,965 @@
+//! Project Fluent (`.ftl`) resources.
+//!
+//! Fluent resources are converted into the nested map used for JSON catalogs:
+//!
+//! * Message ids are split on `-` into nested keys and attributes become child keys, so
+//!   `menu-file = File` with `.save = Save` is looked up as `menu.file` and `menu.file.save`.
+//!   When a key has both a value and children, the value is stored under `$value`.
+//! * A message context precedes the id, separated by `--`: `button--open = Öffnen` is stored
+//!   under `$context.button.open`, see [`Lingua::translate_ctx`].
+//! * Variables (`{ $name }`) become `{{name}}` placeholders.
+//! * Message and term references are inlined, including term arguments and attributes.
+//! * Select expressions on a variable are hoisted to the top of the message. Variants keyed
+//!   by CLDR plural categories or numbers become plural objects (`$plural`, `one`, `=0`, ...),
+//!   or ordinal objects (`$ordinal`) when the selector is `NUMBER($n, type: "ordinal")`;
+//!   all others become select objects (`$select`). The default variant is also stored as
+//!   `other`.
+//!
+//! [`Lingua::translate_ctx`]: crate::prelude::Lingua::translate_ctx
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+use std::fmt;
+
+use crate::plural::PluralCategory;
+
+/// Maximum nesting of message and term references, to guard against cycles.
+const MAX_REFERENCE_DEPTH: usize = 16;
+
+/// An error in a Fluent resource.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// Line of the resource where the error was detected, starting at 1, or 0 for
+    /// errors in references between messages.
+    pub line: usize,
+    /// Description of the error.
+    pub message: String,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.line == 0 {
+            f.write_str(&self.message)
+        } else {
+            write!(f, "{} on line {}", self.message, self.line)
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+#[derive(Debug, Clone)]
+enum Element {
+    Text(String),
+    Placeable(Expression),
+}
+
+#[derive(Debug, Clone)]
+enum Expression {
+    StringLiteral(String),
+    NumberLiteral(String),
+    Variable(String),
+    MessageReference {
+        id: String,
+        attribute: Option<String>,
+    },
+    TermReference {
+        id: String,
+        attribute: Option<String>,
+        arguments: Vec<(String, String)>,
+    },
+    Function {
+        arguments: Vec<Expression>,
+        /// `NUMBER(..., type: "ordinal")`, which selects by the ordinal plural rules.
+        ordinal: bool,
+    },
+    Select {
+        selector: Box<Expression>,
+        variants: Vec<Variant>,
+    },
+}
+
+#[derive(Debug, Clone)]
+struct Variant {
+    key: String,
+    default: bool,
+    value: Vec<Element>,
+}
+
+#[derive(Debug, Clone)]
+struct Entry {
+    value: Option<Vec<Element>>,
+    attributes: Vec<(String, Vec<Element>)>,
+}
+
+type Entries = HashMap<String, Entry>;
+
+/// Parse a Fluent resource into a nested translation map.
+///
+/// # Example
+///
+/// ```rust
+/// use lingua_i18n_rs::fluent;
+///
+/// let map = fluent::parse("menu-file = File\n    .save = Save\n")?;
+/// assert_eq!(map["menu"]["file"]["save"], "Save");
+/// # Ok::<(), fluent::ParseError>(())
+/// ```
+pub fn parse(source: &str) -> Result<Map<String, Value>, ParseError> {
+    let mut parser = Parser {
+        src: source,
+        pos: 0,
+    };
+    let (messages, terms) = parser.resource()?;
+
+    let resolver = Resolver {
+        messages: &messages,
+        terms: &terms,
+    };
+
+    let mut map = Map::new();
+    let mut ids: Vec<&String> = messages.keys().collect();
+    ids.sort();
+    for id in ids {
+        let entry = &messages[id];
+        let path: Vec<&str> = match id.split_once("--") {
+            Some((context, key)) => ["$context", context]
+                .into_iter()
+                .chain(key.split('-'))
+                .collect(),
+            None => id.split('-').collect(),
+        };
+
+        if let Some(value) = &entry.value {
+            let value = resolver.convert(value)?;
+            if entry.attributes.is_empty() {
+                insert_path(&mut map, &path, value);
+            } else {
+                let mut value_path = path.clone();
+                value_path.push("$value");
+                insert_path(&mut map, &value_path, value);
+            }
+        }
+
+        for (name, pattern) in &entry.attributes {
+            let mut attribute_path = path.clone();
+            attribute_path.push(name);
+            insert_path(&mut map, &attribute_path, resolver.convert(pattern)?);
+        }
+    }
+
+    Ok(map)
+}
+
+/// Insert a value at a nested path. Strings in the way are moved to `$value`.
+fn insert_path(map: &mut Map<String, Value>, path: &[&str], value: Value) {
+    let (last, parents) = path.split_last().expect("path is never empty");
+    let mut current = map;
+    for part in parents {
+        let entry = current
+            .entry(part.to_string())
+            .or_insert_with(|| Value::Object(Map::new()));
+        if !entry.is_object() {
+            let previous = entry.take();
+            let mut object = Map::new();
+            object.insert("$value".to_string(), previous);
+            *entry = Value::Object(object);
+        }
+        current = entry.as_object_mut().expect("entry was made an object");
+    }
+
+    match current.get_mut(*last) {
+        Some(Value::Object(existing)) if !value.is_object() => {
+            existing.insert("$value".to_string(), value);
+        }
+        _ => {
+            current.insert(last.to_string(), value);
+        }
+    }
+}
+
+struct Parser<'a> {
+    src: &'a str,
+    pos: usize,
+}
+
+impl Parser<'_> {
+    fn error(&self, message: &str) -> ParseError {
+        ParseError {
+            line: self.src[..self.pos].matches('\n').count() + 1,
+            message: message.to_string(),
+        }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.src[self.pos..].chars().next()
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.pos += c.len_utf8();
+        Some(c)
+    }
+
+    fn rest(&self) -> &str {
+        &self.src[self.pos..]
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), ParseError> {
+        if self.peek() == Some(expected) {
+            self.bump();
+            Ok(())
+        } else {
+            Err(self.error(&format!("Expected '{}'", expected)))
+        }
+    }
+
+    fn skip_inline_blank(&mut self) {
+        while self.peek() == Some(' ') {
+            self.bump();
+        }
+    }
+
+    /// Skip spaces and line breaks.
+    fn skip_blank(&mut self) {
+        while matches!(self.peek(), Some(' ') | Some('\n') | Some('\r')) {
+            self.bump();
+        }
+    }
+
+    fn skip_line(&mut self) {
+        while let Some(c) = self.bump() {
+            if c == '\n' {
+                break;
+            }
+        }
+    }
+
+    fn identifier(&mut self) -> Result<String, ParseError> {
+        let start = self.pos;
+        if !self.peek().is_some_and(|c| c.is_ascii_alphabetic()) {
+            return Err(self.error("Expected an identifier"));
+        }
+        while self
+            .peek()
+            .is_some_and(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+        {
+            self.bump();
+        }
+        Ok(self.src[start..self.pos].to_string())
+    }
+
+    /// Parse all entries, returning the messages and the terms by id.
+    fn resource(&mut self) -> Result<(Entries, Entries), ParseError> {
+        let mut messages = HashMap::new();
+        let mut terms = HashMap::new();
+
+        while let Some(c) = self.peek() {
+            match c {
+                '\n' | '\r' | ' ' => {
+                    let line_end = self.rest().find('\n').map(|i| self.pos + i);
+                    let line = &self.src[self.pos..line_end.unwrap_or(self.src.len())];
+                    if !line.trim().is_empty() {
+                        return Err(self.error("Unexpected indented content"));
+                    }
+                    self.skip_line();
+                }
+                '#' => self.skip_line(),
+                '-' => {
+                    self.bump();
+                    let id = self.identifier()?;
+                    let entry = self.entry()?;
+                    if entry.value.is_none() {
+                        return Err(self.error(&format!("Term '-{}' has no value", id)));
+                    }
+                    terms.insert(id, entry);
+                }
+                _ => {
+                    let id = self.identifier()?;
+                    let entry = self.entry()?;
+                    if entry.value.is_none() && entry.attributes.is_empty() {
+                        return Err(self.error(&format!("Message '{}' has no value", id)));
+                    }
+                    messages.insert(id, entry);
+                }
+            }
+        }
+
+        Ok((messages, terms))
+    }
+
+    fn entry(&mut self) -> Result<Entry, ParseError> {
+        self.skip_inline_blank();
+        self.expect('=')?;
+        let value = self.pattern(false)?;
+
+        let mut attributes = Vec::new();
+        loop {
+            let save = self.pos;
+            self.skip_blank();
+            if self.pos > save && self.src[save..self.pos].contains('\n') {
+                // Attributes must be indented, so only consider the last line break.
+                let line_start = self.src[..self.pos].rfind('\n').map_or(0, |i| i + 1);
+                if line_start < self.pos && self.peek() == Some('.') {
+                    self.bump();
+                    let name = self.identifier()?;
+                    self.skip_inline_blank();
+                    self.expect('=')?;
+                    match self.pattern(false)? {
+                        Some(pattern) => attributes.push((name, pattern)),
+                        None => return Err(self.error("Attribute has no value")),
+                    }
+                    continue;
+                }
+            }
+            self.pos = save;
+            break;
+        }
+
+        if self.peek().is_some() {
+            self.skip_line();
+        }
+
+        Ok(Entry { value, attributes })
+    }
+
+    /// Whether the line starting at `pos` continues the current pattern.
+    fn is_continuation(&self, pos: usize) -> bool {
+        let line = &self.src[pos..];
+        let indent = line.len() - line.trim_start_matches(' ').len();
+        let first = line[indent..].chars().next();
+        indent > 0
+            && !matches!(
+                first,
+                None | Some('\n') | Some('\r') | Some('[') | Some('*') | Some('.') | Some('}')
+            )
+    }
+
+    /// Parse a pattern. Inside variants, a closing brace ends the pattern.
+    fn pattern(&mut self, in_variant: bool) -> Result<Option<Vec<Element>>, ParseError> {
+        self.skip_inline_blank();
+        let mut elements = Vec::new();
+        let mut text = String::new();
+
+        while let Some(c) = self.peek() {
+            match c {
+                '{' => {
+                    if !text.is_empty() {
+                        elements.push(Element::Text(std::mem::take(&mut text)));
+                    }
+                    self.bump();
+                    elements.push(Element::Placeable(self.placeable()?));
+                }
+                '}' if in_variant => break,
+                '}' => return Err(self.error("Unbalanced closing brace")),
+                '\r' => {
+                    self.bump();
+                }
+                '\n' => {
+                    // Look past blank lines for a continuation line.
+                    let mut next = self.pos + 1;
+                    let mut blank_lines = 0;
+                    loop {
+                        let line = &self.src[next..];
+                        let end = line.find('\n');
+                        if line[..end.unwrap_or(line.len())].trim().is_empty() {
+                            match end {
+                                Some(end) => {
+                                    next += end + 1;
+                                    blank_lines += 1;
+                                }
+                                None => break,
+                            }
+                        } else {
+                            break;
+                        }
+                    }
+                    if next < self.src.len() && self.is_continuation(next) {
+                        text.push_str(&"\n".repeat(blank_lines + 1));
+                        self.pos = next;
+                        let line = &self.src[next..];
+                        let indent = line.len() - line.trim_start_matches(' ').len();
+                        // Keep the indentation as a marker, it is dedented in `finish`.
+                        text.push_str(&" ".repeat(indent));
+                        self.pos += indent;
+                    } else {
+                        break;
+                    }
+                }
+                _ => {
+                    self.bump();
+                    text.push(c);
+                }
+            }
+        }
+
+        if !text.is_empty() {
+            elements.push(Element::Text(text));
+        }
+        Ok(finish(elements))
+    }
+
+    fn placeable(&mut self) -> Result<Expression, ParseError> {
+        self.skip_blank();
+        let expression = self.inline_expression()?;
+        self.skip_blank();
+
+        let expression = if self.rest().starts_with("->") {
+            self.pos += 2;
+            let variants = self.variants()?;
+            Expression::Select {
+                selector: Box::new(expression),
+                variants,
+            }
+        } else {
+            expression
+        };
+
+        self.skip_blank();
+        self.expect('}')?;
+        Ok(expression)
+    }
+
+    fn variants(&mut self) -> Result<Vec<Variant>, ParseError> {
+        let mut variants = Vec::new();
+        loop {
+            self.skip_blank();
+            let default = self.peek() == Some('*');
+            if default {
+                self.bump();
+            }
+            if self.peek() != Some('[') {
+                break;
+            }
+            self.bump();
+            self.skip_blank();
+            let start = self.pos;
+            while self
+                .peek()
+                .is_some_and(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.'))
+            {
+                self.bump();
+            }
+            let key = self.src[start..self.pos].to_string();
+            if key.is_empty() {
+                return Err(self.error("Expected a variant key"));
+            }
+            self.skip_blank();
+            self.expect(']')?;
+            let value = self.pattern(true)?.unwrap_or_default();
+            variants.push(Variant {
+                key,
+                default,
+                value,
+            });
+        }
+
+        match variants.iter().filter(|v| v.default).count() {
+            1 => Ok(variants),
+            0 => Err(self.error("Select expression has no default variant")),
+            _ => Err(self.error("Select expression has more than one default variant")),
+        }
+    }
+
+    fn inline_expression(&mut self) -> Result<Expression, ParseError> {
+        match self.peek() {
+            Some('"') => {
+                self.bump();
+                let mut value = String::new();
+                loop {
+                    match self.bump() {
+                        Some('"') => break,
+                        Some('\\') => match self.bump() {
+                            Some('"') => value.push('"'),
+                            Some('\\') => value.push('\\'),
+                            Some(u @ ('u' | 'U')) => {
+                                let len = if u == 'u' { 4 } else { 6 };
+                                let hex = self.rest().get(..len).unwrap_or("");
+                                let c = u32::from_str_radix(hex, 16)
+                                    .ok()
+                                    .and_then(char::from_u32)
+                                    .ok_or_else(|| self.error("Invalid unicode escape"))?;
+                                self.pos += len;
+                                value.push(c);
+                            }
+                            _ => return Err(self.error("Invalid escape sequence")),
+                        },
+                        Some('\n') | None => return Err(self.error("Unterminated string literal")),
+                        Some(c) => value.push(c),
+                    }
+                }
+                Ok(Expression::StringLiteral(value))
+            }
+            Some('$') => {
+                self.bump();
+                Ok(Expression::Variable(self.identifier()?))
+            }
+            Some('{') => {
+                self.bump();
+                self.placeable()
+            }
+            Some(c)
+                if c.is_ascii_digit()
+                    || (c == '-' && self.rest()[1..].starts_with(|c: char| c.is_ascii_digit())) =>
+            {
+                let start = self.pos;
+                self.bump();
+                while self.peek().is_some_and(|c| c.is_ascii_digit() || c == '.') {
+                    self.bump();
+                }
+                Ok(Expression::NumberLiteral(
+                    self.src[start..self.pos].to_string(),
+                ))
+            }
+            Some('-') => {
+                self.bump();
+                let id = self.identifier()?;
+                let attribute = self.attribute_accessor()?;
+                let mut arguments = Vec::new();
+                self.skip_blank();
+                if self.peek() == Some('(') {
+                    for argument in self.call_arguments()? {
+                        match argument {
+                            (
+                                Some(name),
+                                Expression::StringLiteral(value) | Expression::NumberLiteral(value),
+                            ) => arguments.push((name, value)),
+                            _ => return Err(self.error("Term arguments must be named literals")),
+                        }
+                    }
+                }
+                Ok(Expression::TermReference {
+                    id,
+                    attribute,
+                    arguments,
+                })
+            }
+            Some(c) if c.is_ascii_alphabetic() => {
+                let id = self.identifier()?;
+                if self.peek() == Some('(') {
+                    let (named, positional): (Vec<_>, Vec<_>) = self
+                        .call_arguments()?
+                        .into_iter()
+                        .partition(|(name, _)| name.is_some());
+                    let ordinal = id == "NUMBER"
+                        && named.iter().any(|(name, value)| {
+                            name.as_deref() == Some("type")
+                                && matches!(value, Expression::StringLiteral(value) if value == "ordinal")
+                        });
+                    return Ok(Expression::Function {
+                        arguments: positional
+                            .into_iter()
+                            .map(|(_, expression)| expression)
+                            .collect(),
+                        ordinal,
+                    });
+                }
+                let attribute = self.attribute_accessor()?;
+                Ok(Expression::MessageReference { id, attribute })
+            }
+            _ => Err(self.error("Expected an expression")),
+        }
+    }
+
+    fn attribute_accessor(&mut self) -> Result<Option<String>, ParseError> {
+        if self.peek() == Some('.') {
+            self.bump();
+            Ok(Some(self.identifier()?))
+        } else {
+            Ok(None)
+        }
+    }
+
+    fn call_arguments(&mut self) -> Result<Vec<(Option<String>, Expression)>, ParseError> {
+        self.expect('(')?;
+        let mut arguments = Vec::new();
+        loop {
+            self.skip_blank();
+            if self.peek() == Some(')') {
+                self.bump();
+                break;
+            }
+            let expression = self.inline_expression()?;
+            self.skip_blank();
+            let argument = match (&expression, self.peek()) {
+                (
+                    Expression::MessageReference {
+                        id,
+                        attribute: None,
+                    },
+                    Some(':'),
+                ) => {
+                    self.bump();
+                    self.skip_blank();
+                    (Some(id.clone()), self.inline_expression()?)
+                }
+                _ => (None, expression),
+            };
+            arguments.push(argument);
+            self.skip_blank();
+            match self.peek() {
+                Some(',') => {
+                    self.bump();
+                }
+                Some(')') => {}
+                _ => return Err(self.error("Expected ',' or ')'")),
+            }
+        }
+        Ok(arguments)
+    }
+}
+
+/// Remove the common indentation of continuation lines and trailing blanks of a pattern.
+fn finish(mut elements: Vec<Element>) -> Option<Vec<Element>> {
+    let indent = elements
+        .iter()
+        .filter_map(|element| match element {
+            Element::Text(text) => Some(text),
+            Element::Placeable(_) => None,
+        })
+        .flat_map(|text| text.split('\n').skip(1))
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| line.len() - line.trim_start_matches(' ').len())
+        .min()
+        .unwrap_or(0);
+
+    for element in &mut elements {
+        if let Element::Text(text) = element {
+            let mut lines = text.split('\n');
+            let mut dedented = lines.next().unwrap_or("").to_string();
+            for line in lines {
+                dedented.push('\n');
+                dedented.push_str(line.get(indent..).unwrap_or(line.trim_start_matches(' ')));
+            }
+            *text = dedented;
+        }
+    }
+
+    if let Some(Element::Text(text)) = elements.last_mut() {
+        let trimmed = text.trim_end().len();
+        text.truncate(trimmed);
+        if text.is_empty() {
+            elements.pop();
+        }
+    }
+    if let Some(Element::Text(text)) = elements.first_mut() {
+        let trimmed = text.trim_start_matches('\n').to_string();
+        *text = trimmed;
+        if text.is_empty() {
+            elements.remove(0);
+        }
+    }
+
+    if elements.is_empty() {
+        None
+    } else {
+        Some(elements)
+    }
+}
+
+/// Elements after references have been inlined.
+#[derive(Debug, Clone)]
+enum Flat {
+    Text(String),
+    Variable(String),
+    Select {
+        variable: String,
+        ordinal: bool,
+        variants: Vec<(String, bool, Vec<Flat>)>,
+    },
+}
+
+struct Resolver<'a> {
+    messages: &'a HashMap<String, Entry>,
+    terms: &'a HashMap<String, Entry>,
+}
+
+impl Resolver<'_> {
+    fn convert(&self, pattern: &[Element]) -> Result<Value, ParseError> {
+        let flat = self.flatten(pattern, None, 0)?;
+        Ok(to_value(&flat))
+    }
+
+    fn reference_error(message: String) -> ParseError {
+        ParseError { line: 0, message }
+    }
+
+    fn entry_pattern<'b>(
+        entries: &'b HashMap<String, Entry>,
+        prefix: &str,
+        id: &str,
+        attribute: Option<&str>,
+    ) -> Result<&'b [Element], ParseError> {
+        let entry = entries.get(id).ok_or_else(|| {
+            Self::reference_error(format!("Unknown reference '{}{}'", prefix, id))
+        })?;
+        let pattern = match attribute {
+            Some(name) => entry
+                .attributes
+                .iter()
+                .find(|(attribute, _)| attribute == name)
+                .map(|(_, pattern)| pattern),
+            None => entry.value.as_ref(),
+        };
+        pattern.map(Vec::as_slice).ok_or_else(|| {
+            Self::reference_error(format!(
+                "Reference '{}{}{}' has no value",
+                prefix,
+                id,
+                attribute.map(|a| format!(".{}", a)).unwrap_or_default()
+            ))
+        })
+    }
+
+    fn flatten(
+        &self,
+        pattern: &[Element],
+        arguments: Option<&[(String, String)]>,
+        depth: usize,
+    ) -> Result<Vec<Flat>, ParseError> {
+        if depth > MAX_REFERENCE_DEPTH {
+            return Err(Self::reference_error(
+                "Cyclic or too deeply nested reference".to_string(),
+            ));
+        }
+
+        let mut flat = Vec::new();
+        for element in pattern {
+            match element {
+                Element::Text(text) => flat.push(Flat::Text(text.clone())),
+                Element::Placeable(expression) => {
+                    flat.extend(self.flatten_expression(expression, arguments, depth)?)
+                }
+            }
+        }
+        Ok(flat)
+    }
+
+    fn flatten_expression(
+        &self,
+        expression: &Expression,
+        arguments: Option<&[(String, String)]>,
+        depth: usize,
+    ) -> Result<Vec<Flat>, ParseError> {
+        Ok(match expression {
+            Expression::StringLiteral(value) | Expression::NumberLiteral(value) => {
+                vec![Flat::Text(value.clone())]
+            }
+            Expression::Variable(name) => match Self::term_argument(arguments, name) {
+                Some(value) => vec![Flat::Text(value.to_string())],
+                None => vec![Flat::Variable(name.clone())],
+            },
+            Expression::MessageReference { id, attribute } => {
+                let pattern = Self::entry_pattern(self.messages, "", id, attribute.as_deref())?;
+                self.flatten(pattern, None, depth + 1)?
+            }
+            Expression::TermReference {
+                id,
+                attribute,
+                arguments: term_arguments,
+            } => {
+                let pattern = Self::entry_pattern(self.terms, "-", id, attribute.as_deref())?;
+                self.flatten(pattern, Some(term_arguments), depth + 1)?
+            }
+            Expression::Function {
+                arguments: call, ..
+            } => match call.first() {
+                Some(first) => self.flatten_expression(first, arguments, depth)?,
+                None => Vec::new(),
+            },
+            Expression::Select { selector, variants } => {
+                let default = variants
+                    .iter()
+                    .find(|v| v.default)
+                    .expect("parser guarantees a default variant");
+
+                match self.selector_variable(selector, arguments)? {
+                    Ok(variable) => {
+                        let mut flat_variants = Vec::new();
+                        for variant in variants {
+                            flat_variants.push((
+                                variant.key.clone(),
+                                variant.default,
+                                self.flatten(&variant.value, arguments, depth)?,
+                            ));
+                        }
+                        vec![Flat::Select {
+                            variable,
+                            ordinal: matches!(
+                                **selector,
+                                Expression::Function { ordinal: true, .. }
+                            ),
+                            variants: flat_variants,
+                        }]
+                    }
+                    Err(value) => {
+                        let variant = variants.iter().find(|v| v.key == value).unwrap_or(default);
+                        self.flatten(&variant.value, arguments, depth)?
+                    }
+                }
+            }
+        })
+    }
+
+    fn term_argument<'b>(arguments: Option<&'b [(String, String)]>, name: &str) -> Option<&'b str> {
+        arguments?
+            .iter()
+            .find(|(argument, _)| argument == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Resolve a selector to a runtime variable (`Ok`) or a value known at load time (`Err`).
+    fn selector_variable(
+        &self,
+        selector: &Expression,
+        arguments: Option<&[(String, String)]>,
+    ) -> Result<Result<String, String>, ParseError> {
+        match selector {
+            Expression::Variable(name) => Ok(match Self::term_argument(arguments, name) {
+                Some(value) => Err(value.to_string()),
+                None if arguments.is_some() => Err(String::new()),
+                None => Ok(name.clone()),
+            }),
+            Expression::Function {
+                arguments: call, ..
+            } => match call.first() {
+                Some(first) => self.selector_variable(first, arguments),
+                None => Ok(Err(String::new())),
+            },
+            other => {
+                let flat = self.flatten_expression(other, arguments, 0)?;
+                let text = flat
+                    .iter()
+                    .map(|f| match f {
+                        Flat::Text(text) => text.as_str(),
+                        _ => "",
+                    })
+                    .collect::<String>();
+                Ok(Err(text))
+            }
+        }
+    }
+}
+
+/// Convert flattened elements to a JSON value, hoisting the first select expression.
+fn to_value(flat: &[Flat]) -> Value {
+    let Some(index) = flat.iter().position(|f| matches!(f, Flat::Select { .. })) else {
+        let mut text = String::new();
+        for f in flat {
+            match f {
+                Flat::Text(t) => text.push_str(t),
+                Flat::Variable(name) => {
+                    text.push_str("{{");
+                    text.push_str(name);
+                    text.push_str("}}");
+                }
+                Flat::Select { .. } => unreachable!(),
+            }
+        }
+        return Value::String(text);
+    };
+
+    let Flat::Select {
+        variable,
+        ordinal,
+        variants,
+    } = &flat[index]
+    else {
+        unreachable!()
+    };
+    let (prefix, suffix) = (&flat[..index], &flat[index + 1..]);
+
+    let is_plural = variants.iter().all(|(key, _, _)| {
+        PluralCategory::from_keyword(key).is_some() || key.parse::<f64>().is_ok()
+    });
+
+    let mut object = Map::new();
+    let marker = match (is_plural, ordinal) {
+        (true, true) => "$ordinal",
+        (true, false) => "$plural",
+        _ => "$select",
+    };
+    object.insert(marker.to_string(), Value::String(variable.clone()));
+
+    for (key, default, value) in variants {
+        let mut combined = prefix.to_vec();
+        combined.extend(value.iter().cloned());
+        combined.extend(suffix.iter().cloned());
+        let value = to_value(&combined);
+
+        let key = if is_plural && key.parse::<f64>().is_ok() {
+            format!("={}", key)
+        } else {
+            key.clone()
+        };
+        if *default && key != "other" {
+            object.insert("other".to_string(), value.clone());
+        }
+        object.entry(key).or_insert(value);
+    }
+
+    Value::Object(object)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_messages_and_attributes() {
+        let map = parse(
+            "# Comment\nwelcome = Welcome, { $name }!\nmenu = Menu\nmenu-file = File\n    .save = Save\n    .open = Open\nmulti =\n    First line\n      indented\n    last\nbutton--open = Öffnen\nstatus--file-open = Offen\n",
+        )
+        .unwrap();
+
+        assert_eq!(map["welcome"], "Welcome, {{name}}!");
+        assert_eq!(map["menu"]["$value"], "Menu");
+        assert_eq!(map["menu"]["file"]["$value"], "File");
+        assert_eq!(map["menu"]["file"]["save"], "Save");
+        assert_eq!(map["multi"], "First line\n  indented\nlast");
+        assert_eq!(map["$context"]["button"]["open"], "Öffnen");
+        assert_eq!(map["$context"]["status"]["file"]["open"], "Offen");
+    }
+
+    #[test]
+    fn test_terms_and_references() {
+        let map = parse(
+            "-brand = Lingua\n    .gender = feminine\n-thing = { $case ->\n    [gen] Dinges\n   *[nom] Ding\n}\nabout = About { -brand }\nwelcome = { about }, { -thing(case: \"gen\") }\npraise = { -brand.gender ->\n    [feminine] She is great\n   *[other] It is great\n}\n",
+        )
+        .unwrap();
+
+        assert_eq!(map["about"], "About Lingua");
+        assert_eq!(map["welcome"], "About Lingua, Dinges");
+        assert_eq!(map["praise"], "She is great");
+    }
+
+    #[test]
+    fn test_selectors() {
+        let map = parse(
+            "emails = You have { $count ->\n    [0] no emails\n    [one] one email\n   *[other] { $count } emails\n} today.\nwho = { $gender ->\n    [male] He\n   *[female] She\n} left\n",
+        )
+        .unwrap();
+
+        assert_eq!(map["emails"]["$plural"], "count");
+        assert_eq!(map["emails"]["=0"], "You have no emails today.");
+        assert_eq!(map["emails"]["one"], "You have one email today.");
+        assert_eq!(map["emails"]["other"], "You have {{count}} emails today.");
+        assert_eq!(map["who"]["$select"], "gender");
+        assert_eq!(map["who"]["male"], "He left");
+        assert_eq!(map["who"]["other"], "She left");
+
+        let map = parse(
+            "place = { NUMBER($n, type: \"ordinal\") ->\n    [one] {$n}st\n    [two] {$n}nd\n   *[other] {$n}th\n}\n",
+        )
+        .unwrap();
+        assert_eq!(map["place"]["$ordinal"], "n");
+        assert_eq!(map["place"]["two"], "{{n}}nd");
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(parse("a = { $x\n").unwrap_err().line, 2);
+        assert!(parse("a = { $x ->\n    [one] One\n}\n").is_err());
+        assert!(parse("a = { b }\n").is_err());
+        assert!(parse("a = { a }\n").is_err());
+    }
+}
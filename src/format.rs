@@ -0,0 +1,250 @@
+//! Catalog file formats.
+//!
+//! Every format is normalised into the nested JSON map used for lookups, so dot-notation
+//! keys, plural objects and select objects work the same way regardless of the source.
+//! YAML, TOML and XLIFF are available behind the `yaml`, `toml` and `xliff` cargo features.
+use crate::error::LinguaError;
+use crate::{fluent, gettext};
+use serde_json::{Map, Value};
+
+/// The format of a translation catalog.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CatalogFormat {
+    /// JSON (`.json`).
+    Json,
+    /// Project Fluent (`.ftl`).
+    Fluent,
+    /// GNU gettext source catalog (`.po`).
+    Po,
+    /// Compiled GNU gettext catalog (`.mo`).
+    Mo,
+    /// YAML (`.yaml` or `.yml`).
+    #[cfg(feature = "yaml")]
+    Yaml,
+    /// TOML (`.toml`).
+    #[cfg(feature = "toml")]
+    Toml,
+    /// XLIFF 1.2 or 2.0 (`.xlf` or `.xliff`).
+    #[cfg(feature = "xliff")]
+    Xliff,
+}
+
+impl CatalogFormat {
+    /// File extensions loaded from the language directory, in merge order.
+    ///
+    /// With every format feature enabled these are the extensions `include_translations!`
+    /// embeds.
+    pub const EXTENSIONS: &[(&str, CatalogFormat)] = &[
+        ("json", CatalogFormat::Json),
+        ("ftl", CatalogFormat::Fluent),
+        ("po", CatalogFormat::Po),
+        ("mo", CatalogFormat::Mo),
+        #[cfg(feature = "yaml")]
+        ("yaml", CatalogFormat::Yaml),
+        #[cfg(feature = "yaml")]
+        ("yml", CatalogFormat::Yaml),
+        #[cfg(feature = "toml")]
+        ("toml", CatalogFormat::Toml),
+        #[cfg(feature = "xliff")]
+        ("xlf", CatalogFormat::Xliff),
+        #[cfg(feature = "xliff")]
+        ("xliff", CatalogFormat::Xliff),
+    ];
+
+    /// Get the format of a file extension, if it is supported.
+    ///
+    /// Extensions are matched case-sensitively, like the file names that are opened for
+    /// a language.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use lingua_i18n_rs::prelude::*;
+    ///
+    /// assert_eq!(CatalogFormat::from_extension("ftl"), Some(CatalogFormat::Fluent));
+    /// assert_eq!(CatalogFormat::from_extension("txt"), None);
+    /// assert_eq!(CatalogFormat::from_extension("JSON"), None);
+    /// ```
+    pub fn from_extension(extension: &str) -> Option<Self> {
+        Self::EXTENSIONS
+            .iter()
+            .find(|(ext, _)| *ext == extension)
+            .map(|(_, format)| *format)
+    }
+
+    /// Parse a catalog into a translation map.
+    ///
+    /// `file` names the catalog in error messages.
+    pub(crate) fn parse(
+        self,
+        file: &str,
+        content: &[u8],
+    ) -> Result<Map<String, Value>, LinguaError> {
+        let file = file.to_string();
+        let text = std::str::from_utf8(content).map_err(|_| "Invalid UTF-8".to_string());
+
+        match self {
+            CatalogFormat::Json => serde_json::from_slice::<Map<String, Value>>(content)
+                .map_err(|error| LinguaError::JsonParse { file, error }),
+            CatalogFormat::Fluent => text
+                .map_err(|message| fluent::ParseError { line: 0, message })
+                .and_then(fluent::parse)
+                .map_err(|error| LinguaError::FluentParse { file, error }),
+            CatalogFormat::Po => text
+                .map_err(|message| gettext::ParseError { line: 0, message })
+                .and_then(gettext::parse_po)
+                .map_err(|error| LinguaError::GettextParse { file, error }),
+            CatalogFormat::Mo => gettext::parse_mo(content)
+                .map_err(|error| LinguaError::GettextParse { file, error }),
+            #[cfg(feature = "yaml")]
+            CatalogFormat::Yaml => serde_yaml::from_slice::<serde_yaml::Value>(content)
+                .map_err(|error| LinguaError::YamlParse { file, error })
+                .map(|value| match yaml_to_json(value) {
+                    Value::Object(map) => map,
+                    Value::Null => Map::new(),
+                    other => Map::from_iter([("$value".to_string(), other)]),
+                }),
+            #[cfg(feature = "toml")]
+            CatalogFormat::Toml => toml::from_slice::<toml::Table>(content)
+                .map_err(|error| LinguaError::TomlParse { file, error })
+                .map(|table| {
+                    table
+                        .into_iter()
+                        .map(|(k, v)| (k, toml_to_json(v)))
+                        .collect()
+                }),
+            #[cfg(feature = "xliff")]
+            CatalogFormat::Xliff => text
+                .map_err(|message| crate::xliff::ParseError {
+                    position: 0,
+                    message,
+                })
+                .and_then(crate::xliff::Document::parse)
+                .map(|document| document.catalog())
+                .map_err(|error| LinguaError::XliffParse { file, error }),
+        }
+    }
+}
+
+/// A catalog file embedded into the binary, see `include_translations!` and
+/// [`LinguaBuilder::with_embedded`].
+///
+/// [`LinguaBuilder::with_embedded`]: crate::prelude::LinguaBuilder::with_embedded
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EmbeddedFile {
+    /// The language code, i.e. the file name without extension.
+    pub lang_code: &'static str,
+    /// The file extension, which selects the [`CatalogFormat`].
+    pub extension: &'static str,
+    /// The content of the file.
+    pub content: &'static [u8],
+}
+
+/// Convert a YAML value, turning non-string mapping keys (such as the plural key `0`)
+/// into strings.
+#[cfg(feature = "yaml")]
+fn yaml_to_json(value: serde_yaml::Value) -> Value {
+    use serde_yaml::Value as Yaml;
+
+    match value {
+        Yaml::Null => Value::Null,
+        Yaml::Bool(b) => Value::Bool(b),
+        Yaml::Number(n) => serde_json::to_value(&n).unwrap_or(Value::Null),
+        Yaml::String(s) => Value::String(s),
+        Yaml::Sequence(items) => Value::Array(items.into_iter().map(yaml_to_json).collect()),
+        Yaml::Mapping(mapping) => Value::Object(
+            mapping
+                .into_iter()
+                .map(|(key, value)| {
+                    let key = match yaml_to_json(key) {
+                        Value::String(s) => s,
+                        other => other.to_string(),
+                    };
+                    (key, yaml_to_json(value))
+                })
+                .collect(),
+        ),
+        Yaml::Tagged(tagged) => yaml_to_json(tagged.value),
+    }
+}
+
+/// Convert a TOML value; dates and times become strings.
+#[cfg(feature = "toml")]
+fn toml_to_json(value: toml::Value) -> Value {
+    use toml::Value as Toml;
+
+    match value {
+        Toml::String(s) => Value::String(s),
+        Toml::Integer(i) => Value::from(i),
+        Toml::Float(f) => Value::from(f),
+        Toml::Boolean(b) => Value::Bool(b),
+        Toml::Datetime(datetime) => Value::String(datetime.to_string()),
+        Toml::Array(items) => Value::Array(items.into_iter().map(toml_to_json).collect()),
+        Toml::Table(table) => Value::Object(
+            table
+                .into_iter()
+                .map(|(key, value)| (key, toml_to_json(value)))
+                .collect(),
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_extension() {
+        assert_eq!(
+            CatalogFormat::from_extension("json"),
+            Some(CatalogFormat::Json)
+        );
+        assert_eq!(CatalogFormat::from_extension("PO"), None);
+        assert_eq!(CatalogFormat::from_extension("txt"), None);
+    }
+
+    #[test]
+    fn test_extensions_match_embedded_extensions() {
+        // `include_translations!` embeds the files listed in the shared list, which
+        // includes the formats of every feature in the same order.
+        let mut shared = lingua_i18n_rs_shared::CATALOG_EXTENSIONS.iter();
+        for (extension, _) in CatalogFormat::EXTENSIONS {
+            assert!(shared.any(|shared| shared == extension), "{}", extension);
+        }
+        #[cfg(all(feature = "yaml", feature = "toml", feature = "xliff"))]
+        assert_eq!(
+            CatalogFormat::EXTENSIONS.len(),
+            lingua_i18n_rs_shared::CATALOG_EXTENSIONS.len()
+        );
+    }
+
+    #[cfg(feature = "yaml")]
+    #[test]
+    fn test_parse_yaml() {
+        let map = CatalogFormat::Yaml
+            .parse(
+                "de.yaml",
+                b"menu:\n  file: Datei\nitems:\n  0: Keine\n  one: Ein Element\n  other: Elemente\n$context:\n  button:\n    open: \xc3\x96ffnen\n",
+            )
+            .unwrap();
+        assert_eq!(map["menu"]["file"], "Datei");
+        assert_eq!(map["$context"]["button"]["open"], "Öffnen");
+        assert_eq!(map["items"]["0"], "Keine");
+        assert!(CatalogFormat::Yaml.parse("de.yaml", b"menu: [").is_err());
+    }
+
+    #[cfg(feature = "toml")]
+    #[test]
+    fn test_parse_toml() {
+        let map = CatalogFormat::Toml
+            .parse(
+                "de.toml",
+                b"greeting = \"Hallo\"\n\n[menu]\nfile = \"Datei\"\n\n[\"$context\".button]\nopen = \"Offen\"\n",
+            )
+            .unwrap();
+        assert_eq!(map["greeting"], "Hallo");
+        assert_eq!(map["$context"]["button"]["open"], "Offen");
+        assert_eq!(map["menu"]["file"], "Datei");
+        assert!(CatalogFormat::Toml.parse("de.toml", b"menu = ").is_err());
+    }
+}
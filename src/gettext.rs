@@ -0,0 +1,648 @@
+//! GNU gettext catalogs (`.po` and `.mo`).
+//!
+//! Entries are converted into the translation map used for JSON catalogs:
+//!
+//! * The `msgid` is used verbatim as a flat key, so source strings containing dots work.
+//! * Entries with a `msgctxt` are stored under `$context.<msgctxt>`, see
+//!   [`Lingua::translate_ctx`].
+//! * Plural entries become objects with the `Plural-Forms` expression under
+//!   `$plural_forms` and the forms under `"0"`, `"1"`, ...; the form is selected by
+//!   evaluating the expression for the `count` parameter.
+//! * Untranslated and fuzzy entries are skipped, so the fallback chain applies to them.
+//!
+//! [`Lingua::translate_ctx`]: crate::prelude::Lingua::translate_ctx
+use serde_json::{Map, Value};
+use std::fmt;
+
+/// The plural expression gettext uses when a catalog has no `Plural-Forms` header.
+const DEFAULT_PLURAL_FORMS: &str = "n != 1";
+
+/// An error in a gettext catalog or `Plural-Forms` expression.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// Line of the catalog where the error was detected, starting at 1, or 0 for binary
+    /// catalogs and expressions.
+    pub line: usize,
+    /// Description of the error.
+    pub message: String,
+}
+
+impl ParseError {
+    fn new(line: usize, message: impl Into<String>) -> Self {
+        Self {
+            line,
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.line == 0 {
+            f.write_str(&self.message)
+        } else {
+            write!(f, "{} on line {}", self.message, self.line)
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// A catalog entry shared by the PO and MO readers.
+#[derive(Debug, Default)]
+struct Message {
+    context: Option<String>,
+    id: String,
+    plural: bool,
+    strings: Vec<String>,
+    fuzzy: bool,
+}
+
+/// Parse the content of a `.po` file into a translation map.
+///
+/// # Example
+///
+/// ```rust
+/// use lingua_i18n_rs::gettext;
+///
+/// let map = gettext::parse_po("msgctxt \"button\"\nmsgid \"Open\"\nmsgstr \"Öffnen\"\n")?;
+/// assert_eq!(map["$context"]["button"]["Open"], "Öffnen");
+/// # Ok::<(), gettext::ParseError>(())
+/// ```
+pub fn parse_po(source: &str) -> Result<Map<String, Value>, ParseError> {
+    let mut messages = Vec::new();
+    let mut current = Message::default();
+    // The string that continuation lines are appended to.
+    let mut target: Option<(&'static str, usize)> = None;
+
+    for (index, raw_line) in source.lines().enumerate() {
+        let line_number = index + 1;
+        let line = raw_line.trim();
+
+        // An entry ends with its msgstr, at the next blank line, flag comment or msgid.
+        let new_entry = line.is_empty()
+            || line.starts_with("#,")
+            || line.starts_with("msgctxt ")
+            || line.starts_with("msgid ");
+        if new_entry && !current.strings.is_empty() {
+            messages.push(std::mem::take(&mut current));
+        }
+
+        if line.is_empty() {
+            target = None;
+            continue;
+        }
+        if line.starts_with("#~") {
+            continue;
+        }
+        if let Some(flags) = line.strip_prefix("#,") {
+            current.fuzzy |= flags.split(',').any(|flag| flag.trim() == "fuzzy");
+            target = None;
+            continue;
+        }
+        if line.starts_with('#') {
+            continue;
+        }
+
+        if line.starts_with('"') {
+            let value = unquote(line, line_number)?;
+            match target {
+                Some(("msgctxt", _)) => current.context.get_or_insert_default().push_str(&value),
+                Some(("msgid", _)) => current.id.push_str(&value),
+                Some(("msgid_plural", _)) => {}
+                Some(("msgstr", index)) => current.strings[index].push_str(&value),
+                _ => return Err(ParseError::new(line_number, "Unexpected string")),
+            }
+            continue;
+        }
+
+        let (keyword, rest) = line
+            .split_once(|c: char| c.is_whitespace())
+            .ok_or_else(|| ParseError::new(line_number, "Expected a keyword and a string"))?;
+        let value = unquote(rest.trim(), line_number)?;
+
+        match keyword {
+            "msgctxt" => {
+                current.context = Some(value);
+                target = Some(("msgctxt", 0));
+            }
+            "msgid" => {
+                current.id = value;
+                target = Some(("msgid", 0));
+            }
+            "msgid_plural" => {
+                current.plural = true;
+                target = Some(("msgid_plural", 0));
+            }
+            "msgstr" => {
+                current.strings = vec![value];
+                target = Some(("msgstr", 0));
+            }
+            _ => {
+                let index = keyword
+                    .strip_prefix("msgstr[")
+                    .and_then(|rest| rest.strip_suffix(']'))
+                    .and_then(|index| index.parse::<usize>().ok())
+                    .ok_or_else(|| {
+                        ParseError::new(line_number, format!("Unknown keyword '{}'", keyword))
+                    })?;
+                if current.strings.len() <= index {
+                    current.strings.resize(index + 1, String::new());
+                }
+                current.strings[index] = value;
+                target = Some(("msgstr", index));
+            }
+        }
+    }
+
+    if !current.strings.is_empty() {
+        messages.push(current);
+    }
+
+    build(messages)
+}
+
+/// Parse a quoted PO string, resolving C escape sequences.
+fn unquote(text: &str, line: usize) -> Result<String, ParseError> {
+    let inner = text
+        .strip_prefix('"')
+        .and_then(|text| text.strip_suffix('"'))
+        .filter(|_| text.len() >= 2)
+        .ok_or_else(|| ParseError::new(line, "Expected a quoted string"))?;
+
+    let mut value = String::with_capacity(inner.len());
+    let mut chars = inner.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            value.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => value.push('\n'),
+            Some('t') => value.push('\t'),
+            Some('r') => value.push('\r'),
+            Some('a') => value.push('\u{7}'),
+            Some('b') => value.push('\u{8}'),
+            Some('f') => value.push('\u{c}'),
+            Some('v') => value.push('\u{b}'),
+            Some('"') => value.push('"'),
+            Some('\\') => value.push('\\'),
+            Some('\'') => value.push('\''),
+            Some('?') => value.push('?'),
+            _ => return Err(ParseError::new(line, "Invalid escape sequence")),
+        }
+    }
+    Ok(value)
+}
+
+/// Parse the content of a compiled `.mo` file into a translation map.
+pub fn parse_mo(bytes: &[u8]) -> Result<Map<String, Value>, ParseError> {
+    let read_u32 = |offset: usize, little_endian: bool| -> Result<usize, ParseError> {
+        let word: [u8; 4] = bytes
+            .get(offset..offset + 4)
+            .and_then(|slice| slice.try_into().ok())
+            .ok_or_else(|| ParseError::new(0, "Truncated MO file"))?;
+        Ok(if little_endian {
+            u32::from_le_bytes(word)
+        } else {
+            u32::from_be_bytes(word)
+        } as usize)
+    };
+
+    let little_endian = match read_u32(0, true)? {
+        0x9504_12de => true,
+        0xde12_0495 => false,
+        _ => return Err(ParseError::new(0, "Not an MO file")),
+    };
+    let count = read_u32(8, little_endian)?;
+    let originals = read_u32(12, little_endian)?;
+    let translations = read_u32(16, little_endian)?;
+    // Both tables must fit in the file before the untrusted count is used.
+    for table in [originals, translations] {
+        count
+            .checked_mul(8)
+            .and_then(|size| size.checked_add(table))
+            .filter(|end| *end <= bytes.len())
+            .ok_or_else(|| ParseError::new(0, "Truncated MO file"))?;
+    }
+
+    let read_string = |table: usize, index: usize| -> Result<&str, ParseError> {
+        let length = read_u32(table + index * 8, little_endian)?;
+        let offset = read_u32(table + index * 8 + 4, little_endian)?;
+        let data = bytes
+            .get(offset..offset.saturating_add(length))
+            .ok_or_else(|| ParseError::new(0, "String out of bounds in MO file"))?;
+        std::str::from_utf8(data).map_err(|_| ParseError::new(0, "Invalid UTF-8 in MO file"))
+    };
+
+    let mut messages = Vec::new();
+    for index in 0..count {
+        let original = read_string(originals, index)?;
+        let translation = read_string(translations, index)?;
+
+        let (context, original) = match original.split_once('\u{4}') {
+            Some((context, original)) => (Some(context.to_string()), original),
+            None => (None, original),
+        };
+        let mut ids = original.split('\0');
+        let id = ids.next().unwrap_or_default().to_string();
+        let plural = ids.next().is_some();
+
+        messages.push(Message {
+            context,
+            id,
+            plural,
+            strings: translation.split('\0').map(str::to_string).collect(),
+            fuzzy: false,
+        });
+    }
+
+    build(messages)
+}
+
+/// Extract the plural expression from the header entry.
+fn plural_forms(header: &str) -> Option<&str> {
+    let line = header
+        .lines()
+        .find_map(|line| line.trim().strip_prefix("Plural-Forms:"))?;
+    let expression = line
+        .split(';')
+        .find_map(|part| part.trim().strip_prefix("plural="))?;
+    Some(expression.trim())
+}
+
+fn build(messages: Vec<Message>) -> Result<Map<String, Value>, ParseError> {
+    let expression = messages
+        .iter()
+        .find(|message| message.id.is_empty() && message.context.is_none())
+        .and_then(|header| header.strings.first())
+        .and_then(|header| plural_forms(header))
+        .unwrap_or(DEFAULT_PLURAL_FORMS)
+        .to_string();
+    PluralExpression::parse(&expression)?;
+
+    let mut map = Map::new();
+    for message in messages {
+        if message.id.is_empty() || message.fuzzy || message.strings.iter().all(String::is_empty) {
+            continue;
+        }
+
+        let value = if message.plural {
+            let mut forms = Map::new();
+            forms.insert(
+                "$plural_forms".to_string(),
+                Value::String(expression.clone()),
+            );
+            for (index, form) in message.strings.into_iter().enumerate() {
+                forms.insert(index.to_string(), Value::String(form));
+            }
+            Value::Object(forms)
+        } else {
+            Value::String(message.strings.into_iter().next().unwrap_or_default())
+        };
+
+        let target = match message.context {
+            Some(context) => map
+                .entry("$context")
+                .or_insert_with(|| Value::Object(Map::new()))
+                .as_object_mut()
+                .expect("$context is always an object")
+                .entry(context)
+                .or_insert_with(|| Value::Object(Map::new()))
+                .as_object_mut()
+                .expect("contexts are always objects"),
+            None => &mut map,
+        };
+        target.insert(message.id, value);
+    }
+
+    Ok(map)
+}
+
+/// A parsed `Plural-Forms` expression, a subset of C over the variable `n`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PluralExpression(Expr);
+
+#[derive(Debug, Clone, PartialEq)]
+enum Expr {
+    N,
+    Number(u64),
+    Not(Box<Expr>),
+    Binary(Box<Expr>, &'static str, Box<Expr>),
+    Ternary(Box<Expr>, Box<Expr>, Box<Expr>),
+}
+
+impl PluralExpression {
+    /// Parse an expression such as `n==1 ? 0 : n%10>=2 && n%10<=4 ? 1 : 2`.
+    pub fn parse(source: &str) -> Result<Self, ParseError> {
+        let tokens = tokenize(source)?;
+        let mut parser = ExprParser { tokens, pos: 0 };
+        let expr = parser.ternary()?;
+        if parser.pos < parser.tokens.len() {
+            return Err(ParseError::new(
+                0,
+                format!("Unexpected token in '{}'", source),
+            ));
+        }
+        Ok(Self(expr))
+    }
+
+    /// Evaluate the expression, returning the index of the plural form for `n`.
+    pub fn evaluate(&self, n: u64) -> u64 {
+        eval(&self.0, n)
+    }
+}
+
+fn eval(expr: &Expr, n: u64) -> u64 {
+    match expr {
+        Expr::N => n,
+        Expr::Number(value) => *value,
+        Expr::Not(inner) => (eval(inner, n) == 0) as u64,
+        Expr::Ternary(condition, then, otherwise) => {
+            if eval(condition, n) != 0 {
+                eval(then, n)
+            } else {
+                eval(otherwise, n)
+            }
+        }
+        Expr::Binary(left, op, right) => {
+            let l = eval(left, n);
+            // Evaluate lazily for the logical operators.
+            match *op {
+                "&&" => return (l != 0 && eval(right, n) != 0) as u64,
+                "||" => return (l != 0 || eval(right, n) != 0) as u64,
+                _ => {}
+            }
+            let r = eval(right, n);
+            match *op {
+                "==" => (l == r) as u64,
+                "!=" => (l != r) as u64,
+                "<" => (l < r) as u64,
+                "<=" => (l <= r) as u64,
+                ">" => (l > r) as u64,
+                ">=" => (l >= r) as u64,
+                "+" => l.wrapping_add(r),
+                "-" => l.wrapping_sub(r),
+                "*" => l.wrapping_mul(r),
+                "/" => l.checked_div(r).unwrap_or(0),
+                "%" => l.checked_rem(r).unwrap_or(0),
+                _ => unreachable!("unknown operator {}", op),
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    N,
+    Number(u64),
+    Op(&'static str),
+}
+
+const OPERATORS: [&str; 19] = [
+    "&&", "||", "==", "!=", "<=", ">=", "<", ">", "+", "-", "*", "/", "%", "!", "?", ":", "(", ")",
+    ";",
+];
+
+fn tokenize(source: &str) -> Result<Vec<Token>, ParseError> {
+    let mut tokens = Vec::new();
+    let mut rest = source.trim();
+    while !rest.is_empty() {
+        if let Some(stripped) = rest.strip_prefix('n') {
+            tokens.push(Token::N);
+            rest = stripped;
+        } else if rest.starts_with(|c: char| c.is_ascii_digit()) {
+            let end = rest
+                .find(|c: char| !c.is_ascii_digit())
+                .unwrap_or(rest.len());
+            let value = rest[..end]
+                .parse()
+                .map_err(|_| ParseError::new(0, "Number too large in plural expression"))?;
+            tokens.push(Token::Number(value));
+            rest = &rest[end..];
+        } else if let Some(op) = OPERATORS.iter().find(|op| rest.starts_with(*op)) {
+            // A trailing semicolon ends the expression.
+            if *op == ";" {
+                break;
+            }
+            tokens.push(Token::Op(op));
+            rest = &rest[op.len()..];
+        } else {
+            return Err(ParseError::new(
+                0,
+                format!("Invalid character in plural expression '{}'", source),
+            ));
+        }
+        rest = rest.trim_start();
+    }
+    Ok(tokens)
+}
+
+struct ExprParser {
+    tokens: Vec<Token>,
+    pos: usize,
+}
+
+/// Binary operators by precedence, lowest first.
+const PRECEDENCE: [&[&str]; 6] = [
+    &["||"],
+    &["&&"],
+    &["==", "!="],
+    &["<", "<=", ">", ">="],
+    &["+", "-"],
+    &["*", "/", "%"],
+];
+
+impl ExprParser {
+    fn error(&self) -> ParseError {
+        ParseError::new(0, "Invalid plural expression")
+    }
+
+    fn peek_op(&self) -> Option<&'static str> {
+        match self.tokens.get(self.pos) {
+            Some(Token::Op(op)) => Some(op),
+            _ => None,
+        }
+    }
+
+    fn ternary(&mut self) -> Result<Expr, ParseError> {
+        let condition = self.binary(0)?;
+        if self.peek_op() != Some("?") {
+            return Ok(condition);
+        }
+        self.pos += 1;
+        let then = self.ternary()?;
+        if self.peek_op() != Some(":") {
+            return Err(self.error());
+        }
+        self.pos += 1;
+        let otherwise = self.ternary()?;
+        Ok(Expr::Ternary(
+            Box::new(condition),
+            Box::new(then),
+            Box::new(otherwise),
+        ))
+    }
+
+    fn binary(&mut self, level: usize) -> Result<Expr, ParseError> {
+        if level == PRECEDENCE.len() {
+            return self.unary();
+        }
+        let mut left = self.binary(level + 1)?;
+        while let Some(op) = self.peek_op().filter(|op| PRECEDENCE[level].contains(op)) {
+            self.pos += 1;
+            let right = self.binary(level + 1)?;
+            left = Expr::Binary(Box::new(left), op, Box::new(right));
+        }
+        Ok(left)
+    }
+
+    fn unary(&mut self) -> Result<Expr, ParseError> {
+        let token = self
+            .tokens
+            .get(self.pos)
+            .cloned()
+            .ok_or_else(|| self.error())?;
+        self.pos += 1;
+        match token {
+            Token::N => Ok(Expr::N),
+            Token::Number(value) => Ok(Expr::Number(value)),
+            Token::Op("!") => Ok(Expr::Not(Box::new(self.unary()?))),
+            Token::Op("(") => {
+                let inner = self.ternary()?;
+                if self.peek_op() != Some(")") {
+                    return Err(self.error());
+                }
+                self.pos += 1;
+                Ok(inner)
+            }
+            _ => Err(self.error()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PO: &str = r#"
+# Translator comment
+msgid ""
+msgstr ""
+"Content-Type: text/plain; charset=UTF-8\n"
+"Plural-Forms: nplurals=3; plural=(n==1 ? 0 : n%10>=2 && n%10<=4 && (n%100<12 || n%100>14) ? 1 : 2);\n"
+
+#: src/main.rs:10
+msgid "Hello. World"
+msgstr "Witaj. Świecie"
+
+msgctxt "button"
+msgid "Open"
+msgstr "Otwórz"
+
+msgid "One file"
+msgid_plural "{{count}} files"
+msgstr[0] "{{count}} plik"
+msgstr[1] "{{count}} pliki"
+msgstr[2] "{{count}} plików"
+
+#, fuzzy
+msgid "Fuzzy"
+msgstr "Niepewne"
+
+msgid "Untranslated"
+msgstr ""
+
+msgid ""
+"Multi "
+"line"
+msgstr "Wiele "
+"linii"
+"#;
+
+    #[test]
+    fn test_parse_po() {
+        let map = parse_po(PO).unwrap();
+        assert_eq!(map["Hello. World"], "Witaj. Świecie");
+        assert_eq!(map["$context"]["button"]["Open"], "Otwórz");
+        assert_eq!(map["One file"]["2"], "{{count}} plików");
+        assert!(
+            map["One file"]["$plural_forms"]
+                .as_str()
+                .unwrap()
+                .starts_with("(n==1")
+        );
+        assert_eq!(map["Multi line"], "Wiele linii");
+        assert!(!map.contains_key("Fuzzy"));
+        assert!(!map.contains_key("Untranslated"));
+    }
+
+    #[test]
+    fn test_parse_mo() {
+        let entries: [(&str, &str); 3] = [
+            ("", "Plural-Forms: nplurals=2; plural=n != 1;\n"),
+            ("button\u{4}Open", "Öffnen"),
+            ("file\0files", "Datei\0Dateien"),
+        ];
+        let header = 28;
+        let strings_start = header + entries.len() * 16;
+        let mut data = Vec::new();
+        let mut originals = Vec::new();
+        let mut translations = Vec::new();
+        for (original, _) in &entries {
+            originals.push((original.len(), strings_start + data.len()));
+            data.extend_from_slice(original.as_bytes());
+            data.push(0);
+        }
+        for (_, translation) in &entries {
+            translations.push((translation.len(), strings_start + data.len()));
+            data.extend_from_slice(translation.as_bytes());
+            data.push(0);
+        }
+
+        let mut bytes = Vec::new();
+        for word in [
+            0x9504_12de,
+            0,
+            entries.len(),
+            header,
+            header + entries.len() * 8,
+            0,
+            0,
+        ] {
+            bytes.extend_from_slice(&(word as u32).to_le_bytes());
+        }
+        for (length, offset) in originals.iter().chain(translations.iter()) {
+            bytes.extend_from_slice(&(*length as u32).to_le_bytes());
+            bytes.extend_from_slice(&(*offset as u32).to_le_bytes());
+        }
+        bytes.extend_from_slice(&data);
+
+        let map = parse_mo(&bytes).unwrap();
+        assert_eq!(map["$context"]["button"]["Open"], "Öffnen");
+        assert_eq!(map["file"]["1"], "Dateien");
+        assert_eq!(map["file"]["$plural_forms"], "n != 1");
+        assert!(parse_mo(b"not an mo file").is_err());
+
+        // A count far beyond the size of the file is rejected before anything is allocated.
+        let mut truncated = bytes[..20].to_vec();
+        truncated[8..12].copy_from_slice(&u32::MAX.to_le_bytes());
+        assert!(parse_mo(&truncated).is_err());
+    }
+
+    #[test]
+    fn test_plural_expression() {
+        let expr = PluralExpression::parse(
+            "(n==1 ? 0 : n%10>=2 && n%10<=4 && (n%100<12 || n%100>14) ? 1 : 2)",
+        )
+        .unwrap();
+        let forms: Vec<u64> = [1, 2, 5, 12, 22, 25]
+            .iter()
+            .map(|&n| expr.evaluate(n))
+            .collect();
+        assert_eq!(forms, vec![0, 1, 2, 2, 1, 2]);
+        assert_eq!(PluralExpression::parse("n > 1;").unwrap().evaluate(1), 0);
+        assert!(PluralExpression::parse("n ==").is_err());
+        assert!(PluralExpression::parse("x == 1").is_err());
+    }
+}
@@ -0,0 +1,168 @@
+//! Process-wide default catalog.
+//!
+//! These functions are thin wrappers around a global [`Lingua`] instance, for applications
+//! that only need a single catalog. Install a catalog with [`LinguaBuilder::init_global`]
+//! or [`set`]; until then the global instance is empty.
+//!
+//! [`LinguaBuilder::init_global`]: crate::prelude::LinguaBuilder::init_global
+use crate::currency::{CurrencyDisplay, CurrencySign};
+use crate::error::LinguaError;
+use crate::format::CatalogFormat;
+use crate::lingua::Lingua;
+use crate::list::ListType;
+use crate::negotiate::{Negotiated, NegotiationStrategy};
+use crate::relative::Width;
+use crate::unit::{MeasureUnit, MeasurementSystem};
+use once_cell::sync::Lazy;
+use std::fmt;
+use std::path::Path;
+use std::sync::RwLock;
+use std::time::Duration;
+
+static GLOBAL: Lazy<RwLock<Lingua>> = Lazy::new(|| RwLock::new(Lingua::default()));
+
+/// Replace the global instance.
+///
+/// # Arguments
+///
+/// * `lingua` - The catalog to use for all global functions.
+pub fn set(lingua: Lingua) {
+    *GLOBAL.write().unwrap() = lingua;
+}
+
+/// Get a handle to the global instance.
+pub fn instance() -> Lingua {
+    GLOBAL.read().unwrap().clone()
+}
+
+/// Register a callback on the global instance. See [`Lingua::on_language_change`].
+#[cfg(feature = "web")]
+pub fn on_language_change<F>(callback: F)
+where
+    F: Fn(&str) + Send + Sync + 'static,
+{
+    instance().on_language_change(callback)
+}
+
+/// Set the current language of the global instance. See [`Lingua::set_language`].
+pub fn set_language(lang_code: &str) -> Result<bool, LinguaError> {
+    instance().set_language(lang_code)
+}
+
+/// Load translations from a JSON string into the global instance. See [`Lingua::load_translations_from_str`].
+pub fn load_translations_from_str(lang_code: &str, json_str: &str) -> Result<(), LinguaError> {
+    instance().load_translations_from_str(lang_code, json_str)
+}
+
+/// Load translations in any supported catalog format into the global instance.
+/// See [`Lingua::load_translations_from_str_with_format`].
+pub fn load_translations_from_str_with_format(
+    lang_code: &str,
+    content: &str,
+    format: CatalogFormat,
+) -> Result<(), LinguaError> {
+    instance().load_translations_from_str_with_format(lang_code, content, format)
+}
+
+/// Get the languages of the global instance. See [`Lingua::get_languages`].
+pub fn get_languages() -> Result<Vec<String>, LinguaError> {
+    instance().get_languages()
+}
+
+/// Find the language of the global instance that serves a list of requested locales best.
+/// See [`Lingua::negotiate_language`].
+pub fn negotiate_language<S: fmt::Display>(
+    requested: &[S],
+    strategy: NegotiationStrategy,
+) -> Option<Negotiated> {
+    instance().negotiate_language(requested, strategy)
+}
+
+/// Get the current language of the global instance. See [`Lingua::get_language`].
+pub fn get_language() -> Result<String, LinguaError> {
+    instance().get_language()
+}
+
+/// Translate a key with the global instance. See [`Lingua::translate`].
+pub fn translate(key: &str, params: &[(&str, &str)]) -> Result<String, LinguaError> {
+    instance().translate(key, params)
+}
+
+/// Translate a key with the global instance and report the language that served it.
+/// See [`Lingua::translate_with_language`].
+pub fn translate_with_language(
+    key: &str,
+    params: &[(&str, &str)],
+) -> Result<(String, String), LinguaError> {
+    instance().translate_with_language(key, params)
+}
+
+/// Translate a key in a message context with the global instance. See [`Lingua::translate_ctx`].
+pub fn translate_ctx(
+    context: &str,
+    key: &str,
+    params: &[(&str, &str)],
+) -> Result<String, LinguaError> {
+    instance().translate_ctx(context, key, params)
+}
+
+/// Translate a key with the global instance. See [`Lingua::t`].
+pub fn t(key: &str, params: &[(&str, &str)]) -> Result<String, LinguaError> {
+    instance().t(key, params)
+}
+
+/// Translate a key in a message context with the global instance. See [`Lingua::t_ctx`].
+pub fn t_ctx(context: &str, key: &str, params: &[(&str, &str)]) -> Result<String, LinguaError> {
+    instance().t_ctx(context, key, params)
+}
+
+/// Format an amount of money in the language of the global instance.
+/// See [`Lingua::format_currency`].
+pub fn format_currency(
+    value: f64,
+    code: &str,
+    display: CurrencyDisplay,
+    sign: CurrencySign,
+) -> String {
+    instance().format_currency(value, code, display, sign)
+}
+
+/// Format seconds relative to now in the language of the global instance.
+/// See [`Lingua::format_relative`].
+pub fn format_relative(seconds: i64, width: Width) -> String {
+    instance().format_relative(seconds, width)
+}
+
+/// Format a duration in the language of the global instance. See [`Lingua::format_duration`].
+pub fn format_duration(duration: Duration, width: Width) -> String {
+    instance().format_duration(duration, width)
+}
+
+/// Join a list of items in the language of the global instance. See [`Lingua::format_list`].
+pub fn format_list<S: AsRef<str>>(items: &[S], list_type: ListType, width: Width) -> String {
+    instance().format_list(items, list_type, width)
+}
+
+/// Format an ordinal number in the language of the global instance.
+/// See [`Lingua::format_ordinal`].
+pub fn format_ordinal(value: i64) -> String {
+    instance().format_ordinal(value)
+}
+
+/// Format a number in a unit in the language of the global instance.
+/// See [`Lingua::format_unit`].
+pub fn format_unit(value: f64, unit: MeasureUnit, width: Width) -> String {
+    instance().format_unit(value, unit, width)
+}
+
+/// Get the measurement system of the language of the global instance.
+/// See [`Lingua::measurement_system`].
+pub fn measurement_system() -> MeasurementSystem {
+    instance().measurement_system()
+}
+
+/// Load a language code from a configuration file, validated against the global instance.
+/// See [`Lingua::load_lang_from_config`].
+pub fn load_lang_from_config(path: &Path, key: &str) -> Result<String, LinguaError> {
+    instance().load_lang_from_config(path, key)
+}
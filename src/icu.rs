@@ -0,0 +1,592 @@
+//! ICU MessageFormat support.
+//!
+//! Enabled per catalog with [`LinguaBuilder::with_message_format`]. Supported are simple
+//! arguments (`{name}`), `plural` and `selectordinal` with `offset:`, exact matches (`=0`)
+//! and `#`, `select`, formatted arguments (`{n, number}`) and apostrophe quoting. Legacy
+//! `{{name}}` placeholders keep working in ICU mode.
+//!
+//! [`LinguaBuilder::with_message_format`]: crate::prelude::LinguaBuilder::with_message_format
+use crate::error::LinguaError;
+use crate::number::{self, NumberStyle};
+use crate::placeholder;
+use crate::plural::{self, PluralCategory, PluralOperands};
+use std::fmt;
+
+/// Maximum nesting of plural and select arguments, to guard against stack overflows.
+const MAX_NESTING_DEPTH: usize = 32;
+
+/// The syntax used to interpret translation strings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MessageFormat {
+    /// Literal `{{name}}` replacement.
+    #[default]
+    Simple,
+    /// ICU MessageFormat, validated when translations are loaded.
+    Icu,
+}
+
+/// An error in the syntax of an ICU message.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// Byte offset in the message where the error was detected.
+    pub position: usize,
+    /// Description of the error.
+    pub message: String,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at position {}", self.message, self.position)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Node {
+    Text(String),
+    Argument(String),
+    Formatted {
+        name: String,
+        format: String,
+        style: Option<String>,
+    },
+    Pound,
+    Plural {
+        name: String,
+        ordinal: bool,
+        offset: f64,
+        branches: Vec<(String, Vec<Node>)>,
+    },
+    Select {
+        name: String,
+        branches: Vec<(String, Vec<Node>)>,
+    },
+}
+
+/// Check that a message is valid ICU MessageFormat.
+///
+/// # Example
+///
+/// ```rust
+/// use lingua_i18n_rs::icu;
+///
+/// assert!(icu::validate("{count, plural, one {# item} other {# items}}").is_ok());
+/// assert!(icu::validate("{count, plural, one {# item}}").is_err());
+/// ```
+pub fn validate(message: &str) -> Result<(), ParseError> {
+    parse(message).map(|_| ())
+}
+
+pub(crate) fn parse(message: &str) -> Result<Vec<Node>, ParseError> {
+    let mut parser = Parser {
+        src: message,
+        pos: 0,
+        depth: 0,
+    };
+    let nodes = parser.message(0)?;
+    if parser.pos < message.len() {
+        return Err(parser.error("Unmatched '}'"));
+    }
+    Ok(nodes)
+}
+
+struct Parser<'a> {
+    src: &'a str,
+    pos: usize,
+    /// The number of enclosing plural and select arguments.
+    depth: usize,
+}
+
+impl Parser<'_> {
+    fn error(&self, message: &str) -> ParseError {
+        ParseError {
+            position: self.pos,
+            message: message.to_string(),
+        }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.src[self.pos..].chars().next()
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.pos += c.len_utf8();
+        Some(c)
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.bump();
+        }
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), ParseError> {
+        if self.peek() == Some(expected) {
+            self.bump();
+            Ok(())
+        } else {
+            Err(self.error(&format!("Expected '{}'", expected)))
+        }
+    }
+
+    /// Parse message text up to an unmatched `}` or the end of input.
+    /// `plural_depth` is non-zero inside plural branches, where `#` is special.
+    fn message(&mut self, plural_depth: usize) -> Result<Vec<Node>, ParseError> {
+        let mut nodes = Vec::new();
+        let mut text = String::new();
+
+        while let Some(c) = self.peek() {
+            match c {
+                '}' => break,
+                '{' => {
+                    if !text.is_empty() {
+                        nodes.push(Node::Text(std::mem::take(&mut text)));
+                    }
+                    nodes.push(self.argument(plural_depth)?);
+                }
+                '#' if plural_depth > 0 => {
+                    self.bump();
+                    if !text.is_empty() {
+                        nodes.push(Node::Text(std::mem::take(&mut text)));
+                    }
+                    nodes.push(Node::Pound);
+                }
+                '\'' => {
+                    self.bump();
+                    match self.peek() {
+                        Some('\'') => {
+                            self.bump();
+                            text.push('\'');
+                        }
+                        Some('{') | Some('}') | Some('|') => self.quoted(&mut text),
+                        Some('#') if plural_depth > 0 => self.quoted(&mut text),
+                        _ => text.push('\''),
+                    }
+                }
+                _ => {
+                    self.bump();
+                    text.push(c);
+                }
+            }
+        }
+
+        if !text.is_empty() {
+            nodes.push(Node::Text(text));
+        }
+        Ok(nodes)
+    }
+
+    /// Consume quoted literal text after an opening apostrophe.
+    fn quoted(&mut self, text: &mut String) {
+        while let Some(c) = self.bump() {
+            if c == '\'' {
+                if self.peek() == Some('\'') {
+                    self.bump();
+                    text.push('\'');
+                } else {
+                    return;
+                }
+            } else {
+                text.push(c);
+            }
+        }
+    }
+
+    fn identifier(&mut self) -> Result<String, ParseError> {
+        let start = self.pos;
+        while self
+            .peek()
+            .is_some_and(|c| !c.is_whitespace() && !matches!(c, '{' | '}' | ',' | '#' | '\''))
+        {
+            self.bump();
+        }
+        if start == self.pos {
+            return Err(self.error("Expected an identifier"));
+        }
+        Ok(self.src[start..self.pos].to_string())
+    }
+
+    fn argument(&mut self, plural_depth: usize) -> Result<Node, ParseError> {
+        self.expect('{')?;
+        // Legacy `{{name}}` placeholders are accepted as simple arguments.
+        let legacy = self.peek() == Some('{');
+        if legacy {
+            self.bump();
+        }
+
+        self.skip_whitespace();
+        let name = self.identifier()?;
+        self.skip_whitespace();
+
+        let node = match self.peek() {
+            Some('}') => Node::Argument(name),
+            Some(',') => {
+                self.bump();
+                self.skip_whitespace();
+                let format = self.identifier()?;
+                self.skip_whitespace();
+                match format.as_str() {
+                    "plural" | "selectordinal" if !legacy => {
+                        self.expect(',')?;
+                        self.plural(name, format == "selectordinal", plural_depth)?
+                    }
+                    "select" if !legacy => {
+                        self.expect(',')?;
+                        let branches = self.branches(plural_depth)?;
+                        Node::Select { name, branches }
+                    }
+                    _ => {
+                        let style = if self.peek() == Some(',') {
+                            self.bump();
+                            Some(self.style()?)
+                        } else {
+                            None
+                        };
+                        Node::Formatted {
+                            name,
+                            format,
+                            style,
+                        }
+                    }
+                }
+            }
+            _ => return Err(self.error("Expected '}' or ','")),
+        };
+
+        self.skip_whitespace();
+        self.expect('}')?;
+        if legacy {
+            self.expect('}')?;
+        }
+        Ok(node)
+    }
+
+    /// Parse an argument style, e.g. `integer` or `::currency/EUR`, keeping nested braces.
+    fn style(&mut self) -> Result<String, ParseError> {
+        let start = self.pos;
+        let mut depth = 0usize;
+        while let Some(c) = self.peek() {
+            match c {
+                '{' => depth += 1,
+                '}' if depth == 0 => break,
+                '}' => depth -= 1,
+                _ => {}
+            }
+            self.bump();
+        }
+        if self.peek().is_none() {
+            return Err(self.error("Unterminated argument"));
+        }
+        Ok(self.src[start..self.pos].trim().to_string())
+    }
+
+    fn plural(
+        &mut self,
+        name: String,
+        ordinal: bool,
+        plural_depth: usize,
+    ) -> Result<Node, ParseError> {
+        self.skip_whitespace();
+        let mut offset = 0.0;
+        if self.src[self.pos..].starts_with("offset:") {
+            self.pos += "offset:".len();
+            self.skip_whitespace();
+            let value = self.identifier()?;
+            offset = value
+                .parse::<f64>()
+                .map_err(|_| self.error("Invalid plural offset"))?;
+        }
+
+        let branches = self.branches(plural_depth + 1)?;
+        for (selector, _) in &branches {
+            let valid = match selector.strip_prefix('=') {
+                Some(exact) => exact.parse::<f64>().is_ok(),
+                None => PluralCategory::from_keyword(selector).is_some(),
+            };
+            if !valid {
+                return Err(self.error(&format!("Invalid plural selector '{}'", selector)));
+            }
+        }
+
+        Ok(Node::Plural {
+            name,
+            ordinal,
+            offset,
+            branches,
+        })
+    }
+
+    fn branches(&mut self, plural_depth: usize) -> Result<Vec<(String, Vec<Node>)>, ParseError> {
+        if self.depth >= MAX_NESTING_DEPTH {
+            return Err(self.error("Too deeply nested arguments"));
+        }
+        self.depth += 1;
+
+        let mut branches = Vec::new();
+        loop {
+            self.skip_whitespace();
+            if self.peek() == Some('}') || self.peek().is_none() {
+                break;
+            }
+            let selector = self.identifier()?;
+            self.skip_whitespace();
+            self.expect('{')?;
+            let message = self.message(plural_depth)?;
+            self.expect('}')?;
+            branches.push((selector, message));
+        }
+        self.depth -= 1;
+
+        if !branches.iter().any(|(selector, _)| selector == "other") {
+            return Err(self.error("Missing 'other' branch"));
+        }
+        Ok(branches)
+    }
+}
+
+/// Format a parsed message for a language.
+///
+/// Missing simple arguments are rendered as `{name}`; plural arguments must be numeric.
+pub(crate) fn format(
+    lang: &str,
+    key: &str,
+    nodes: &[Node],
+    params: &[(&str, &str)],
+) -> Result<String, LinguaError> {
+    let mut out = String::new();
+    write_nodes(lang, key, nodes, params, None, &mut out)?;
+    Ok(out)
+}
+
+fn param<'a>(params: &[(&str, &'a str)], name: &str) -> Option<&'a str> {
+    params
+        .iter()
+        .find(|(param, _)| *param == name)
+        .map(|(_, value)| *value)
+}
+
+fn write_nodes(
+    lang: &str,
+    key: &str,
+    nodes: &[Node],
+    params: &[(&str, &str)],
+    pound: Option<&str>,
+    out: &mut String,
+) -> Result<(), LinguaError> {
+    for node in nodes {
+        match node {
+            Node::Text(text) => out.push_str(text),
+            Node::Argument(name) => match param(params, name) {
+                Some(value) => out.push_str(value),
+                None => {
+                    out.push('{');
+                    out.push_str(name);
+                    out.push('}');
+                }
+            },
+            Node::Formatted {
+                name,
+                format,
+                style,
+            } => match param(params, name) {
+                Some(value) => {
+                    let formatted = placeholder::format_argument(
+                        lang,
+                        key,
+                        name,
+                        format,
+                        style.as_deref(),
+                        value,
+                    )?;
+                    out.push_str(formatted.as_deref().unwrap_or(value));
+                }
+                None => {
+                    out.push('{');
+                    out.push_str(name);
+                    out.push('}');
+                }
+            },
+            Node::Pound => out.push_str(pound.unwrap_or("#")),
+            Node::Select { name, branches } => {
+                let value = param(params, name).unwrap_or("other");
+                let branch = branches
+                    .iter()
+                    .find(|(selector, _)| selector == value)
+                    .or_else(|| branches.iter().find(|(selector, _)| selector == "other"));
+                if let Some((_, message)) = branch {
+                    write_nodes(lang, key, message, params, pound, out)?;
+                }
+            }
+            Node::Plural {
+                name,
+                ordinal,
+                offset,
+                branches,
+            } => {
+                let invalid = || LinguaError::InvalidPluralCount(key.to_string());
+                let raw = param(params, name).ok_or_else(invalid)?;
+                let value = raw.trim().parse::<f64>().map_err(|_| invalid())?;
+
+                let exact = branches.iter().find(|(selector, _)| {
+                    selector
+                        .strip_prefix('=')
+                        .and_then(|exact| exact.parse::<f64>().ok())
+                        == Some(value)
+                });
+
+                // The plural category depends on the visible fraction digits of the
+                // parameter, e.g. "1.0" is "other" in English.
+                let remainder = if *offset == 0.0 {
+                    raw.trim().to_string()
+                } else {
+                    (value - offset).to_string()
+                };
+
+                let branch = match exact {
+                    Some(branch) => Some(branch),
+                    None => {
+                        let operands = remainder
+                            .parse::<PluralOperands>()
+                            .unwrap_or_else(|_| PluralOperands::from(value - offset));
+                        let category = if *ordinal {
+                            plural::ordinal(lang, &operands)
+                        } else {
+                            plural::cardinal(lang, &operands)
+                        };
+                        branches
+                            .iter()
+                            .find(|(selector, _)| selector == category.as_str())
+                            .or_else(|| branches.iter().find(|(selector, _)| selector == "other"))
+                    }
+                };
+
+                if let Some((_, message)) = branch {
+                    let shown = number::format(lang, value - offset, NumberStyle::Decimal);
+                    write_nodes(lang, key, message, params, Some(&shown), out)?;
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn render(lang: &str, message: &str, params: &[(&str, &str)]) -> String {
+        format(lang, "test", &parse(message).unwrap(), params).unwrap()
+    }
+
+    #[test]
+    fn test_plural_and_offset() {
+        let msg = "{count, plural, =0 {No items} one {# item} other {# items}}";
+        assert_eq!(render("en", msg, &[("count", "0")]), "No items");
+        assert_eq!(render("en", msg, &[("count", "1")]), "1 item");
+        assert_eq!(render("en", msg, &[("count", "7")]), "7 items");
+
+        let msg = "{n, plural, offset:1 =0 {nobody} =1 {{host}} one {{host} and # other} other {{host} and # others}}";
+        assert_eq!(
+            render("en", msg, &[("n", "2"), ("host", "Ann")]),
+            "Ann and 1 other"
+        );
+        assert_eq!(
+            render("en", msg, &[("n", "4"), ("host", "Ann")]),
+            "Ann and 3 others"
+        );
+
+        // `#` is formatted as a number for the language.
+        let msg = "{count, plural, one {# item} other {# items}}";
+        assert_eq!(render("en", msg, &[("count", "1234")]), "1,234 items");
+        assert_eq!(render("de", msg, &[("count", "1234.5")]), "1.234,5 items");
+    }
+
+    #[test]
+    fn test_select_and_ordinal() {
+        let msg = "{gender, select, female {She} male {He} other {They}} finished {place, selectordinal, one {#st} two {#nd} few {#rd} other {#th}}";
+        assert_eq!(
+            render("en", msg, &[("gender", "female"), ("place", "2")]),
+            "She finished 2nd"
+        );
+        assert_eq!(
+            render("en", msg, &[("gender", "x"), ("place", "11")]),
+            "They finished 11th"
+        );
+    }
+
+    #[test]
+    fn test_quoting_and_legacy() {
+        assert_eq!(
+            render("en", "It''s '{literal}' {{name}}", &[("name", "ok")]),
+            "It's {literal} ok"
+        );
+        assert_eq!(
+            render("en", "{n, plural, other {'#' is #}}", &[("n", "3")]),
+            "# is 3"
+        );
+    }
+
+    #[test]
+    fn test_number_arguments() {
+        let params = [("n", "1234.5"), ("r", "0.5")];
+        assert_eq!(
+            render(
+                "de",
+                "{n, number} {n, number, integer} {r, number, percent}",
+                &params
+            ),
+            "1.234,5 1.234 50\u{a0}%"
+        );
+        assert_eq!(
+            render("en", "{n, number, ::compact-short} {{r, percent}}", &params),
+            "1.2K 50%"
+        );
+        assert_eq!(
+            render(
+                "en",
+                "{n, number, ::currency/EUR} {n, number, ::currency/EUR unit-width-iso-code}",
+                &params
+            ),
+            "€1,234.50 EUR\u{a0}1,234.50"
+        );
+        assert_eq!(
+            render(
+                "en",
+                "{n, number, ::unit/kilometer-per-hour unit-width-full-name}",
+                &params
+            ),
+            "1,234.5 kilometers per hour"
+        );
+    }
+
+    #[test]
+    fn test_date_arguments() {
+        let params = [("when", "2024-03-05T14:30:00Z")];
+        assert_eq!(
+            render("en", "{when, date} {when, time, short}", &params),
+            "Mar 5, 2024 2:30\u{202f}PM"
+        );
+        assert_eq!(
+            render("fr", "{when, date, ::yMMMMEd}", &params),
+            "mar. 5 mars 2024"
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(validate("{name").is_err());
+        assert!(validate("text }").is_err());
+        assert!(validate("{n, plural, one {x}}").is_err());
+
+        let nested = "{a, select, other {".repeat(10_000) + &"}}".repeat(10_000);
+        let error = validate(&nested).unwrap_err();
+        assert_eq!(error.message, "Too deeply nested arguments");
+        let nested =
+            "{a, select, other {".repeat(MAX_NESTING_DEPTH) + &"}}".repeat(MAX_NESTING_DEPTH);
+        assert!(validate(&nested).is_ok());
+        assert!(validate("{n, plural, lots {x} other {y}}").is_err());
+        assert!(validate("{n, select, a {x} other {y}").is_err());
+    }
+}
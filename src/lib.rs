@@ -1,10 +1,42 @@
 //! # i18n library for Rust
 //!
 //! This library provides a simple way to add internationalization to your Rust applications by using JSON files.
+extern crate self as lingua_i18n_rs;
+
+pub mod accept_language;
+pub mod codegen;
+pub mod currency;
+pub mod datetime;
 mod error;
+pub mod fluent;
+mod format;
+pub mod gettext;
+pub mod global;
+pub mod icu;
 mod lingua;
+pub mod list;
+pub mod locale;
+pub mod negotiate;
+pub mod number;
+pub mod ordinal;
+mod placeholder;
+pub mod plural;
+pub mod relative;
+pub mod unit;
+#[cfg(not(feature = "web"))]
+mod watch;
+#[cfg(feature = "xliff")]
+pub mod xliff;
 
 pub mod prelude {
     pub use crate::error::LinguaError;
-    pub use crate::lingua::Lingua;
+    pub use crate::format::{CatalogFormat, EmbeddedFile};
+    pub use crate::global;
+    pub use crate::icu::MessageFormat;
+    pub use crate::lingua::{Formality, Lingua, LinguaBuilder};
+    pub use crate::locale::Locale;
+    #[cfg(not(feature = "web"))]
+    pub use crate::watch::ReloadEvent;
+    #[cfg(feature = "macros")]
+    pub use lingua_i18n_rs_macros::{include_translations, t};
 }
@@ -1,5 +1,19 @@
+use crate::currency::{self, CurrencyDisplay, CurrencySign};
 use crate::error::LinguaError;
-use once_cell::sync::Lazy;
+use crate::format::{CatalogFormat, EmbeddedFile};
+use crate::gettext::PluralExpression;
+use crate::icu::{self, MessageFormat};
+use crate::list::{self, ListType};
+use crate::locale::Locale;
+use crate::negotiate::{self, MatchReason, Negotiated, NegotiationStrategy};
+use crate::ordinal;
+use crate::placeholder;
+use crate::plural::{self, PluralCategory, PluralOperands};
+use crate::relative::{self, Width};
+use crate::unit::{self, MeasureUnit, MeasurementSystem};
+#[cfg(not(feature = "web"))]
+use crate::watch::{self, ReloadCallback, ReloadEvent};
+use lingua_i18n_rs_shared::message;
 use serde_json::{Map, Value};
 use std::collections::HashMap;
 use std::fs;
@@ -7,39 +21,146 @@ use std::fs;
 use wasm_bindgen::prelude::*;
 
 use std::path::{Path, PathBuf};
-use std::sync::RwLock;
-
-// Global variables for the library
-static TRANSLATIONS: Lazy<RwLock<HashMap<String, Map<String, Value>>>> =
-    Lazy::new(|| RwLock::new(HashMap::new()));
-static CURRENT_LANGUAGE: Lazy<RwLock<String>> = Lazy::new(|| RwLock::new("en".to_string()));
-static LANGUAGE_DIR: Lazy<RwLock<PathBuf>> = Lazy::new(|| RwLock::new(PathBuf::from("languages")));
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
 
 /// Callback function type for language change events
 #[cfg(feature = "web")]
 pub type LanguageChangeCallback = Box<dyn Fn(&str) + Send + Sync>;
 
-#[cfg(feature = "web")]
-static LANGUAGE_CHANGE_CALLBACKS: Lazy<RwLock<Vec<LanguageChangeCallback>>> =
-    Lazy::new(|| RwLock::new(Vec::new()));
-
 pub struct LinguaBuilder {
     language_dir: String,
+    fallbacks: HashMap<String, Vec<String>>,
+    formality: HashMap<String, Formality>,
+    fallback_language: Option<String>,
+    message_format: MessageFormat,
+    embedded: Option<&'static [EmbeddedFile]>,
+    #[cfg(not(feature = "web"))]
+    hot_reload: Option<Duration>,
     #[cfg(feature = "web")]
     languages_to_load: Option<Vec<String>>,
 }
 
-pub struct Lingua;
+/// The register in which a language addresses the user, e.g. "Sie" or "du" in German.
+///
+/// Messages vary by register with a select object on the `formality` parameter, whose
+/// branches are named `formal` and `informal`. See [`LinguaBuilder::with_formality`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Formality {
+    Formal,
+    Informal,
+}
+
+impl Formality {
+    /// The name of the select branch for the register.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Formality::Formal => "formal",
+            Formality::Informal => "informal",
+        }
+    }
+}
+
+impl std::fmt::Display for Formality {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// A translation catalog with its own languages, current language and language directory.
+///
+/// Cloning a `Lingua` is cheap and yields a handle to the same catalog, so it can be
+/// shared between threads. Independent catalogs are created with separate calls to
+/// [`Lingua::new`].
+#[derive(Clone)]
+pub struct Lingua {
+    inner: Arc<LinguaInner>,
+}
+
+struct LinguaInner {
+    translations: RwLock<HashMap<String, Map<String, Value>>>,
+    current_language: RwLock<String>,
+    language_dir: PathBuf,
+    fallbacks: HashMap<String, Vec<String>>,
+    formality: HashMap<String, Formality>,
+    fallback_language: Option<String>,
+    message_format: MessageFormat,
+    #[cfg(not(feature = "web"))]
+    reload_callbacks: RwLock<Vec<ReloadCallback>>,
+    #[cfg(feature = "web")]
+    language_change_callbacks: RwLock<Vec<LanguageChangeCallback>>,
+}
+
+impl Default for Lingua {
+    /// Create an empty catalog using the "languages" directory and "en" as current language.
+    fn default() -> Self {
+        Lingua::new("languages").build()
+    }
+}
 
 impl Lingua {
+    #[allow(clippy::new_ret_no_self)]
     pub fn new(language_dir: &str) -> LinguaBuilder {
         LinguaBuilder {
             language_dir: language_dir.to_string(),
+            fallbacks: HashMap::new(),
+            formality: HashMap::new(),
+            fallback_language: None,
+            message_format: MessageFormat::Simple,
+            embedded: None,
+            #[cfg(not(feature = "web"))]
+            hot_reload: None,
             #[cfg(feature = "web")]
             languages_to_load: None,
         }
     }
 
+    fn from_builder(builder: LinguaBuilder) -> Self {
+        Self {
+            inner: Arc::new(LinguaInner {
+                translations: RwLock::new(HashMap::new()),
+                current_language: RwLock::new("en".to_string()),
+                language_dir: PathBuf::from(builder.language_dir),
+                fallbacks: builder.fallbacks,
+                formality: builder.formality,
+                fallback_language: builder.fallback_language,
+                message_format: builder.message_format,
+                #[cfg(not(feature = "web"))]
+                reload_callbacks: RwLock::new(Vec::new()),
+                #[cfg(feature = "web")]
+                language_change_callbacks: RwLock::new(Vec::new()),
+            }),
+        }
+    }
+
+    /// Switch to the loaded language that serves a list of requested languages best.
+    ///
+    /// Each requested language, in order, is served by the first loaded language of its
+    /// fallback chain, or else by a loaded language negotiated for it, e.g. `de-AT` for
+    /// `de-CH`. If no requested language can be served, the language set with
+    /// [`LinguaBuilder::with_fallback_language`] is selected.
+    fn select_languages<S: AsRef<str>>(&self, requested: &[S]) {
+        for lang_code in requested {
+            let lang_code = lang_code.as_ref();
+            let chosen = self
+                .language_chain(lang_code)
+                .into_iter()
+                .find(|candidate| self.has_language(candidate))
+                .or_else(|| {
+                    self.negotiate_language(&[lang_code], NegotiationStrategy::Matching)
+                        .filter(|negotiated| negotiated.reason != MatchReason::Default)
+                        .map(|negotiated| negotiated.language)
+                });
+            if let Some(lang) = chosen {
+                let _ = self.set_language(&lang);
+                return;
+            }
+        }
+        if let Some(fallback) = &self.inner.fallback_language {
+            let _ = self.set_language(fallback);
+        }
+    }
+
     /// Register a callback that will be called when the language changes.
     /// Useful for web frameworks that need to react to language changes.
     ///
@@ -49,19 +170,19 @@ impl Lingua {
     ///
     /// # Example
     ///
-    /// ```rust
-    /// #[cfg(feature = "web")]
-    /// Lingua::on_language_change(|lang| {
+    /// ```rust,ignore
+    /// lingua.on_language_change(|lang| {
     ///     println!("Language changed to: {}", lang);
     ///     // Trigger UI update in your framework
     /// });
     /// ```
     #[cfg(feature = "web")]
-    pub fn on_language_change<F>(callback: F)
+    pub fn on_language_change<F>(&self, callback: F)
     where
         F: Fn(&str) + Send + Sync + 'static,
     {
-        LANGUAGE_CHANGE_CALLBACKS
+        self.inner
+            .language_change_callbacks
             .write()
             .unwrap()
             .push(Box::new(callback));
@@ -71,39 +192,51 @@ impl Lingua {
     ///
     /// # Returns
     ///
-    /// Returns a `Result` with the translated string if successful, otherwise a `LinguaError`.
+    /// Returns a `Result` with the number of loaded languages if successful, otherwise a `LinguaError`.
     #[cfg(not(feature = "web"))]
-    fn load_available_languages() -> Result<usize, LinguaError> {
-        Self::load_languages_fs()
+    fn load_available_languages(&self) -> Result<usize, LinguaError> {
+        self.load_languages_fs()
     }
 
     #[cfg(feature = "web")]
-    async fn load_available_languages(languages: Option<&[String]>) -> Result<usize, LinguaError> {
-        Self::load_languages_wasm(languages).await
+    async fn load_available_languages(
+        &self,
+        languages: Option<&[String]>,
+    ) -> Result<usize, LinguaError> {
+        self.load_languages_wasm(languages).await
     }
 
     #[cfg(not(feature = "web"))]
-    fn load_languages_fs() -> Result<usize, LinguaError> {
-        let dir_path = LANGUAGE_DIR.read().unwrap().clone();
-        let entries = fs::read_dir(&dir_path).map_err(LinguaError::DirectoryAccess)?;
+    fn load_languages_fs(&self) -> Result<usize, LinguaError> {
+        let entries =
+            fs::read_dir(&self.inner.language_dir).map_err(LinguaError::DirectoryAccess)?;
 
-        let mut count = 0;
+        let mut lang_codes = Vec::new();
         for entry in entries {
             let entry = entry.map_err(LinguaError::DirectoryAccess)?;
-            if let Some(file_name) = entry.file_name().to_str() {
-                if file_name.ends_with(".json") {
-                    let lang_code = file_name.trim_end_matches(".json");
-                    Self::load_language(lang_code)?;
-                    count += 1;
-                }
+            let path = entry.path();
+            if let (Some(lang_code), Some(extension)) = (
+                path.file_stem().and_then(|stem| stem.to_str()),
+                path.extension().and_then(|ext| ext.to_str()),
+            ) && CatalogFormat::from_extension(extension).is_some()
+                && !lang_codes.iter().any(|code| code == lang_code)
+            {
+                lang_codes.push(lang_code.to_string());
             }
         }
 
-        Ok(count)
+        for lang_code in &lang_codes {
+            self.load_language(lang_code)?;
+        }
+
+        Ok(lang_codes.len())
     }
 
     #[cfg(feature = "web")]
-    async fn load_languages_wasm(languages: Option<&[String]>) -> Result<usize, LinguaError> {
+    async fn load_languages_wasm(
+        &self,
+        languages: Option<&[String]>,
+    ) -> Result<usize, LinguaError> {
         let default_languages = vec![
             "en".to_string(),
             "de".to_string(),
@@ -117,13 +250,19 @@ impl Lingua {
         let mut count = 0;
 
         for lang_code in languages_to_try {
-            match Self::load_language_wasm(lang_code).await {
+            match self.load_language_wasm(lang_code).await {
                 Ok(_) => {
                     count += 1;
-                    web_sys::console::log_1(&wasm_bindgen::JsValue::from_str(&format!("Successfully loaded language: {}", lang_code)));
+                    web_sys::console::log_1(&wasm_bindgen::JsValue::from_str(&format!(
+                        "Successfully loaded language: {}",
+                        lang_code
+                    )));
                 }
                 Err(e) => {
-                    web_sys::console::warn_1(&wasm_bindgen::JsValue::from_str(&format!("Failed to load language {}: {}", lang_code, e)));
+                    web_sys::console::warn_1(&wasm_bindgen::JsValue::from_str(&format!(
+                        "Failed to load language {}: {}",
+                        lang_code, e
+                    )));
                 }
             }
         }
@@ -133,7 +272,8 @@ impl Lingua {
             translations.insert("hello".to_string(), Value::String("Hello".to_string()));
             translations.insert("goodbye".to_string(), Value::String("Goodbye".to_string()));
 
-            TRANSLATIONS
+            self.inner
+                .translations
                 .write()
                 .unwrap()
                 .insert("en".to_string(), translations);
@@ -150,44 +290,214 @@ impl Lingua {
     ///
     /// * `lang_code` - The language code of the language file to load.
     #[cfg(not(feature = "web"))]
-    fn load_language(lang_code: &str) -> Result<(), LinguaError> {
-        Self::load_language_fs(lang_code)
+    fn load_language(&self, lang_code: &str) -> Result<(), LinguaError> {
+        self.load_language_fs(lang_code)
     }
 
     #[cfg(feature = "web")]
     #[allow(dead_code)]
-    async fn load_language(lang_code: &str) -> Result<(), LinguaError> {
-        Self::load_language_wasm(lang_code).await
+    async fn load_language(&self, lang_code: &str) -> Result<(), LinguaError> {
+        self.load_language_wasm(lang_code).await
     }
 
+    /// Load all catalog files of a language from the language directory.
+    ///
+    /// Every supported format present for the language (e.g. `de.json` and `de.ftl`) is
+    /// loaded, and the files are merged in the order of [`CatalogFormat::EXTENSIONS`].
     #[cfg(not(feature = "web"))]
-    fn load_language_fs(lang_code: &str) -> Result<(), LinguaError> {
-        let path = LANGUAGE_DIR
-            .read()
-            .unwrap()
-            .join(format!("{}.json", lang_code));
-
-        let content = fs::read_to_string(&path)
-            .map_err(|_| LinguaError::LanguageFileNotFound(lang_code.to_string()))?;
+    fn load_language_fs(&self, lang_code: &str) -> Result<(), LinguaError> {
+        let translations = self.read_language_fs(lang_code)?;
+        self.insert_translations(lang_code, translations)
+    }
 
-        let json = serde_json::from_str::<Map<String, Value>>(&content).map_err(|error| {
-            LinguaError::JsonParse {
-                file: lang_code.to_string(),
-                error,
+    /// Read and merge all catalog files of a language without inserting them.
+    #[cfg(not(feature = "web"))]
+    fn read_language_fs(&self, lang_code: &str) -> Result<Map<String, Value>, LinguaError> {
+        let mut translations = Map::new();
+        let mut found = false;
+
+        for (extension, format) in CatalogFormat::EXTENSIONS {
+            let path = self
+                .inner
+                .language_dir
+                .join(format!("{}.{}", lang_code, extension));
+            if !path.is_file() {
+                continue;
             }
-        })?;
 
-        TRANSLATIONS
+            let content = fs::read(&path)
+                .map_err(|_| LinguaError::LanguageFileNotFound(lang_code.to_string()))?;
+            let catalog = format.parse(&format!("{}.{}", lang_code, extension), &content)?;
+            Self::merge_translations(&mut translations, catalog);
+            found = true;
+        }
+
+        if !found {
+            return Err(LinguaError::LanguageFileNotFound(lang_code.to_string()));
+        }
+
+        Ok(translations)
+    }
+
+    /// Register a callback that is called after a hot reload, see
+    /// [`LinguaBuilder::with_hot_reload`].
+    ///
+    /// # Arguments
+    ///
+    /// * `callback` - A closure that receives the changed languages and keys
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use lingua_i18n_rs::prelude::*;
+    /// use std::time::Duration;
+    ///
+    /// let lingua = Lingua::new("languages")
+    ///     .with_hot_reload(Duration::from_millis(500))
+    ///     .init()?;
+    /// lingua.on_reload(|event| {
+    ///     println!("Reloaded {:?}: {:?}", event.languages, event.keys);
+    /// });
+    /// # Ok::<(), LinguaError>(())
+    /// ```
+    #[cfg(not(feature = "web"))]
+    pub fn on_reload<F>(&self, callback: F)
+    where
+        F: Fn(&ReloadEvent) + Send + Sync + 'static,
+    {
+        self.inner
+            .reload_callbacks
             .write()
             .unwrap()
-            .insert(lang_code.to_string(), json);
+            .push(Box::new(callback));
+    }
+
+    /// Reload languages from the language directory and notify the reload callbacks.
+    ///
+    /// Each language is swapped in as a whole; a language that fails to load keeps its
+    /// previous translations and is reported in [`ReloadEvent::errors`].
+    #[cfg(not(feature = "web"))]
+    fn reload_languages(&self, lang_codes: &std::collections::BTreeSet<String>) -> ReloadEvent {
+        let mut event = ReloadEvent::default();
+
+        for lang_code in lang_codes {
+            let result = self.read_language_fs(lang_code).and_then(|translations| {
+                if self.inner.message_format == MessageFormat::Icu {
+                    Self::validate_messages(lang_code, "", &translations)?;
+                }
+                Ok(translations)
+            });
+
+            match result {
+                Ok(translations) => {
+                    let new = translations.clone();
+                    let old = self
+                        .inner
+                        .translations
+                        .write()
+                        .unwrap()
+                        .insert(lang_code.clone(), translations)
+                        .unwrap_or_default();
+
+                    let keys = watch::changed_keys(&old, &new);
+                    if !keys.is_empty() {
+                        event.languages.insert(lang_code.clone());
+                        event.keys.extend(keys);
+                    }
+                }
+                Err(error) => event.errors.push((lang_code.clone(), error)),
+            }
+        }
+
+        if !event.is_empty() {
+            for callback in self.inner.reload_callbacks.read().unwrap().iter() {
+                callback(&event);
+            }
+        }
+        event
+    }
+
+    /// Poll the language directory and reload changed languages until the last handle to
+    /// this catalog is dropped.
+    #[cfg(not(feature = "web"))]
+    fn spawn_watcher(
+        &self,
+        interval: Duration,
+        mut snapshot: watch::Snapshot,
+    ) -> Result<(), LinguaError> {
+        let catalog = Arc::downgrade(&self.inner);
+        let language_dir = self.inner.language_dir.clone();
+
+        std::thread::Builder::new()
+            .name("lingua-hot-reload".to_string())
+            .spawn(move || {
+                loop {
+                    std::thread::sleep(interval);
+                    let Some(inner) = catalog.upgrade() else {
+                        break;
+                    };
+
+                    let current = watch::snapshot(&language_dir);
+                    let changed = watch::changed_languages(&snapshot, &current);
+                    snapshot = current;
+                    if !changed.is_empty() {
+                        Lingua { inner }.reload_languages(&changed);
+                    }
+                }
+            })?;
         Ok(())
     }
 
+    /// Load embedded catalog files, merging the files of a language in the order of
+    /// [`CatalogFormat::EXTENSIONS`]. Files in formats that are not enabled are skipped.
+    ///
+    /// # Returns
+    ///
+    /// Returns the number of loaded languages.
+    fn load_embedded(&self, files: &[EmbeddedFile]) -> Result<usize, LinguaError> {
+        let mut lang_codes: Vec<&str> = Vec::new();
+        for file in files {
+            if CatalogFormat::from_extension(file.extension).is_some()
+                && !lang_codes.contains(&file.lang_code)
+            {
+                lang_codes.push(file.lang_code);
+            }
+        }
+
+        for lang_code in &lang_codes {
+            let mut translations = Map::new();
+            for (extension, format) in CatalogFormat::EXTENSIONS {
+                for file in files
+                    .iter()
+                    .filter(|file| file.lang_code == *lang_code && file.extension == *extension)
+                {
+                    let name = format!("{}.{}", file.lang_code, file.extension);
+                    Self::merge_translations(&mut translations, format.parse(&name, file.content)?);
+                }
+            }
+            self.insert_translations(lang_code, translations)?;
+        }
+
+        Ok(lang_codes.len())
+    }
+
+    /// Deep-merge `source` into `target`; values in `source` take precedence.
+    fn merge_translations(target: &mut Map<String, Value>, source: Map<String, Value>) {
+        for (key, value) in source {
+            match (target.get_mut(&key), value) {
+                (Some(Value::Object(existing)), Value::Object(incoming)) => {
+                    Self::merge_translations(existing, incoming)
+                }
+                (_, value) => {
+                    target.insert(key, value);
+                }
+            }
+        }
+    }
+
     #[cfg(feature = "web")]
-    async fn load_language_wasm(lang_code: &str) -> Result<(), LinguaError> {
-        let dir_path = LANGUAGE_DIR.read().unwrap().clone();
-        let base_path = dir_path.to_str().unwrap_or("languages");
+    async fn load_language_wasm(&self, lang_code: &str) -> Result<(), LinguaError> {
+        let base_path = self.inner.language_dir.to_str().unwrap_or("languages");
         // Ensure path starts with / for absolute paths in browser
         let base_path = if base_path.starts_with('/') {
             base_path
@@ -195,8 +505,11 @@ impl Lingua {
             &format!("/{}", base_path)
         };
         let url = format!("{}/{}.json", base_path, lang_code);
-        
-        web_sys::console::log_1(&wasm_bindgen::JsValue::from_str(&format!("Loading language file from: {}", url)));
+
+        web_sys::console::log_1(&wasm_bindgen::JsValue::from_str(&format!(
+            "Loading language file from: {}",
+            url
+        )));
 
         let opts = web_sys::RequestInit::new();
         opts.set_method("GET");
@@ -265,13 +578,60 @@ impl Lingua {
             })?;
 
         let key_count = json_map.len();
-        TRANSLATIONS
+        self.insert_translations(lang_code, json_map)?;
+
+        web_sys::console::log_1(&wasm_bindgen::JsValue::from_str(&format!(
+            "Successfully loaded and stored language '{}' with {} keys",
+            lang_code, key_count
+        )));
+
+        Ok(())
+    }
+
+    /// Store the translations of a language, replacing any previously loaded ones.
+    ///
+    /// With [`MessageFormat::Icu`] every message is parsed first, so syntax errors are
+    /// reported at load time.
+    fn insert_translations(
+        &self,
+        lang_code: &str,
+        translations: Map<String, Value>,
+    ) -> Result<(), LinguaError> {
+        if self.inner.message_format == MessageFormat::Icu {
+            Self::validate_messages(lang_code, "", &translations)?;
+        }
+
+        self.inner
+            .translations
             .write()
             .unwrap()
-            .insert(lang_code.to_string(), json_map);
-        
-        web_sys::console::log_1(&wasm_bindgen::JsValue::from_str(&format!("Successfully loaded and stored language '{}' with {} keys", lang_code, key_count)));
+            .insert(lang_code.to_string(), translations);
+        Ok(())
+    }
 
+    fn validate_messages(
+        lang_code: &str,
+        prefix: &str,
+        map: &Map<String, Value>,
+    ) -> Result<(), LinguaError> {
+        for (name, value) in map {
+            let key = if prefix.is_empty() {
+                name.clone()
+            } else {
+                format!("{}.{}", prefix, name)
+            };
+            match value {
+                Value::String(message) => {
+                    icu::validate(message).map_err(|error| LinguaError::MessageFormat {
+                        file: lang_code.to_string(),
+                        key,
+                        error,
+                    })?
+                }
+                Value::Object(map) => Self::validate_messages(lang_code, &key, map)?,
+                _ => {}
+            }
+        }
         Ok(())
     }
 
@@ -280,8 +640,12 @@ impl Lingua {
     /// # Arguments
     ///
     /// * `lang_code` - The language code to check.
-    fn has_language(lang_code: &str) -> bool {
-        TRANSLATIONS.read().unwrap().contains_key(lang_code)
+    fn has_language(&self, lang_code: &str) -> bool {
+        self.inner
+            .translations
+            .read()
+            .unwrap()
+            .contains_key(lang_code)
     }
 
     /// Set the current language.
@@ -299,21 +663,24 @@ impl Lingua {
     /// ```rust
     /// use lingua_i18n_rs::prelude::*;
     ///
-    /// Lingua::set_language("de");
+    /// let lingua = Lingua::default();
+    /// lingua.load_translations_from_str("de", r#"{"hello": "Hallo"}"#)?;
+    /// lingua.set_language("de")?;
+    /// # Ok::<(), LinguaError>(())
     /// ```
-    pub fn set_language(lang_code: &str) -> Result<bool, LinguaError> {
-        if Self::has_language(lang_code) {
-            *CURRENT_LANGUAGE.write().unwrap() = lang_code.to_string();
-            
+    pub fn set_language(&self, lang_code: &str) -> Result<bool, LinguaError> {
+        if self.has_language(lang_code) {
+            *self.inner.current_language.write().unwrap() = lang_code.to_string();
+
             #[cfg(feature = "web")]
             {
                 // Notify all registered callbacks
-                let callbacks = LANGUAGE_CHANGE_CALLBACKS.read().unwrap();
+                let callbacks = self.inner.language_change_callbacks.read().unwrap();
                 for callback in callbacks.iter() {
                     callback(lang_code);
                 }
             }
-            
+
             Ok(true)
         } else {
             Err(LinguaError::LanguageNotAvailable(lang_code.to_string()))
@@ -333,124 +700,205 @@ impl Lingua {
     /// ```rust
     /// use lingua_i18n_rs::prelude::*;
     ///
-    /// Lingua::load_translations_from_str("en", r#"{"hello": "Hello"}"#)?;
+    /// let lingua = Lingua::default();
+    /// lingua.load_translations_from_str("en", r#"{"hello": "Hello"}"#)?;
+    /// # Ok::<(), LinguaError>(())
     /// ```
-    pub fn load_translations_from_str(lang_code: &str, json_str: &str) -> Result<(), LinguaError> {
-        let json_map = serde_json::from_str::<Map<String, Value>>(json_str).map_err(|error| {
-            LinguaError::JsonParse {
-                file: lang_code.to_string(),
-                error,
-            }
-        })?;
-        
-        TRANSLATIONS.write().unwrap().insert(lang_code.to_string(), json_map);
-        Ok(())
+    pub fn load_translations_from_str(
+        &self,
+        lang_code: &str,
+        json_str: &str,
+    ) -> Result<(), LinguaError> {
+        self.load_translations_from_str_with_format(lang_code, json_str, CatalogFormat::Json)
     }
 
-    /// Get a list of available languages.
+    /// Load translations from a string in any supported catalog format.
+    ///
+    /// # Arguments
+    ///
+    /// * `lang_code` - The language code
+    /// * `content` - The catalog content
+    /// * `format` - The format of `content`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use lingua_i18n_rs::prelude::*;
+    ///
+    /// let lingua = Lingua::default();
+    /// lingua.load_translations_from_str_with_format(
+    ///     "en",
+    ///     "menu-file = File\n",
+    ///     CatalogFormat::Fluent,
+    /// )?;
+    /// assert_eq!(lingua.t("menu.file", &[])?, "File");
+    /// # Ok::<(), LinguaError>(())
+    /// ```
+    pub fn load_translations_from_str_with_format(
+        &self,
+        lang_code: &str,
+        content: &str,
+        format: CatalogFormat,
+    ) -> Result<(), LinguaError> {
+        let translations = format.parse(lang_code, content.as_bytes())?;
+        self.insert_translations(lang_code, translations)
+    }
+
+    /// Export the catalogs of a language pair as an XLIFF document.
+    ///
+    /// Every key of the source or target catalog becomes a unit, nested in groups like the
+    /// catalog. Keys missing in the target catalog are exported without a target, so the
+    /// document can be handed to translators and loaded back as the target catalog.
+    ///
+    /// # Arguments
+    ///
+    /// * `source_lang` - The language code of the source catalog, which must be loaded.
+    /// * `target_lang` - The language code of the target catalog, which may be missing.
+    /// * `version` - The XLIFF version to write.
     ///
     /// # Returns
     ///
-    /// Returns a list of available languages.
+    /// Returns the XLIFF document.
     ///
     /// # Example
     ///
     /// ```rust
     /// use lingua_i18n_rs::prelude::*;
+    /// use lingua_i18n_rs::xliff::XliffVersion;
     ///
-    /// let languages = Lingua::get_languages();
+    /// let lingua = Lingua::default();
+    /// lingua.load_translations_from_str("en", r#"{"menu": {"file": "File"}}"#)?;
+    /// let xliff = lingua.export_xliff("en", "de", XliffVersion::V2_0)?;
+    /// assert!(xliff.contains("<source>File</source>"));
+    /// # Ok::<(), LinguaError>(())
     /// ```
-    pub fn get_languages() -> Result<Vec<String>, LinguaError> {
-        Ok(TRANSLATIONS.read().unwrap().keys().cloned().collect())
+    #[cfg(feature = "xliff")]
+    pub fn export_xliff(
+        &self,
+        source_lang: &str,
+        target_lang: &str,
+        version: crate::xliff::XliffVersion,
+    ) -> Result<String, LinguaError> {
+        let translations = self.inner.translations.read().unwrap();
+        let source = translations
+            .get(source_lang)
+            .ok_or_else(|| LinguaError::LanguageNotAvailable(source_lang.to_string()))?;
+
+        let document = crate::xliff::Document::from_catalogs(
+            version,
+            source_lang,
+            source,
+            Some(target_lang),
+            translations.get(target_lang),
+        );
+        Ok(document.to_xml())
     }
 
-    /// Get the current language.
+    /// Get a list of available languages.
     ///
     /// # Returns
     ///
-    /// Returns the current language.
+    /// Returns a list of available languages.
     ///
     /// # Example
     ///
     /// ```rust
     /// use lingua_i18n_rs::prelude::*;
     ///
-    /// let lang = Lingua::get_language();
+    /// let lingua = Lingua::default();
+    /// let languages = lingua.get_languages()?;
+    /// # Ok::<(), LinguaError>(())
     /// ```
-    pub fn get_language() -> Result<String, LinguaError> {
-        Ok(CURRENT_LANGUAGE.read().unwrap().clone())
+    pub fn get_languages(&self) -> Result<Vec<String>, LinguaError> {
+        Ok(self
+            .inner
+            .translations
+            .read()
+            .unwrap()
+            .keys()
+            .cloned()
+            .collect())
     }
 
-    /// Translate a key with optional parameters.
+    /// Find the loaded language that serves a list of requested locales best.
+    ///
+    /// The requested locales are matched against the loaded languages with
+    /// [`negotiate::negotiate_languages`], so `de-CH` is served by a loaded `de` and `fr` by
+    /// a loaded `fr-CA`. When nothing matches, the language set with
+    /// [`LinguaBuilder::with_fallback_language`] is returned if it is loaded.
     ///
     /// # Arguments
     ///
-    /// * `key` - The key to translate.
-    /// * `params` - A list of parameters to replace in the translation.
+    /// * `requested` - The requested locales, most preferred first, as strings or
+    ///   [`Locale`]s, e.g. from [`crate::accept_language::parse`].
+    /// * `strategy` - How the requested locales are matched.
     ///
     /// # Returns
     ///
-    /// Returns the translated string.
+    /// Returns the chosen language and the reason it matched, or `None` if no language fits.
     ///
     /// # Example
     ///
     /// ```rust
     /// use lingua_i18n_rs::prelude::*;
+    /// use lingua_i18n_rs::negotiate::{MatchReason, NegotiationStrategy};
+    ///
+    /// let lingua = Lingua::default();
+    /// lingua.load_translations_from_str("de", r#"{"hello": "Hallo"}"#)?;
+    /// lingua.load_translations_from_str("fr", r#"{"hello": "Bonjour"}"#)?;
     ///
-    /// let translated = Lingua::translate("hello", &[]);
+    /// let negotiated = lingua
+    ///     .negotiate_language(&["it", "de-CH", "fr"], NegotiationStrategy::Matching)
+    ///     .unwrap();
+    /// assert_eq!(negotiated.language, "de");
+    /// assert_eq!(negotiated.reason, MatchReason::Parent);
+    /// lingua.set_language(&negotiated.language)?;
+    /// # Ok::<(), LinguaError>(())
     /// ```
-    pub fn translate(key: &str, params: &[(&str, &str)]) -> Result<String, LinguaError> {
-        let lang = CURRENT_LANGUAGE.read().unwrap().clone();
-        let translations = TRANSLATIONS.read().unwrap();
-
-        #[cfg(feature = "web")]
-        {
-            let available_langs: Vec<String> = translations.keys().cloned().collect();
-            web_sys::console::log_1(&wasm_bindgen::JsValue::from_str(&format!("Translating key '{}' for language '{}'. Available languages: {:?}", key, lang, available_langs)));
-        }
-
-        let lang_map = translations
-            .get(&lang)
-            .ok_or_else(|| {
-                #[cfg(feature = "web")]
-                {
-                    let available_langs: Vec<String> = translations.keys().cloned().collect();
-                    web_sys::console::error_1(&wasm_bindgen::JsValue::from_str(&format!("Language '{}' not found in translations. Available: {:?}", lang, available_langs)));
-                }
-                LinguaError::LanguageNotAvailable(lang.clone())
-            })?;
-
-        let parts: Vec<&str> = key.split('.').collect();
-        let mut current = Some(lang_map);
-
-        for (i, part) in parts.iter().enumerate() {
-            if i < parts.len() - 1 {
-                current = current
-                    .and_then(|v| v.get(*part))
-                    .and_then(|v| v.as_object());
-            } else if let Some(val) = current.and_then(|v| v.get(*part)) {
-                let mut result = match val {
-                    Value::String(s) => s.clone(),
-                    _ => val.to_string().trim_matches('"').to_string(),
-                };
-
-                for (name, value) in params {
-                    result = result.replace(&format!("{{{{{}}}}}", name), value);
-                }
-
-                return Ok(result);
-            }
-        }
+    pub fn negotiate_language<S: std::fmt::Display>(
+        &self,
+        requested: &[S],
+        strategy: NegotiationStrategy,
+    ) -> Option<Negotiated> {
+        let mut available: Vec<String> = self
+            .inner
+            .translations
+            .read()
+            .unwrap()
+            .keys()
+            .cloned()
+            .collect();
+        available.sort();
+        let default = self
+            .inner
+            .fallback_language
+            .as_deref()
+            .filter(|lang| available.iter().any(|available| available == lang));
+        negotiate::negotiate_languages(requested, &available, default, strategy)
+            .into_iter()
+            .next()
+    }
 
-        #[cfg(feature = "web")]
-        {
-            web_sys::console::warn_1(&wasm_bindgen::JsValue::from_str(&format!("Translation key '{}' not found for language '{}'", key, lang)));
-        }
-        Err(LinguaError::KeyNotFound(key.to_string()))
+    /// Get the current language.
+    ///
+    /// # Returns
+    ///
+    /// Returns the current language.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use lingua_i18n_rs::prelude::*;
+    ///
+    /// let lingua = Lingua::default();
+    /// let lang = lingua.get_language()?;
+    /// # Ok::<(), LinguaError>(())
+    /// ```
+    pub fn get_language(&self) -> Result<String, LinguaError> {
+        Ok(self.inner.current_language.read().unwrap().clone())
     }
 
     /// Translate a key with optional parameters.
-    /// This function is a shorthand for `Lingua::translate`.
     ///
     /// # Arguments
     ///
@@ -466,85 +914,714 @@ impl Lingua {
     /// ```rust
     /// use lingua_i18n_rs::prelude::*;
     ///
-    /// let translated = Lingua::t("hello", &[]);
+    /// let lingua = Lingua::default();
+    /// lingua.load_translations_from_str("en", r#"{"hello": "Hello"}"#)?;
+    /// let translated = lingua.translate("hello", &[])?;
+    /// # Ok::<(), LinguaError>(())
     /// ```
-    pub fn t(key: &str, params: &[(&str, &str)]) -> Result<String, LinguaError> {
-        let translated = Self::translate(key, params)?;
+    pub fn translate(&self, key: &str, params: &[(&str, &str)]) -> Result<String, LinguaError> {
+        let (_, translated) = self.translate_with_language(key, params)?;
         Ok(translated)
     }
 
-    /// Detect the system language.
+    /// Translate a key and report which language actually served it.
+    ///
+    /// The key is looked up in the current language first and then along its fallback
+    /// chain, see [`Lingua::fallback_chain`].
     ///
-    /// Load the system language via the `sys-locale` crate for cross-platform compatibility.
+    /// # Arguments
+    ///
+    /// * `key` - The key to translate.
+    /// * `params` - A list of parameters to replace in the translation.
     ///
     /// # Returns
     ///
-    /// Returns the system language if it was detected, otherwise `None`.
-    #[cfg(not(feature = "web"))]
-    fn detect_system_language() -> Option<String> {
-        sys_locale::get_locale()
-            .and_then(|locale| locale.split('-').next().map(|lang| lang.to_string()))
-    }
-
-    #[cfg(feature = "web")]
-    fn detect_system_language() -> Option<String> {
-        let window = web_sys::window()?;
-        let navigator = window.navigator();
-
-        if let Ok(lang) = js_sys::Reflect::get(&navigator, &JsValue::from_str("language")) {
-            if let Some(lang_str) = lang.as_string() {
-                return lang_str.split('-').next().map(|s| s.to_string());
-            }
-        }
-
-        if let Ok(langs) = js_sys::Reflect::get(&navigator, &JsValue::from_str("languages")) {
-            if js_sys::Array::is_array(&langs) {
-                let langs_array = js_sys::Array::from(&langs);
-                if langs_array.length() > 0 {
-                    if let Some(first_lang) = langs_array.get(0).as_string() {
-                        return first_lang.split('-').next().map(|s| s.to_string());
-                    }
-                }
-            }
-        }
-
-        Some("en".to_string())
+    /// Returns the code of the language that contained the key and the translated string.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use lingua_i18n_rs::prelude::*;
+    ///
+    /// let lingua = Lingua::new("languages").with_fallback_language("en").build();
+    /// lingua.load_translations_from_str("en", r#"{"hello": "Hello", "bye": "Bye"}"#)?;
+    /// lingua.load_translations_from_str("de", r#"{"hello": "Hallo"}"#)?;
+    /// lingua.set_language("de")?;
+    ///
+    /// let (lang, translated) = lingua.translate_with_language("bye", &[])?;
+    /// assert_eq!((lang.as_str(), translated.as_str()), ("en", "Bye"));
+    /// # Ok::<(), LinguaError>(())
+    /// ```
+    pub fn translate_with_language(
+        &self,
+        key: &str,
+        params: &[(&str, &str)],
+    ) -> Result<(String, String), LinguaError> {
+        self.translate_in_context(None, key, params)
     }
 
-    /// Load a language code from a configuration file.
+    /// Translate a key that is disambiguated by a message context.
     ///
-    /// The configuration file can be in JSON, TOML, or a simple key-value format.
+    /// Contexts are stored under the reserved top-level `$context` object of a catalog,
+    /// keyed by context and then by key, so identical source strings can carry distinct
+    /// translations. JSON, YAML and TOML catalogs write the object directly, gettext
+    /// catalogs fill it from `msgctxt` and Fluent resources from ids of the form
+    /// `context--key`; XLIFF exports keep it as a group. The fallback chain applies as for
+    /// [`Lingua::translate`].
     ///
     /// # Arguments
     ///
-    /// * `path` - The path to the configuration file.
-    /// * `key` - The key to look for in the configuration file.
+    /// * `context` - The message context, e.g. `"button"`.
+    /// * `key` - The key to translate.
+    /// * `params` - A list of parameters to replace in the translation.
     ///
     /// # Returns
     ///
-    /// Returns the language code if it was found in the configuration file.
+    /// Returns the translated string.
     ///
     /// # Example
     ///
     /// ```rust
     /// use lingua_i18n_rs::prelude::*;
-    /// use std::path::Path;
     ///
-    /// let lang_code = Lingua::load_lang_from_config(Path::new("config.toml"), "language");
+    /// let lingua = Lingua::default();
+    /// lingua.load_translations_from_str(
+    ///     "en",
+    ///     r#"{"Open": "Open", "$context": {"button": {"Open": "Open file"}}}"#,
+    /// )?;
+    /// assert_eq!(lingua.translate_ctx("button", "Open", &[])?, "Open file");
+    /// # Ok::<(), LinguaError>(())
     /// ```
-    pub fn load_lang_from_config(path: &Path, key: &str) -> Result<String, LinguaError> {
-        if !path.exists() {
-            return Err(LinguaError::ConfigFileNotFound(path.display().to_string()));
-        }
-
-        let content = fs::read_to_string(path)
-            .map_err(|e| LinguaError::ConfigFileReadError(e.to_string()))?;
-
-        let seperators = [':', '='];
-        let clean_key = key.trim_matches('"').trim();
+    pub fn translate_ctx(
+        &self,
+        context: &str,
+        key: &str,
+        params: &[(&str, &str)],
+    ) -> Result<String, LinguaError> {
+        let (_, translated) = self.translate_in_context(Some(context), key, params)?;
+        Ok(translated)
+    }
 
-        for line in content.lines() {
-            let line = line.trim();
+    fn translate_in_context(
+        &self,
+        context: Option<&str>,
+        key: &str,
+        params: &[(&str, &str)],
+    ) -> Result<(String, String), LinguaError> {
+        let lang = self.inner.current_language.read().unwrap().clone();
+        let translations = self.inner.translations.read().unwrap();
+
+        #[cfg(feature = "web")]
+        {
+            let available_langs: Vec<String> = translations.keys().cloned().collect();
+            web_sys::console::log_1(&wasm_bindgen::JsValue::from_str(&format!(
+                "Translating key '{}' for language '{}'. Available languages: {:?}",
+                key, lang, available_langs
+            )));
+        }
+
+        let mut language_found = false;
+        for candidate in self.fallback_chain(&lang) {
+            let Some(lang_map) = translations.get(&candidate) else {
+                continue;
+            };
+            language_found = true;
+
+            let scope = match context {
+                Some(context) => lang_map
+                    .get("$context")
+                    .and_then(|contexts| contexts.get(context))
+                    .and_then(Value::as_object),
+                None => Some(lang_map),
+            };
+
+            if let Some(val) = scope.and_then(|scope| Self::lookup(scope, key)) {
+                let with_formality;
+                let params = match self.formality(&candidate) {
+                    Some(formality)
+                        if !params
+                            .iter()
+                            .any(|(name, value)| *name == "formality" && !value.is_empty()) =>
+                    {
+                        with_formality = params
+                            .iter()
+                            .copied()
+                            .filter(|(name, _)| *name != "formality")
+                            .chain(std::iter::once(("formality", formality.as_str())))
+                            .collect::<Vec<_>>();
+                        &with_formality[..]
+                    }
+                    _ => params,
+                };
+
+                let val = Self::resolve_plural(&candidate, key, val, params)?;
+                let mut result = match val {
+                    Value::String(s) => s.clone(),
+                    _ => val.to_string().trim_matches('"').to_string(),
+                };
+
+                match self.inner.message_format {
+                    MessageFormat::Simple => {
+                        result = placeholder::substitute(&candidate, key, &result, params)?;
+                    }
+                    MessageFormat::Icu => {
+                        let nodes =
+                            icu::parse(&result).map_err(|error| LinguaError::MessageFormat {
+                                file: candidate.clone(),
+                                key: key.to_string(),
+                                error,
+                            })?;
+                        result = icu::format(&candidate, key, &nodes, params)?;
+                    }
+                }
+
+                return Ok((candidate, result));
+            }
+        }
+
+        if !language_found {
+            #[cfg(feature = "web")]
+            {
+                let available_langs: Vec<String> = translations.keys().cloned().collect();
+                web_sys::console::error_1(&wasm_bindgen::JsValue::from_str(&format!(
+                    "Language '{}' not found in translations. Available: {:?}",
+                    lang, available_langs
+                )));
+            }
+            return Err(LinguaError::LanguageNotAvailable(lang));
+        }
+
+        #[cfg(feature = "web")]
+        {
+            web_sys::console::warn_1(&wasm_bindgen::JsValue::from_str(&format!(
+                "Translation key '{}' not found for language '{}'",
+                key, lang
+            )));
+        }
+        Err(LinguaError::KeyNotFound(key.to_string()))
+    }
+
+    /// Look up a dot-separated key in the translations of a single language.
+    ///
+    /// A top-level entry matching the whole key wins, so flat keys containing dots (such as
+    /// gettext source strings) can be looked up as well.
+    fn lookup<'a>(lang_map: &'a Map<String, Value>, key: &str) -> Option<&'a Value> {
+        if let Some(val) = lang_map.get(key) {
+            return Some(val);
+        }
+
+        let (path, last) = match key.rsplit_once('.') {
+            Some((path, last)) => (Some(path), last),
+            None => (None, key),
+        };
+
+        let mut current = lang_map;
+        if let Some(path) = path {
+            for part in path.split('.') {
+                current = current.get(part)?.as_object()?;
+            }
+        }
+
+        current.get(last)
+    }
+
+    /// Resolve a looked-up value to the message to render.
+    ///
+    /// * An object with a `$value` entry (a message that also has children) renders `$value`.
+    /// * A select object names its parameter with `$select` and selects the branch matching
+    ///   the parameter value, or `other`.
+    /// * A plural object has an `other` branch and optionally `zero`, `one`, `two`, `few`,
+    ///   `many` and exact `=N` branches. Exact matches win, otherwise the branch is selected
+    ///   by the CLDR plural rules of `lang`. The parameter is named by the `$plural` entry and
+    ///   defaults to `count`. An `$ordinal` entry names the parameter instead and selects
+    ///   the branch by the ordinal rules (`one` for "1st", `two` for "2nd", ...).
+    ///
+    /// * A gettext plural object holds a `Plural-Forms` expression under `$plural_forms` and
+    ///   the forms under `"0"`, `"1"`, ...; the expression is evaluated for the integer part
+    ///   of the parameter, which is also named by `$plural`.
+    ///
+    /// Branches are resolved recursively; any other value is returned unchanged.
+    fn resolve_plural<'a>(
+        lang: &str,
+        key: &str,
+        val: &'a Value,
+        params: &[(&str, &str)],
+    ) -> Result<&'a Value, LinguaError> {
+        let Some(branches) = val.as_object() else {
+            return Ok(val);
+        };
+
+        if let Some(value) = branches.get("$value") {
+            return Self::resolve_plural(lang, key, value, params);
+        }
+
+        let param_value = |param: &str| {
+            params
+                .iter()
+                .find(|(name, _)| *name == param)
+                .map(|(_, value)| *value)
+        };
+
+        if let Some(param) = branches.get("$select").and_then(Value::as_str) {
+            let branch = param_value(param)
+                .and_then(|value| branches.get(value))
+                .or_else(|| branches.get("other"))
+                .unwrap_or(val);
+            return Self::resolve_plural(lang, key, branch, params);
+        }
+
+        let plural_forms = branches.get("$plural_forms").and_then(Value::as_str);
+        if plural_forms.is_none() && !message::is_plural_object(branches) {
+            return Ok(val);
+        }
+
+        let ordinal = branches.get("$ordinal").and_then(Value::as_str);
+        let param = ordinal
+            .or_else(|| branches.get("$plural").and_then(Value::as_str))
+            .unwrap_or("count");
+        let raw =
+            param_value(param).ok_or_else(|| LinguaError::InvalidPluralCount(key.to_string()))?;
+        let operands = raw
+            .parse::<PluralOperands>()
+            .map_err(|_| LinguaError::InvalidPluralCount(key.to_string()))?;
+
+        if let Some(expression) = plural_forms {
+            let index = PluralExpression::parse(expression)
+                .map(|expression| expression.evaluate(operands.i))
+                .unwrap_or(0);
+            let branch = branches
+                .get(&index.to_string())
+                .or_else(|| branches.get("0"))
+                .unwrap_or(val);
+            return Self::resolve_plural(lang, key, branch, params);
+        }
+
+        let exact = branches.iter().find(|(name, _)| {
+            name.strip_prefix('=')
+                .and_then(|exact| exact.parse::<f64>().ok())
+                .is_some_and(|exact| raw.trim().parse::<f64>().ok() == Some(exact))
+        });
+
+        let branch = match exact {
+            Some((_, branch)) => branch,
+            None => {
+                let category = if ordinal.is_some() {
+                    plural::ordinal(lang, &operands)
+                } else {
+                    plural::cardinal(lang, &operands)
+                };
+                branches
+                    .get(category.as_str())
+                    .or_else(|| branches.get(PluralCategory::Other.as_str()))
+                    .unwrap_or(val)
+            }
+        };
+        Self::resolve_plural(lang, key, branch, params)
+    }
+
+    /// Get the default formality configured for a language or its primary language.
+    fn formality(&self, lang_code: &str) -> Option<Formality> {
+        let primary = lang_code.split(['-', '_']).next().unwrap_or(lang_code);
+        self.inner
+            .formality
+            .get(lang_code)
+            .or_else(|| self.inner.formality.get(primary))
+            .copied()
+    }
+
+    /// Get the languages that are consulted, in order, when translating for a language.
+    ///
+    /// The chain starts with the language itself. Each language in the chain is followed by
+    /// the fallbacks configured for it with [`LinguaBuilder::with_fallback`] and then by its
+    /// [`Locale::parent`], so `zh-Hant-TW` falls back to `zh-Hant` and `zh`. The language set
+    /// with [`LinguaBuilder::with_fallback_language`] ends the chain.
+    ///
+    /// # Arguments
+    ///
+    /// * `lang_code` - The language code to resolve.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use lingua_i18n_rs::prelude::*;
+    ///
+    /// let lingua = Lingua::new("languages")
+    ///     .with_fallback("de-AT", &["de"])
+    ///     .with_fallback_language("en")
+    ///     .build();
+    ///
+    /// assert_eq!(lingua.fallback_chain("de-AT"), vec!["de-AT", "de", "en"]);
+    /// assert_eq!(
+    ///     lingua.fallback_chain("zh-Hant-TW"),
+    ///     vec!["zh-Hant-TW", "zh-Hant", "zh", "en"]
+    /// );
+    /// ```
+    pub fn fallback_chain(&self, lang_code: &str) -> Vec<String> {
+        let mut chain = self.language_chain(lang_code);
+        if let Some(fallback) = &self.inner.fallback_language
+            && !chain.contains(fallback)
+        {
+            chain.push(fallback.clone());
+        }
+
+        chain
+    }
+
+    /// Get the fallback chain of a language without the language set with
+    /// [`LinguaBuilder::with_fallback_language`].
+    fn language_chain(&self, lang_code: &str) -> Vec<String> {
+        let mut chain = vec![lang_code.to_string()];
+        let mut i = 0;
+        while i < chain.len() {
+            let mut next = self
+                .inner
+                .fallbacks
+                .get(&chain[i])
+                .cloned()
+                .unwrap_or_default();
+            if let Some(parent) = Locale::parse(&chain[i]).ok().and_then(|l| l.parent()) {
+                next.push(parent.to_string());
+            }
+            for fallback in next {
+                if !chain.contains(&fallback) {
+                    chain.push(fallback);
+                }
+            }
+            i += 1;
+        }
+        chain
+    }
+
+    /// Translate a key with optional parameters.
+    /// This function is a shorthand for `Lingua::translate`.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key to translate.
+    /// * `params` - A list of parameters to replace in the translation.
+    ///
+    /// # Returns
+    ///
+    /// Returns the translated string.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use lingua_i18n_rs::prelude::*;
+    ///
+    /// let lingua = Lingua::default();
+    /// lingua.load_translations_from_str("en", r#"{"hello": "Hello"}"#)?;
+    /// let translated = lingua.t("hello", &[])?;
+    /// # Ok::<(), LinguaError>(())
+    /// ```
+    pub fn t(&self, key: &str, params: &[(&str, &str)]) -> Result<String, LinguaError> {
+        let translated = self.translate(key, params)?;
+        Ok(translated)
+    }
+
+    /// Translate a key in a message context.
+    /// This function is a shorthand for `Lingua::translate_ctx`.
+    ///
+    /// # Arguments
+    ///
+    /// * `context` - The message context, e.g. `"button"`.
+    /// * `key` - The key to translate.
+    /// * `params` - A list of parameters to replace in the translation.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use lingua_i18n_rs::prelude::*;
+    ///
+    /// let lingua = Lingua::default();
+    /// lingua.load_translations_from_str(
+    ///     "de",
+    ///     r#"{"$context": {"button": {"open": "Öffnen"}, "status": {"open": "Offen"}}}"#,
+    /// )?;
+    /// lingua.set_language("de")?;
+    /// assert_eq!(lingua.t_ctx("button", "open", &[])?, "Öffnen");
+    /// assert_eq!(lingua.t_ctx("status", "open", &[])?, "Offen");
+    /// # Ok::<(), LinguaError>(())
+    /// ```
+    pub fn t_ctx(
+        &self,
+        context: &str,
+        key: &str,
+        params: &[(&str, &str)],
+    ) -> Result<String, LinguaError> {
+        self.translate_ctx(context, key, params)
+    }
+
+    /// Format an amount of money in the current language, e.g. "$1,234.50".
+    ///
+    /// See [`currency::format_currency`] for how the amount is rounded and laid out.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The amount in major units.
+    /// * `code` - The ISO 4217 code of the currency, e.g. `"EUR"`.
+    /// * `display` - Whether to show the symbol, the narrow symbol or the code.
+    /// * `sign` - How to show negative amounts.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use lingua_i18n_rs::prelude::*;
+    /// use lingua_i18n_rs::currency::{CurrencyDisplay, CurrencySign};
+    ///
+    /// let lingua = Lingua::default();
+    /// lingua.load_translations_from_str("fr", "{}")?;
+    /// lingua.set_language("fr")?;
+    /// assert_eq!(
+    ///     lingua.format_currency(-12.5, "EUR", CurrencyDisplay::Symbol, CurrencySign::Accounting),
+    ///     "(12,50\u{a0}€)"
+    /// );
+    /// # Ok::<(), LinguaError>(())
+    /// ```
+    pub fn format_currency(
+        &self,
+        value: f64,
+        code: &str,
+        display: CurrencyDisplay,
+        sign: CurrencySign,
+    ) -> String {
+        let lang = self.inner.current_language.read().unwrap();
+        currency::format_currency(&lang, value, code, display, sign)
+    }
+
+    /// Format a number of seconds relative to now in the current language.
+    ///
+    /// See [`relative::format_relative_seconds`] for how the unit is chosen.
+    ///
+    /// # Arguments
+    ///
+    /// * `seconds` - The offset from now; negative values are in the past.
+    /// * `width` - The width of the pattern.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use lingua_i18n_rs::prelude::*;
+    /// use lingua_i18n_rs::relative::Width;
+    ///
+    /// let lingua = Lingua::default();
+    /// lingua.load_translations_from_str("en", "{}")?;
+    /// lingua.set_language("en")?;
+    /// assert_eq!(lingua.format_relative(-180, Width::Long), "3 minutes ago");
+    /// # Ok::<(), LinguaError>(())
+    /// ```
+    pub fn format_relative(&self, seconds: i64, width: Width) -> String {
+        let lang = self.inner.current_language.read().unwrap();
+        relative::format_relative_seconds(&lang, seconds, width)
+    }
+
+    /// Format a duration in the current language, e.g. "1 hour, 20 minutes".
+    ///
+    /// # Arguments
+    ///
+    /// * `duration` - The duration to format.
+    /// * `width` - The width of the pattern.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use lingua_i18n_rs::prelude::*;
+    /// use lingua_i18n_rs::relative::Width;
+    /// use std::time::Duration;
+    ///
+    /// let lingua = Lingua::default();
+    /// lingua.load_translations_from_str("de", "{}")?;
+    /// lingua.set_language("de")?;
+    /// assert_eq!(lingua.format_duration(Duration::from_secs(4_800), Width::Short), "1 Std. und 20 Min.");
+    /// # Ok::<(), LinguaError>(())
+    /// ```
+    pub fn format_duration(&self, duration: Duration, width: Width) -> String {
+        let lang = self.inner.current_language.read().unwrap();
+        relative::format_duration(&lang, duration, width)
+    }
+
+    /// Join a list of items in the current language, e.g. "Alice, Bob, and Carol".
+    ///
+    /// # Arguments
+    ///
+    /// * `items` - The items to join.
+    /// * `list_type` - Whether the list is a conjunction, disjunction or a list of units.
+    /// * `width` - The width of the pattern.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use lingua_i18n_rs::prelude::*;
+    /// use lingua_i18n_rs::list::{ListType, Width};
+    ///
+    /// let lingua = Lingua::default();
+    /// lingua.load_translations_from_str("fr", "{}")?;
+    /// lingua.set_language("fr")?;
+    /// assert_eq!(
+    ///     lingua.format_list(&["Alice", "Bob", "Carol"], ListType::Disjunction, Width::Long),
+    ///     "Alice, Bob ou Carol"
+    /// );
+    /// # Ok::<(), LinguaError>(())
+    /// ```
+    pub fn format_list<S: AsRef<str>>(
+        &self,
+        items: &[S],
+        list_type: ListType,
+        width: Width,
+    ) -> String {
+        let lang = self.inner.current_language.read().unwrap();
+        list::format_list(&lang, items, list_type, width)
+    }
+
+    /// Format an ordinal number in the current language, e.g. "3rd".
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use lingua_i18n_rs::prelude::*;
+    ///
+    /// let lingua = Lingua::default();
+    /// lingua.load_translations_from_str("de", "{}")?;
+    /// lingua.set_language("de")?;
+    /// assert_eq!(lingua.format_ordinal(3), "3.");
+    /// # Ok::<(), LinguaError>(())
+    /// ```
+    pub fn format_ordinal(&self, value: i64) -> String {
+        let lang = self.inner.current_language.read().unwrap();
+        ordinal::format_ordinal(&lang, value)
+    }
+
+    /// Format a number in a unit in the current language, e.g. "5 km".
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The number of units.
+    /// * `unit` - The unit of `value`.
+    /// * `width` - The width of the pattern.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use lingua_i18n_rs::prelude::*;
+    /// use lingua_i18n_rs::unit::{MeasureUnit, Width};
+    ///
+    /// let lingua = Lingua::default();
+    /// lingua.load_translations_from_str("de", "{}")?;
+    /// lingua.set_language("de")?;
+    /// assert_eq!(lingua.format_unit(5.0, MeasureUnit::Kilometer, Width::Long), "5 Kilometer");
+    /// # Ok::<(), LinguaError>(())
+    /// ```
+    pub fn format_unit(&self, value: f64, unit: MeasureUnit, width: Width) -> String {
+        let lang = self.inner.current_language.read().unwrap();
+        unit::format_unit(&lang, value, unit, width)
+    }
+
+    /// Get the measurement system the current language prefers.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use lingua_i18n_rs::prelude::*;
+    /// use lingua_i18n_rs::unit::MeasurementSystem;
+    ///
+    /// let lingua = Lingua::default();
+    /// lingua.load_translations_from_str("en-GB", "{}")?;
+    /// lingua.set_language("en-GB")?;
+    /// assert_eq!(lingua.measurement_system(), MeasurementSystem::Uk);
+    /// # Ok::<(), LinguaError>(())
+    /// ```
+    pub fn measurement_system(&self) -> MeasurementSystem {
+        let lang = self.inner.current_language.read().unwrap();
+        unit::measurement_system(&lang)
+    }
+
+    /// Detect the languages preferred by the system, most preferred first.
+    ///
+    /// The POSIX locale variables are consulted first: the colon-separated `LANGUAGE`
+    /// priority list, followed by the first of `LC_ALL`, `LC_MESSAGES` and `LANG` that is
+    /// set. When none of them is set, the system locales are read via the `sys-locale` crate
+    /// for cross-platform compatibility.
+    ///
+    /// # Returns
+    ///
+    /// Returns the detected languages, in canonical form where they parse as a [`Locale`],
+    /// e.g. `"pt-BR"`.
+    #[cfg(not(feature = "web"))]
+    fn detect_system_languages() -> Vec<String> {
+        let languages = posix_languages(|name| std::env::var(name).ok());
+        if !languages.is_empty() {
+            return languages;
+        }
+        sys_locale::get_locales()
+            .map(|locale| canonical_tag(&locale))
+            .collect()
+    }
+
+    #[cfg(feature = "web")]
+    fn detect_system_languages() -> Vec<String> {
+        let Some(window) = web_sys::window() else {
+            return vec!["en".to_string()];
+        };
+        let navigator = window.navigator();
+
+        if let Ok(langs) = js_sys::Reflect::get(&navigator, &JsValue::from_str("languages"))
+            && js_sys::Array::is_array(&langs)
+        {
+            let languages: Vec<String> = js_sys::Array::from(&langs)
+                .iter()
+                .filter_map(|lang| lang.as_string())
+                .map(|lang| canonical_tag(&lang))
+                .collect();
+            if !languages.is_empty() {
+                return languages;
+            }
+        }
+
+        if let Ok(lang) = js_sys::Reflect::get(&navigator, &JsValue::from_str("language"))
+            && let Some(lang_str) = lang.as_string()
+        {
+            return vec![canonical_tag(&lang_str)];
+        }
+
+        vec!["en".to_string()]
+    }
+
+    /// Load a language code from a configuration file.
+    ///
+    /// The configuration file can be in JSON, TOML, or a simple key-value format.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path to the configuration file.
+    /// * `key` - The key to look for in the configuration file.
+    ///
+    /// # Returns
+    ///
+    /// Returns the language code if it was found in the configuration file.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use lingua_i18n_rs::prelude::*;
+    /// use std::path::Path;
+    ///
+    /// let lingua = Lingua::new("languages").init()?;
+    /// let lang_code = lingua.load_lang_from_config(Path::new("config.toml"), "language")?;
+    /// # Ok::<(), LinguaError>(())
+    /// ```
+    pub fn load_lang_from_config(&self, path: &Path, key: &str) -> Result<String, LinguaError> {
+        if !path.exists() {
+            return Err(LinguaError::ConfigFileNotFound(path.display().to_string()));
+        }
+
+        let content = fs::read_to_string(path)
+            .map_err(|e| LinguaError::ConfigFileReadError(e.to_string()))?;
+
+        let seperators = [':', '='];
+        let clean_key = key.trim_matches('"').trim();
+
+        for line in content.lines() {
+            let line = line.trim();
 
             if line.starts_with('#') || line.starts_with("//") {
                 continue;
@@ -559,7 +1636,7 @@ impl Lingua {
 
                         let lang_code = lang_code.trim_matches('"');
 
-                        if !Self::has_language(lang_code) {
+                        if !self.has_language(lang_code) {
                             return Err(LinguaError::LanguageNotAvailable(lang_code.to_string()));
                         }
 
@@ -583,9 +1660,8 @@ impl LinguaBuilder {
     ///
     /// # Example
     ///
-    /// ```rust
-    /// #[cfg(feature = "web")]
-    /// Lingua::new("languages")
+    /// ```rust,ignore
+    /// let lingua = Lingua::new("languages")
     ///     .with_languages(vec!["en".to_string(), "de".to_string()])
     ///     .init().await?;
     /// ```
@@ -595,47 +1671,279 @@ impl LinguaBuilder {
         self
     }
 
+    /// Configure the fallback chain for a language.
+    ///
+    /// Keys missing in `lang_code` are looked up in `fallbacks`, in order. Chains are
+    /// followed transitively, so `de-AT -> de` and `de -> en` resolve `de-AT -> de -> en`.
+    ///
+    /// # Arguments
+    ///
+    /// * `lang_code` - The language the chain applies to.
+    /// * `fallbacks` - The languages to try after `lang_code`.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use lingua_i18n_rs::prelude::*;
+    ///
+    /// let lingua = Lingua::new("languages")
+    ///     .with_fallback("de-AT", &["de"])
+    ///     .with_fallback("de", &["en"])
+    ///     .init()?;
+    /// # Ok::<(), LinguaError>(())
+    /// ```
+    pub fn with_fallback(mut self, lang_code: &str, fallbacks: &[&str]) -> Self {
+        self.fallbacks.insert(
+            lang_code.to_string(),
+            fallbacks.iter().map(|lang| lang.to_string()).collect(),
+        );
+        self
+    }
+
+    /// Set the register used for a language when a translation gets no `formality`
+    /// parameter.
+    ///
+    /// Messages that differ by register select on `formality` with `formal` and `informal`
+    /// branches (and an `other` default), so the whole application can switch between
+    /// e.g. "Sie" and "du" without passing the parameter everywhere. The setting for a
+    /// primary language (`de`) also applies to its regional variants (`de-AT`). An explicit
+    /// non-empty `formality` parameter takes precedence.
+    ///
+    /// # Arguments
+    ///
+    /// * `lang_code` - The language the register applies to.
+    /// * `formality` - The register to use.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use lingua_i18n_rs::prelude::*;
+    ///
+    /// let lingua = Lingua::new("languages")
+    ///     .with_formality("de", Formality::Formal)
+    ///     .build();
+    /// lingua.load_translations_from_str(
+    ///     "de",
+    ///     r#"{"welcome": {"$select": "formality", "formal": "Willkommen, Sie sind angemeldet.", "other": "Willkommen, du bist angemeldet."}}"#,
+    /// )?;
+    /// lingua.set_language("de")?;
+    /// assert_eq!(lingua.t("welcome", &[])?, "Willkommen, Sie sind angemeldet.");
+    /// assert_eq!(
+    ///     lingua.t("welcome", &[("formality", "informal")])?,
+    ///     "Willkommen, du bist angemeldet."
+    /// );
+    /// # Ok::<(), LinguaError>(())
+    /// ```
+    pub fn with_formality(mut self, lang_code: &str, formality: Formality) -> Self {
+        self.formality.insert(lang_code.to_string(), formality);
+        self
+    }
+
+    /// Set the language that ends every fallback chain.
+    ///
+    /// # Arguments
+    ///
+    /// * `lang_code` - The language to fall back to when all other languages miss a key.
+    pub fn with_fallback_language(mut self, lang_code: &str) -> Self {
+        self.fallback_language = Some(lang_code.to_string());
+        self
+    }
+
+    /// Choose the syntax of translation strings.
+    ///
+    /// With [`MessageFormat::Icu`] messages use ICU MessageFormat and are validated when
+    /// they are loaded, so `init` and `load_translations_from_str` fail with
+    /// [`LinguaError::MessageFormat`] on syntax errors.
+    ///
+    /// # Arguments
+    ///
+    /// * `message_format` - The syntax to use.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use lingua_i18n_rs::prelude::*;
+    ///
+    /// let lingua = Lingua::new("languages")
+    ///     .with_message_format(MessageFormat::Icu)
+    ///     .build();
+    /// lingua.load_translations_from_str(
+    ///     "en",
+    ///     r#"{"items": "{count, plural, one {# item} other {# items}}"}"#,
+    /// )?;
+    /// assert_eq!(lingua.t("items", &[("count", "1")])?, "1 item");
+    /// # Ok::<(), LinguaError>(())
+    /// ```
+    pub fn with_message_format(mut self, message_format: MessageFormat) -> Self {
+        self.message_format = message_format;
+        self
+    }
+
+    /// Load the languages from files embedded into the binary instead of the language
+    /// directory.
+    ///
+    /// Use the `include_translations!` macro (`macros` feature) to embed a directory at
+    /// compile time; JSON files are validated during compilation.
+    ///
+    /// # Arguments
+    ///
+    /// * `files` - The embedded catalog files.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// use lingua_i18n_rs::prelude::*;
+    ///
+    /// let lingua = Lingua::new("languages")
+    ///     .with_embedded(include_translations!("languages"))
+    ///     .init()?;
+    /// ```
+    pub fn with_embedded(mut self, files: &'static [EmbeddedFile]) -> Self {
+        self.embedded = Some(files);
+        self
+    }
+
+    /// Watch the language directory and reload changed languages while the catalog is in
+    /// use.
+    ///
+    /// The directory is polled every `interval`. Changed languages are reloaded as a
+    /// whole and swapped in atomically; if a file fails to parse the previous translations
+    /// are kept. Subscribe to changes with [`Lingua::on_reload`]. Has no effect together
+    /// with [`LinguaBuilder::with_embedded`].
+    ///
+    /// # Arguments
+    ///
+    /// * `interval` - How often to check the language files for changes.
     #[cfg(not(feature = "web"))]
-    pub fn init(self) -> Result<Lingua, LinguaError> {
-        *LANGUAGE_DIR.write().unwrap() = PathBuf::from(&self.language_dir);
+    pub fn with_hot_reload(mut self, interval: Duration) -> Self {
+        self.hot_reload = Some(interval);
+        self
+    }
+
+    /// Create an empty `Lingua` with this configuration without loading any language files.
+    ///
+    /// Translations can then be added with [`Lingua::load_translations_from_str`].
+    pub fn build(self) -> Lingua {
+        Lingua::from_builder(self)
+    }
 
-        let languages_loaded = Lingua::load_available_languages()?;
+    /// Load all languages from the language directory (or the embedded files, see
+    /// [`LinguaBuilder::with_embedded`]) into a new, independent `Lingua`.
+    ///
+    /// The languages preferred by the system (the POSIX `LANGUAGE` list and locale variables,
+    /// or the system locales) are tried in order: each is served by the first loaded language
+    /// of its fallback chain or a negotiated one, e.g. `de-AT` for `de-CH`.
+    #[cfg(not(feature = "web"))]
+    pub fn init(self) -> Result<Lingua, LinguaError> {
+        let language_dir = self.language_dir.clone();
+        let embedded = self.embedded;
+        let hot_reload = self.hot_reload;
+        let lingua = self.build();
+
+        // Taken before loading, so changes made while loading are picked up.
+        let snapshot = watch::snapshot(&lingua.inner.language_dir);
+        let languages_loaded = match embedded {
+            Some(files) => lingua.load_embedded(files)?,
+            None => lingua.load_available_languages()?,
+        };
 
         if languages_loaded == 0 {
             return Err(LinguaError::DirectoryAccess(std::io::Error::new(
                 std::io::ErrorKind::NotFound,
-                format!("No language files found in '{}'", self.language_dir),
+                format!("No language files found in '{}'", language_dir),
             )));
         }
 
-        if let Some(lang) = Lingua::detect_system_language() {
-            let _ = Lingua::set_language(&lang);
+        lingua.select_languages(&Lingua::detect_system_languages());
+
+        if let Some(interval) = hot_reload
+            && embedded.is_none()
+        {
+            lingua.spawn_watcher(interval, snapshot)?;
         }
 
-        Ok(Lingua)
+        Ok(lingua)
     }
 
     #[cfg(feature = "web")]
-    pub async fn init(self) -> Result<Lingua, LinguaError> {
-        *LANGUAGE_DIR.write().unwrap() = PathBuf::from(&self.language_dir);
-
-        let languages_loaded = Lingua::load_available_languages(
-            self.languages_to_load.as_deref()
-        ).await?;
+    pub async fn init(mut self) -> Result<Lingua, LinguaError> {
+        let language_dir = self.language_dir.clone();
+        let languages_to_load = self.languages_to_load.take();
+        let embedded = self.embedded;
+        let lingua = self.build();
+
+        let languages_loaded = match embedded {
+            Some(files) => lingua.load_embedded(files)?,
+            None => {
+                lingua
+                    .load_available_languages(languages_to_load.as_deref())
+                    .await?
+            }
+        };
 
         if languages_loaded == 0 {
             return Err(LinguaError::DirectoryAccess(std::io::Error::new(
                 std::io::ErrorKind::NotFound,
-                format!("No language files found in '{}'", self.language_dir),
+                format!("No language files found in '{}'", language_dir),
             )));
         }
 
-        if let Some(lang) = Lingua::detect_system_language() {
-            let _ = Lingua::set_language(&lang);
-        }
+        lingua.select_languages(&Lingua::detect_system_languages());
 
-        Ok(Lingua)
+        Ok(lingua)
     }
+
+    /// Initialize a `Lingua` and install it as the global instance used by the
+    /// functions in [`crate::global`].
+    #[cfg(not(feature = "web"))]
+    pub fn init_global(self) -> Result<Lingua, LinguaError> {
+        let lingua = self.init()?;
+        crate::global::set(lingua.clone());
+        Ok(lingua)
+    }
+
+    #[cfg(feature = "web")]
+    pub async fn init_global(self) -> Result<Lingua, LinguaError> {
+        let lingua = self.init().await?;
+        crate::global::set(lingua.clone());
+        Ok(lingua)
+    }
+}
+
+/// Read the preferred languages from the POSIX locale variables, looked up with `var`.
+///
+/// `LANGUAGE` lists languages in order of preference and is followed by the locale in
+/// effect, the first non-empty of `LC_ALL`, `LC_MESSAGES` and `LANG`. As in gettext,
+/// `LANGUAGE` is ignored when that locale is `C` or `POSIX`.
+#[cfg(not(feature = "web"))]
+fn posix_languages(var: impl Fn(&str) -> Option<String>) -> Vec<String> {
+    let locale = ["LC_ALL", "LC_MESSAGES", "LANG"]
+        .into_iter()
+        .find_map(|name| var(name).filter(|value| !value.is_empty()));
+    let is_c = |name: &str| {
+        let name = name.split(['.', '@']).next().unwrap_or_default();
+        name.eq_ignore_ascii_case("c") || name.eq_ignore_ascii_case("posix")
+    };
+    if locale.as_deref().is_some_and(is_c) {
+        return Vec::new();
+    }
+
+    let mut languages = Vec::new();
+    let language = var("LANGUAGE").unwrap_or_default();
+    for name in language.split(':').map(str::to_string).chain(locale) {
+        if let Ok(locale) = Locale::from_posix(&name) {
+            let tag = locale.to_string();
+            if !languages.contains(&tag) {
+                languages.push(tag);
+            }
+        }
+    }
+    languages
+}
+
+/// Canonicalize a language tag, keeping tags that are not valid BCP 47 as they are.
+fn canonical_tag(tag: &str) -> String {
+    Locale::parse(tag).map_or_else(|_| tag.to_string(), |locale| locale.to_string())
 }
 
 #[cfg(test)]
@@ -643,24 +1951,29 @@ mod tests {
     use super::*;
     use std::fs;
 
-    fn setup() {
-        TRANSLATIONS.write().unwrap().clear();
+    fn setup(lang: &str, map: Map<String, Value>) -> Lingua {
+        let lingua = Lingua::default();
+        lingua
+            .inner
+            .translations
+            .write()
+            .unwrap()
+            .insert(lang.to_string(), map);
+        *lingua.inner.current_language.write().unwrap() = lang.to_string();
+        lingua
     }
 
     #[test]
     fn test_translate_simple() {
-        setup();
         let mut map = Map::new();
         map.insert("hello".to_string(), Value::String("Hallo".to_string()));
-        TRANSLATIONS.write().unwrap().insert("de".to_string(), map);
-        *CURRENT_LANGUAGE.write().unwrap() = "de".to_string();
+        let lingua = setup("de", map);
 
-        assert_eq!(Lingua::translate("hello", &[]).unwrap(), "Hallo");
+        assert_eq!(lingua.translate("hello", &[]).unwrap(), "Hallo");
     }
 
     #[test]
     fn test_translate_nested() {
-        setup();
         let mut map = Map::new();
         let mut submenu = Map::new();
         submenu.insert("save".to_string(), Value::String("Speichern".to_string()));
@@ -669,53 +1982,564 @@ mod tests {
         menu.insert("file".to_string(), Value::Object(submenu));
 
         map.insert("menu".to_string(), Value::Object(menu));
-        TRANSLATIONS.write().unwrap().insert("de".to_string(), map);
-
-        *CURRENT_LANGUAGE.write().unwrap() = "de".to_string();
+        let lingua = setup("de", map);
 
         assert_eq!(
-            Lingua::translate("menu.file.save", &[]).unwrap(),
+            lingua.translate("menu.file.save", &[]).unwrap(),
             "Speichern"
         );
     }
 
     #[test]
     fn test_translate_params() {
-        setup();
         let mut map = Map::new();
         map.insert(
             "greeting".to_string(),
             Value::String("Hello, {{name}}!".to_string()),
         );
-        TRANSLATIONS.write().unwrap().insert("en".to_string(), map);
-        *CURRENT_LANGUAGE.write().unwrap() = "en".to_string();
+        let lingua = setup("en", map);
 
         assert_eq!(
-            Lingua::translate("greeting", &[("name", "Alice")]).unwrap(),
+            lingua.translate("greeting", &[("name", "Alice")]).unwrap(),
             "Hello, Alice!"
         );
     }
 
+    #[test]
+    fn test_translate_number() {
+        let map = serde_json::json!({
+            "total": "Total: {{amount, number}} ({{ratio, percent}})",
+            "followers": {"one": "{{count, compact}} follower", "other": "{{count, compact}} followers"}
+        });
+        let lingua = setup("de", map.as_object().unwrap().clone());
+        let params = [("amount", "1234.5"), ("ratio", "0.126")];
+
+        assert_eq!(
+            lingua.translate("total", &params).unwrap(),
+            "Total: 1.234,5 (13\u{a0}%)"
+        );
+        assert_eq!(
+            lingua
+                .translate("followers", &[("count", "2500000")])
+                .unwrap(),
+            "2,5\u{a0}Mio. followers"
+        );
+        assert!(matches!(
+            lingua.translate("total", &[("amount", "lots")]),
+            Err(LinguaError::InvalidArgument { .. })
+        ));
+    }
+
     #[test]
     fn test_translate_missing_key() {
-        setup();
         let mut map = Map::new();
         map.insert("hello".to_string(), Value::String("Hallo".to_string()));
-        TRANSLATIONS.write().unwrap().insert("de".to_string(), map);
-        *CURRENT_LANGUAGE.write().unwrap() = "de".to_string();
+        let lingua = setup("de", map);
 
-        assert!(Lingua::translate("world", &[]).is_err());
+        assert!(lingua.translate("world", &[]).is_err());
     }
 
     #[test]
-    fn test_load_lang_from_config() {
-        setup();
+    fn test_independent_instances() {
+        let first = Lingua::default();
+        let second = Lingua::default();
+        first
+            .load_translations_from_str("en", r#"{"title": "Product"}"#)
+            .unwrap();
+        second
+            .load_translations_from_str("en", r#"{"title": "Email"}"#)
+            .unwrap();
+        second
+            .load_translations_from_str("de", r#"{"title": "E-Mail"}"#)
+            .unwrap();
+        second.set_language("de").unwrap();
+
+        assert_eq!(first.t("title", &[]).unwrap(), "Product");
+        assert_eq!(second.t("title", &[]).unwrap(), "E-Mail");
+        assert!(first.set_language("de").is_err());
+        assert_eq!(first.get_language().unwrap(), "en");
+    }
+
+    #[test]
+    fn test_translate_fallback_chain() {
+        let lingua = Lingua::new("languages")
+            .with_fallback("de-AT", &["de"])
+            .with_fallback_language("en")
+            .build();
+        lingua
+            .load_translations_from_str("en", r#"{"a": "A", "b": "B", "c": "C"}"#)
+            .unwrap();
+        lingua
+            .load_translations_from_str("de", r#"{"a": "De-A", "b": "De-B"}"#)
+            .unwrap();
+        lingua
+            .load_translations_from_str("de-AT", r#"{"a": "At-A"}"#)
+            .unwrap();
+        lingua.set_language("de-AT").unwrap();
+
+        assert_eq!(lingua.t("a", &[]).unwrap(), "At-A");
+        assert_eq!(
+            lingua.translate_with_language("b", &[]).unwrap(),
+            ("de".to_string(), "De-B".to_string())
+        );
+        assert_eq!(
+            lingua.translate_with_language("c", &[]).unwrap(),
+            ("en".to_string(), "C".to_string())
+        );
+        assert!(matches!(
+            lingua.t("d", &[]),
+            Err(LinguaError::KeyNotFound(_))
+        ));
+    }
+
+    #[test]
+    fn test_translate_plural() {
+        let lingua = Lingua::default();
+        lingua
+            .load_translations_from_str(
+                "pl",
+                r#"{
+                    "items": {
+                        "one": "{{count}} plik",
+                        "few": "{{count}} pliki",
+                        "many": "{{count}} plików",
+                        "other": "{{count}} pliku"
+                    },
+                    "points": {"$plural": "n", "one": "{{n}} punkt", "other": "{{n}} punktów"}
+                }"#,
+            )
+            .unwrap();
+        lingua.set_language("pl").unwrap();
+
+        assert_eq!(lingua.t("items", &[("count", "1")]).unwrap(), "1 plik");
+        assert_eq!(lingua.t("items", &[("count", "3")]).unwrap(), "3 pliki");
+        assert_eq!(lingua.t("items", &[("count", "12")]).unwrap(), "12 plików");
+        assert_eq!(lingua.t("items", &[("count", "1.5")]).unwrap(), "1.5 pliku");
+        assert_eq!(lingua.t("points", &[("n", "1")]).unwrap(), "1 punkt");
+        assert_eq!(lingua.t("points", &[("n", "5")]).unwrap(), "5 punktów");
+        assert!(matches!(
+            lingua.t("items", &[]),
+            Err(LinguaError::InvalidPluralCount(_))
+        ));
+    }
+
+    #[test]
+    fn test_gender_and_formality() {
+        let lingua = Lingua::new("languages")
+            .with_formality("de", Formality::Formal)
+            .build();
+        let catalog = r#"{
+            "welcome": {
+                "$select": "gender",
+                "female": "Willkommen, liebe {{name}}",
+                "male": "Willkommen, lieber {{name}}",
+                "other": "Willkommen, {{name}}"
+            },
+            "signed_in": {
+                "$select": "formality",
+                "formal": {
+                    "$select": "gender",
+                    "female": "Sie sind als Autorin angemeldet.",
+                    "other": "Sie sind als Autor angemeldet."
+                },
+                "other": "Du bist angemeldet."
+            }
+        }"#;
+        lingua.load_translations_from_str("de", catalog).unwrap();
+        lingua.load_translations_from_str("de-AT", catalog).unwrap();
+        lingua.load_translations_from_str("fr", catalog).unwrap();
+        lingua.set_language("de").unwrap();
+
+        let welcome = |gender| {
+            lingua
+                .t("welcome", &[("gender", gender), ("name", "Kim")])
+                .unwrap()
+        };
+        assert_eq!(welcome("female"), "Willkommen, liebe Kim");
+        assert_eq!(welcome("male"), "Willkommen, lieber Kim");
+        assert_eq!(welcome("nonbinary"), "Willkommen, Kim");
+
+        assert_eq!(
+            lingua.t("signed_in", &[("gender", "female")]).unwrap(),
+            "Sie sind als Autorin angemeldet."
+        );
+        assert_eq!(
+            lingua.t("signed_in", &[("formality", "informal")]).unwrap(),
+            "Du bist angemeldet."
+        );
+        assert_eq!(
+            lingua.t("signed_in", &[("formality", "")]).unwrap(),
+            "Sie sind als Autor angemeldet."
+        );
+        lingua.set_language("de-AT").unwrap();
+        assert_eq!(
+            lingua.t("signed_in", &[]).unwrap(),
+            "Sie sind als Autor angemeldet."
+        );
+        lingua.set_language("fr").unwrap();
+        assert_eq!(lingua.t("signed_in", &[]).unwrap(), "Du bist angemeldet.");
+    }
+
+    #[test]
+    fn test_translate_ordinal() {
+        let lingua = Lingua::default();
+        lingua
+            .load_translations_from_str(
+                "en",
+                r#"{
+                    "finished": {
+                        "$ordinal": "place",
+                        "one": "You finished {{place}}st",
+                        "two": "You finished {{place}}nd",
+                        "few": "You finished {{place}}rd",
+                        "other": "You finished {{place}}th"
+                    },
+                    "rank": "Rank: {{place, ordinal}}"
+                }"#,
+            )
+            .unwrap();
+        lingua.set_language("en").unwrap();
+
+        let finished = |place| lingua.t("finished", &[("place", place)]).unwrap();
+        assert_eq!(finished("1"), "You finished 1st");
+        assert_eq!(finished("12"), "You finished 12th");
+        assert_eq!(finished("23"), "You finished 23rd");
+        assert_eq!(lingua.t("rank", &[("place", "42")]).unwrap(), "Rank: 42nd");
+        assert_eq!(lingua.format_ordinal(3), "3rd");
+    }
+
+    #[test]
+    fn test_translate_icu() {
+        let lingua = Lingua::new("languages")
+            .with_message_format(MessageFormat::Icu)
+            .build();
+        lingua
+            .load_translations_from_str(
+                "en",
+                r#"{"inbox": {"summary": "{name} has {count, plural, =0 {no messages} one {# message} other {# messages}}"}}"#,
+            )
+            .unwrap();
+
+        assert_eq!(
+            lingua
+                .t("inbox.summary", &[("name", "Ann"), ("count", "0")])
+                .unwrap(),
+            "Ann has no messages"
+        );
+        assert_eq!(
+            lingua
+                .t("inbox.summary", &[("name", "Ann"), ("count", "2")])
+                .unwrap(),
+            "Ann has 2 messages"
+        );
+
+        let result = lingua.load_translations_from_str("de", r#"{"a": {"b": "{broken"}}"#);
+        match result {
+            Err(LinguaError::MessageFormat { file, key, .. }) => {
+                assert_eq!((file.as_str(), key.as_str()), ("de", "a.b"));
+            }
+            _ => panic!("expected a message format error"),
+        }
+        assert!(!lingua.has_language("de"));
+    }
+
+    #[test]
+    fn test_load_fluent_alongside_json() {
+        let test_dir = std::env::temp_dir().join("lingua_test_fluent");
+        let _ = fs::create_dir(&test_dir);
+        fs::write(test_dir.join("en.json"), r#"{"welcome": "Welcome!"}"#).unwrap();
+        fs::write(
+            test_dir.join("en.ftl"),
+            "menu-file = File\n    .save = Save\nemails = { $count ->\n    [0] No emails\n    [one] One email\n   *[other] { $count } emails\n}\n",
+        )
+        .unwrap();
+        fs::write(test_dir.join("de.ftl"), "welcome = Willkommen!\n").unwrap();
+        // Only lowercase extensions are loaded.
+        fs::write(test_dir.join("fr.JSON"), r#"{"welcome": "Bienvenue !"}"#).unwrap();
+
+        let lingua = Lingua::new(test_dir.to_str().unwrap()).build();
+        assert_eq!(lingua.load_available_languages().unwrap(), 2);
+        lingua.set_language("en").unwrap();
+
+        assert_eq!(lingua.t("welcome", &[]).unwrap(), "Welcome!");
+        assert_eq!(lingua.t("menu.file", &[]).unwrap(), "File");
+        assert_eq!(lingua.t("menu.file.save", &[]).unwrap(), "Save");
+        assert_eq!(lingua.t("emails", &[("count", "0")]).unwrap(), "No emails");
+        assert_eq!(lingua.t("emails", &[("count", "4")]).unwrap(), "4 emails");
+
+        lingua.set_language("de").unwrap();
+        assert_eq!(lingua.t("welcome", &[]).unwrap(), "Willkommen!");
+
+        let _ = fs::remove_dir_all(&test_dir);
+    }
+
+    #[test]
+    fn test_load_gettext_catalog() {
+        let test_dir = std::env::temp_dir().join("lingua_test_gettext");
+        let _ = fs::create_dir(&test_dir);
+        fs::write(
+            test_dir.join("pl.po"),
+            r#"msgid ""
+msgstr "Plural-Forms: nplurals=3; plural=(n==1 ? 0 : n%10>=2 && n%10<=4 && (n%100<12 || n%100>14) ? 1 : 2);\n"
+
+msgid "Open the file."
+msgstr "Otwórz plik."
+
+msgctxt "verb"
+msgid "Open"
+msgstr "Otwórz"
+
+msgid "{{count}} file"
+msgid_plural "{{count}} files"
+msgstr[0] "{{count}} plik"
+msgstr[1] "{{count}} pliki"
+msgstr[2] "{{count}} plików"
+"#,
+        )
+        .unwrap();
+
+        let lingua = Lingua::new(test_dir.to_str().unwrap()).build();
+        assert_eq!(lingua.load_available_languages().unwrap(), 1);
+        lingua.set_language("pl").unwrap();
+
+        assert_eq!(lingua.t("Open the file.", &[]).unwrap(), "Otwórz plik.");
+        assert_eq!(lingua.translate_ctx("verb", "Open", &[]).unwrap(), "Otwórz");
+        assert!(lingua.t("Open", &[]).is_err());
+        let files = |count| lingua.t("{{count}} file", &[("count", count)]).unwrap();
+        assert_eq!(files("1"), "1 plik");
+        assert_eq!(files("3"), "3 pliki");
+        assert_eq!(files("12"), "12 plików");
+
+        let _ = fs::remove_dir_all(&test_dir);
+    }
+
+    #[test]
+    fn test_select_object() {
+        let lingua = Lingua::default();
+        lingua
+            .load_translations_from_str(
+                "en",
+                r#"{"left": {"$select": "gender", "female": "She left", "other": "They left"}}"#,
+            )
+            .unwrap();
+
+        assert_eq!(
+            lingua.t("left", &[("gender", "female")]).unwrap(),
+            "She left"
+        );
+        assert_eq!(lingua.t("left", &[]).unwrap(), "They left");
+    }
+
+    #[test]
+    fn test_select_language_uses_fallback() {
+        let lingua = Lingua::new("languages")
+            .with_fallback("de-CH", &["de"])
+            .build();
+        lingua
+            .load_translations_from_str("de", r#"{"a": "A"}"#)
+            .unwrap();
+
+        lingua.select_languages(&["de-CH"]);
+        assert_eq!(lingua.get_language().unwrap(), "de");
+    }
+
+    #[test]
+    fn test_select_system_languages() {
+        let env = |vars: &'static [(&'static str, &'static str)]| {
+            move |name: &str| {
+                vars.iter()
+                    .find(|(var, _)| *var == name)
+                    .map(|(_, value)| value.to_string())
+            }
+        };
+        assert_eq!(
+            posix_languages(env(&[
+                ("LANGUAGE", "sr@latin:de_CH::en"),
+                ("LC_MESSAGES", "fr_FR.UTF-8"),
+                ("LANG", "de_DE.UTF-8@euro"),
+            ])),
+            ["sr-Latn", "de-CH", "en", "fr-FR"]
+        );
+        assert_eq!(
+            posix_languages(env(&[("LC_ALL", ""), ("LANG", "pt_BR.UTF-8")])),
+            ["pt-BR"]
+        );
+        assert!(posix_languages(env(&[("LANGUAGE", "de"), ("LC_ALL", "C.UTF-8")])).is_empty());
+        assert!(posix_languages(env(&[("LANG", "POSIX")])).is_empty());
+
+        let lingua = Lingua::new("languages")
+            .with_fallback_language("en")
+            .build();
+        for lang in ["en", "de-AT", "fr"] {
+            lingua.load_translations_from_str(lang, "{}").unwrap();
+        }
+        lingua.select_languages(&["it", "de-CH", "fr"]);
+        assert_eq!(lingua.get_language().unwrap(), "de-AT");
+        lingua.select_languages(&["it", "fr-BE"]);
+        assert_eq!(lingua.get_language().unwrap(), "fr");
+        lingua.select_languages(&["it", "ja"]);
+        assert_eq!(lingua.get_language().unwrap(), "en");
+    }
 
+    #[test]
+    fn test_region_and_script_files() {
+        let test_dir = std::env::temp_dir().join("lingua_test_locales");
+        let _ = fs::create_dir(&test_dir);
+        fs::write(
+            test_dir.join("pt.json"),
+            r#"{"bus": "Autocarro", "yes": "Sim"}"#,
+        )
+        .unwrap();
+        fs::write(test_dir.join("pt-BR.json"), r#"{"bus": "Ônibus"}"#).unwrap();
+        fs::write(test_dir.join("zh.json"), r#"{"yes": "是"}"#).unwrap();
+        fs::write(test_dir.join("zh-Hant.json"), r#"{"no": "不是"}"#).unwrap();
+
+        let lingua = Lingua::new(test_dir.to_str().unwrap()).build();
+        assert_eq!(lingua.load_available_languages().unwrap(), 4);
+
+        lingua.select_languages(&["pt_br-u-ca-gregory"]);
+        assert_eq!(lingua.get_language().unwrap(), "pt-BR");
+        assert_eq!(lingua.t("bus", &[]).unwrap(), "Ônibus");
+        assert_eq!(lingua.t("yes", &[]).unwrap(), "Sim");
+
+        lingua.select_languages(&["zh-Hant-TW"]);
+        assert_eq!(lingua.get_language().unwrap(), "zh-Hant");
+        assert_eq!(lingua.t("no", &[]).unwrap(), "不是");
+        assert_eq!(
+            lingua.translate_with_language("yes", &[]).unwrap(),
+            ("zh".to_string(), "是".to_string())
+        );
+
+        let _ = fs::remove_dir_all(&test_dir);
+    }
+
+    #[test]
+    fn test_load_embedded() {
+        static FILES: &[EmbeddedFile] = &[
+            EmbeddedFile {
+                lang_code: "en",
+                extension: "json",
+                content: br#"{"welcome": "Welcome!", "menu": {"file": "File"}}"#,
+            },
+            EmbeddedFile {
+                lang_code: "en",
+                extension: "ftl",
+                content: b"menu-file = File menu\n",
+            },
+            EmbeddedFile {
+                lang_code: "en",
+                extension: "txt",
+                content: b"ignored",
+            },
+        ];
+
+        let lingua = Lingua::new("missing").with_embedded(FILES).init().unwrap();
+        lingua.set_language("en").unwrap();
+        assert_eq!(lingua.get_languages().unwrap(), vec!["en"]);
+        assert_eq!(lingua.t("welcome", &[]).unwrap(), "Welcome!");
+        assert_eq!(lingua.t("menu.file", &[]).unwrap(), "File menu");
+    }
+
+    #[cfg(feature = "macros")]
+    #[test]
+    fn test_include_translations() {
+        let lingua = Lingua::new("languages")
+            .with_embedded(crate::prelude::include_translations!(
+                "examples/basic/languages"
+            ))
+            .init()
+            .unwrap();
+        lingua.set_language("de").unwrap();
+        assert_eq!(lingua.t("menu.file.open", &[]).unwrap(), "Öffnen");
+    }
+
+    #[test]
+    fn test_hot_reload() {
+        let test_dir = std::env::temp_dir().join("lingua_test_hot_reload");
+        let _ = fs::remove_dir_all(&test_dir);
+        fs::create_dir(&test_dir).unwrap();
+        fs::write(
+            test_dir.join("en.json"),
+            r#"{"hello": "Hello", "bye": "Bye"}"#,
+        )
+        .unwrap();
+
+        let lingua = Lingua::new(test_dir.to_str().unwrap()).build();
+        lingua.load_available_languages().unwrap();
+        lingua.set_language("en").unwrap();
+        let events = Arc::new(RwLock::new(Vec::new()));
+        let received = events.clone();
+        lingua.on_reload(move |event| {
+            received.write().unwrap().push(format!(
+                "{:?} {:?} {}",
+                event.languages,
+                event.keys,
+                event.errors.len()
+            ));
+        });
+
+        fs::write(test_dir.join("en.json"), r#"{"hello": "Hi", "bye": "Bye"}"#).unwrap();
+        lingua.reload_languages(&["en".to_string()].into());
+        assert_eq!(lingua.t("hello", &[]).unwrap(), "Hi");
+
+        fs::write(test_dir.join("en.json"), r#"{"hello": "#).unwrap();
+        lingua.reload_languages(&["en".to_string()].into());
+        assert_eq!(lingua.t("hello", &[]).unwrap(), "Hi");
+
+        assert_eq!(
+            *events.read().unwrap(),
+            vec![r#"{"en"} {"hello"} 0"#, "{} {} 1"]
+        );
+        let _ = fs::remove_dir_all(&test_dir);
+    }
+
+    #[test]
+    fn test_hot_reload_watcher() {
+        use std::sync::mpsc;
+
+        let test_dir = std::env::temp_dir().join("lingua_test_hot_reload_watcher");
+        let _ = fs::remove_dir_all(&test_dir);
+        fs::create_dir(&test_dir).unwrap();
+        let file = test_dir.join("en.json");
+        fs::write(&file, r#"{"hello": "Hello"}"#).unwrap();
+
+        let lingua = Lingua::new(test_dir.to_str().unwrap())
+            .with_hot_reload(Duration::from_millis(10))
+            .init()
+            .unwrap();
+        lingua.set_language("en").unwrap();
+        let (sender, receiver) = mpsc::channel();
+        lingua.on_reload(move |event| {
+            let _ = sender.send((event.languages.clone(), event.keys.clone()));
+        });
+
+        fs::write(&file, r#"{"hello": "Hi"}"#).unwrap();
+        // Move the modification time forward in case the file system's resolution is coarse.
+        fs::File::options()
+            .write(true)
+            .open(&file)
+            .and_then(|f| f.set_modified(std::time::SystemTime::now() + Duration::from_secs(5)))
+            .unwrap();
+
+        let (languages, keys) = receiver.recv_timeout(Duration::from_secs(10)).unwrap();
+        assert_eq!(languages.into_iter().collect::<Vec<_>>(), ["en"]);
+        assert_eq!(keys.into_iter().collect::<Vec<_>>(), ["hello"]);
+        assert_eq!(lingua.t("hello", &[]).unwrap(), "Hi");
+
+        // The watcher does not keep the catalog alive, so dropping it drops the callback.
+        drop(lingua);
+        assert_eq!(
+            receiver.recv_timeout(Duration::from_secs(10)),
+            Err(mpsc::RecvTimeoutError::Disconnected)
+        );
+        let _ = fs::remove_dir_all(&test_dir);
+    }
+
+    #[test]
+    fn test_load_lang_from_config() {
         let mut map = Map::new();
         map.insert("hello".to_string(), Value::String("Hallo".to_string()));
-        TRANSLATIONS.write().unwrap().insert("de".to_string(), map);
-
-        *CURRENT_LANGUAGE.write().unwrap() = "de".to_string();
+        let lingua = setup("de", map);
 
         let test_dir = std::env::temp_dir().join("lingua_test");
         let _ = fs::create_dir(&test_dir);
@@ -742,20 +2566,26 @@ mod tests {
         );
 
         assert_eq!(
-            Lingua::load_lang_from_config(&simple_config_path, "language").unwrap(),
+            lingua
+                .load_lang_from_config(&simple_config_path, "language")
+                .unwrap(),
             "de"
         );
         assert_eq!(
-            Lingua::load_lang_from_config(&json_config_path, "language").unwrap(),
+            lingua
+                .load_lang_from_config(&json_config_path, "language")
+                .unwrap(),
             "de"
         );
         assert_eq!(
-            Lingua::load_lang_from_config(&toml_config_path, "language").unwrap(),
+            lingua
+                .load_lang_from_config(&toml_config_path, "language")
+                .unwrap(),
             "de"
         );
 
         let invalid_config_path = test_dir.join("invalid_config.txt");
-        let result = Lingua::load_lang_from_config(&invalid_config_path, "language");
+        let result = lingua.load_lang_from_config(&invalid_config_path, "language");
         assert!(matches!(result, Err(LinguaError::ConfigFileNotFound(_))));
 
         let _ = fs::remove_dir_all(&test_dir);
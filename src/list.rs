@@ -0,0 +1,328 @@
+//! Locale-aware list formatting.
+//!
+//! Lists are joined with the CLDR list patterns of the language: "Alice, Bob and Carol",
+//! "Alice, Bob oder Carol", "1 hr, 20 min". Common languages are built in and matched by
+//! their full tag first (e.g. `en-GB`), then by their primary language subtag; unknown
+//! languages use the English patterns. Spanish "y" and "o" become "e" and "u" before words
+//! starting with the same sound.
+//!
+//! In translation strings, `{{name, list}}` joins a list-valued parameter, optionally with
+//! a type and width (`{{name, list:or}}`, `{{name, list:unit-narrow}}`). List-valued
+//! parameters are JSON arrays of strings, see [`to_param`]; any other value is a list of
+//! one item.
+use crate::locale::find_locale;
+pub use crate::relative::Width;
+use std::fmt;
+
+/// The kind of list.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ListType {
+    /// "A, B, and C".
+    #[default]
+    Conjunction,
+    /// "A, B, or C".
+    Disjunction,
+    /// A list of measurements: "1 hour, 20 minutes".
+    Unit,
+}
+
+impl ListType {
+    /// The keyword used for the type in placeholders.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ListType::Conjunction => "and",
+            ListType::Disjunction => "or",
+            ListType::Unit => "unit",
+        }
+    }
+
+    /// Parse a placeholder keyword.
+    pub fn from_keyword(keyword: &str) -> Option<Self> {
+        match keyword.trim() {
+            "and" | "conjunction" => Some(ListType::Conjunction),
+            "or" | "disjunction" => Some(ListType::Disjunction),
+            "unit" => Some(ListType::Unit),
+            _ => None,
+        }
+    }
+
+    fn index(self) -> usize {
+        self as usize
+    }
+}
+
+impl fmt::Display for ListType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// The separators of a list pattern.
+#[derive(Clone, Copy)]
+struct Separators {
+    /// Between the items of a list of two.
+    two: &'static str,
+    /// Between the first two items of a longer list.
+    start: &'static str,
+    /// Between the other items.
+    middle: &'static str,
+    /// Before the last item.
+    end: &'static str,
+}
+
+/// Separators with `between` between all items but the last two, which `last` separates.
+const fn list(between: &'static str, last: &'static str) -> Separators {
+    Separators {
+        two: last,
+        start: between,
+        middle: between,
+        end: last,
+    }
+}
+
+/// Separators by list type and width.
+type Patterns = [[Separators; 3]; 3];
+
+/// Patterns where the widths only differ for units, which are joined with spaces when
+/// narrow.
+const fn patterns(and: &'static str, or: &'static str) -> Patterns {
+    [
+        [list(", ", and); 3],
+        [list(", ", or); 3],
+        [list(", ", and), list(", ", and), list(" ", " ")],
+    ]
+}
+
+const EN: Patterns = [
+    [
+        Separators {
+            two: " and ",
+            end: ", and ",
+            ..list(", ", "")
+        },
+        Separators {
+            two: " & ",
+            end: ", & ",
+            ..list(", ", "")
+        },
+        list(", ", ", "),
+    ],
+    [
+        Separators {
+            two: " or ",
+            end: ", or ",
+            ..list(", ", "")
+        },
+        Separators {
+            two: " or ",
+            end: ", or ",
+            ..list(", ", "")
+        },
+        Separators {
+            two: " or ",
+            end: ", or ",
+            ..list(", ", "")
+        },
+    ],
+    [list(", ", ", "), list(", ", ", "), list(" ", " ")],
+];
+
+/// Built-in languages, by lowercase tag.
+const LOCALES: &[(&str, Patterns)] = &[
+    ("de", patterns(" und ", " oder ")),
+    ("en", EN),
+    (
+        "en-gb",
+        [
+            [list(", ", " and "); 3],
+            [list(", ", " or "); 3],
+            [list(", ", ", "), list(", ", ", "), list(" ", " ")],
+        ],
+    ),
+    ("es", patterns(" y ", " o ")),
+    ("fr", patterns(" et ", " ou ")),
+    ("it", patterns(" e ", " o ")),
+    (
+        "ja",
+        [
+            [list("、", "、"); 3],
+            [Separators {
+                two: "または",
+                end: "、または",
+                ..list("、", "")
+            }; 3],
+            [list(" ", " "), list(" ", " "), list("", "")],
+        ],
+    ),
+    ("nl", patterns(" en ", " of ")),
+    ("pl", patterns(" i ", " lub ")),
+    ("pt", patterns(" e ", " ou ")),
+    ("ru", patterns(" и ", " или ")),
+    ("sv", patterns(" och ", " eller ")),
+    (
+        "zh",
+        [
+            [list("、", "和"); 3],
+            [list("、", "或"); 3],
+            [list("", ""); 3],
+        ],
+    ),
+];
+
+/// Join a list of items.
+///
+/// # Arguments
+///
+/// * `lang` - A language code, e.g. `"de"`.
+/// * `items` - The items to join.
+/// * `list_type` - Whether the list is a conjunction, disjunction or a list of units.
+/// * `width` - The width of the pattern.
+///
+/// # Example
+///
+/// ```rust
+/// use lingua_i18n_rs::list::{format_list, ListType, Width};
+///
+/// let names = ["Alice", "Bob", "Carol"];
+/// assert_eq!(format_list("en", &names, ListType::Conjunction, Width::Long), "Alice, Bob, and Carol");
+/// assert_eq!(format_list("de", &names, ListType::Disjunction, Width::Long), "Alice, Bob oder Carol");
+/// assert_eq!(format_list("es", &["Ana", "Inés"], ListType::Conjunction, Width::Long), "Ana e Inés");
+/// ```
+pub fn format_list<S: AsRef<str>>(
+    lang: &str,
+    items: &[S],
+    list_type: ListType,
+    width: Width,
+) -> String {
+    let patterns = find_locale(lang, LOCALES, &EN);
+    let spanish = lang
+        .split(['-', '_'])
+        .next()
+        .is_some_and(|language| language.eq_ignore_ascii_case("es"));
+    let separators = patterns[list_type.index()][width.index()];
+
+    let mut out = String::new();
+    for (index, item) in items.iter().enumerate() {
+        let item = item.as_ref();
+        let separator = match (index, items.len()) {
+            (0, _) => "",
+            (1, 2) => separators.two,
+            (1, _) => separators.start,
+            (index, len) if index == len - 1 => separators.end,
+            _ => separators.middle,
+        };
+        if spanish {
+            out.push_str(&spanish_separator(separator, item));
+        } else {
+            out.push_str(separator);
+        }
+        out.push_str(item);
+    }
+    out
+}
+
+/// Spanish uses "e" instead of "y" before an /i/ sound and "u" instead of "o" before an
+/// /o/ sound.
+fn spanish_separator(separator: &str, next: &str) -> String {
+    let next = next.to_lowercase();
+    let mut chars = next.chars();
+    let first = chars.next();
+    let second = chars.next();
+    let sound = match (first, second) {
+        (Some('h'), Some(vowel)) if chars.next().is_none_or(|c| !"aeiouáéíóú".contains(c)) => {
+            Some(vowel)
+        }
+        (Some('h'), _) => None,
+        _ => first,
+    };
+
+    match separator {
+        " y " if matches!(sound, Some('i' | 'í')) => " e ".to_string(),
+        " o " if matches!(sound, Some('o' | 'ó' | '8')) || leading_number(&next) == "11" => {
+            " u ".to_string()
+        }
+        _ => separator.to_string(),
+    }
+}
+
+fn leading_number(text: &str) -> &str {
+    let end = text
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(text.len());
+    &text[..end]
+}
+
+/// Encode a list as a parameter value for `{{name, list}}` placeholders.
+///
+/// # Example
+///
+/// ```rust
+/// use lingua_i18n_rs::list::to_param;
+///
+/// assert_eq!(to_param(&["Alice", "Bob"]), r#"["Alice","Bob"]"#);
+/// ```
+pub fn to_param<S: AsRef<str>>(items: &[S]) -> String {
+    let items: Vec<&str> = items.iter().map(AsRef::as_ref).collect();
+    serde_json::to_string(&items).unwrap_or_default()
+}
+
+/// Decode a list-valued parameter; values that are no JSON array of strings are a list of
+/// one item.
+pub(crate) fn from_param(value: &str) -> Vec<String> {
+    if value.trim_start().starts_with('[')
+        && let Ok(items) = serde_json::from_str::<Vec<String>>(value)
+    {
+        return items;
+    }
+    vec![value.to_string()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_list() {
+        let names = ["Alice", "Bob", "Carol", "Dave"];
+        let and =
+            |lang, items: &[&str], width| format_list(lang, items, ListType::Conjunction, width);
+        assert_eq!(and("en", &[], Width::Long), "");
+        assert_eq!(and("en", &names[..1], Width::Long), "Alice");
+        assert_eq!(and("en", &names[..2], Width::Long), "Alice and Bob");
+        assert_eq!(and("en", &names, Width::Short), "Alice, Bob, Carol, & Dave");
+        assert_eq!(
+            and("en-GB", &names[..3], Width::Long),
+            "Alice, Bob and Carol"
+        );
+        assert_eq!(and("fr", &names[..3], Width::Long), "Alice, Bob et Carol");
+        assert_eq!(and("zh", &["甲", "乙", "丙"], Width::Long), "甲、乙和丙");
+        assert_eq!(
+            format_list("en", &names[..3], ListType::Disjunction, Width::Long),
+            "Alice, Bob, or Carol"
+        );
+        assert_eq!(
+            format_list("de", &["1 Std.", "20 Min."], ListType::Unit, Width::Narrow),
+            "1 Std. 20 Min."
+        );
+    }
+
+    #[test]
+    fn test_spanish_conjunctions() {
+        let and = |items: &[&str]| format_list("es", items, ListType::Conjunction, Width::Long);
+        let or = |items: &[&str]| format_list("es", items, ListType::Disjunction, Width::Long);
+        assert_eq!(and(&["Ana", "Hilario"]), "Ana e Hilario");
+        assert_eq!(and(&["agua", "hielo"]), "agua y hielo");
+        assert_eq!(and(&["Ana", "Bea", "Carla"]), "Ana, Bea y Carla");
+        assert_eq!(or(&["siete", "ocho"]), "siete u ocho");
+        assert_eq!(or(&["7", "8"]), "7 u 8");
+        assert_eq!(or(&["10", "11"]), "10 u 11");
+        assert_eq!(or(&["10", "110"]), "10 o 110");
+    }
+
+    #[test]
+    fn test_params() {
+        assert_eq!(from_param(&to_param(&["a\"b", "c"])), vec!["a\"b", "c"]);
+        assert_eq!(from_param("Alice"), vec!["Alice"]);
+        assert_eq!(from_param("[not json"), vec!["[not json"]);
+    }
+}
@@ -0,0 +1,349 @@
+//! BCP 47 language tags.
+//!
+//! A [`Locale`] holds the subtags of a tag like `zh-Hant-TW` or `en-US-u-ms-metric`: the
+//! language, an optional script and region, variants and the keywords of the `-u-`
+//! extension. Parsing accepts `_` as separator and any letter case, and [`Locale`] prints
+//! the canonical form (`zh-Hant-TW`), so `zh_hant_tw` and `ZH-HANT-TW` name the same locale.
+//!
+//! POSIX locale names from the environment, like `de_DE.UTF-8@euro`, are converted with
+//! [`Locale::from_posix`].
+//!
+//! Translations are looked up along the parents of a locale, which drop the most specific
+//! subtags first: `zh-Hant-TW` falls back to `zh-Hant` and then to `zh`.
+use crate::error::LinguaError;
+use std::fmt;
+use std::str::FromStr;
+
+/// A parsed BCP 47 language tag.
+///
+/// Extensions other than `-u-` and private use subtags (`-x-`) are accepted but not kept.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Locale {
+    /// The language subtag, lowercase, e.g. `"zh"`.
+    pub language: String,
+    /// The script subtag, title case, e.g. `"Hant"`.
+    pub script: Option<String>,
+    /// The region subtag, uppercase, e.g. `"TW"` or `"419"`.
+    pub region: Option<String>,
+    /// Variant subtags, lowercase, e.g. `["valencia"]`.
+    pub variants: Vec<String>,
+    /// Keywords of the `-u-` extension, lowercase, e.g. `[("ms", "metric")]`. Keywords
+    /// without a value have an empty one.
+    pub keywords: Vec<(String, String)>,
+}
+
+impl Locale {
+    /// Parse a language tag.
+    ///
+    /// # Arguments
+    ///
+    /// * `tag` - A BCP 47 tag, e.g. `"pt-BR"` or `"sr_Latn_RS"`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use lingua_i18n_rs::locale::Locale;
+    ///
+    /// let locale = Locale::parse("zh_hant_tw")?;
+    /// assert_eq!(locale.language, "zh");
+    /// assert_eq!(locale.script.as_deref(), Some("Hant"));
+    /// assert_eq!(locale.region.as_deref(), Some("TW"));
+    /// assert_eq!(locale.to_string(), "zh-Hant-TW");
+    /// # Ok::<(), lingua_i18n_rs::prelude::LinguaError>(())
+    /// ```
+    pub fn parse(tag: &str) -> Result<Locale, LinguaError> {
+        let invalid = || LinguaError::InvalidLocale(tag.to_string());
+        let lowercase = tag.to_ascii_lowercase();
+        let mut subtags = lowercase.split(['-', '_']).peekable();
+
+        let language = subtags.next().unwrap_or_default();
+        if !matches!(language.len(), 2..=3 | 5..=8) || !is_alpha(language) {
+            return Err(invalid());
+        }
+        let mut locale = Locale {
+            language: language.to_string(),
+            script: None,
+            region: None,
+            variants: Vec::new(),
+            keywords: Vec::new(),
+        };
+
+        if let Some(script) = subtags.next_if(|s| s.len() == 4 && is_alpha(s)) {
+            locale.script = Some(script[..1].to_ascii_uppercase() + &script[1..]);
+        }
+        if let Some(region) =
+            subtags.next_if(|s| (s.len() == 2 && is_alpha(s)) || (s.len() == 3 && is_digit(s)))
+        {
+            locale.region = Some(region.to_ascii_uppercase());
+        }
+        while let Some(variant) = subtags.next_if(|s| {
+            is_alphanumeric(s)
+                && (matches!(s.len(), 5..=8) || (s.len() == 4 && s.as_bytes()[0].is_ascii_digit()))
+        }) {
+            locale.variants.push(variant.to_string());
+        }
+
+        while let Some(singleton) = subtags.next() {
+            if singleton.len() != 1 || !is_alphanumeric(singleton) {
+                return Err(invalid());
+            }
+            if singleton == "x" {
+                // Private use subtags run to the end of the tag.
+                let rest: Vec<&str> = subtags.collect();
+                if rest.is_empty()
+                    || !rest
+                        .iter()
+                        .all(|s| matches!(s.len(), 1..=8) && is_alphanumeric(s))
+                {
+                    return Err(invalid());
+                }
+                break;
+            }
+
+            let mut empty = true;
+            while let Some(subtag) = subtags.next_if(|s| s.len() > 1) {
+                if !is_alphanumeric(subtag) || subtag.len() > 8 {
+                    return Err(invalid());
+                }
+                empty = false;
+                if singleton != "u" {
+                    continue;
+                }
+                if subtag.len() == 2 {
+                    locale.keywords.push((subtag.to_string(), String::new()));
+                } else if let Some((_, value)) = locale.keywords.last_mut() {
+                    if !value.is_empty() {
+                        value.push('-');
+                    }
+                    value.push_str(subtag);
+                }
+                // Attributes before the first keyword are not kept.
+            }
+            if empty {
+                return Err(invalid());
+            }
+        }
+
+        Ok(locale)
+    }
+
+    /// Convert a POSIX locale name, as found in `LANG` or `LC_MESSAGES`, to a locale.
+    ///
+    /// The codeset (`.UTF-8`) is dropped. The modifiers `@latin` and `@cyrillic` select a
+    /// script and other modifiers of five to eight letters, like `@valencia`, become
+    /// variants; the rest, like `@euro`, are dropped. The `C` and `POSIX` locales name no
+    /// language and are rejected.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - A POSIX locale name, e.g. `"sr_RS.UTF-8@latin"`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use lingua_i18n_rs::locale::Locale;
+    ///
+    /// assert_eq!(Locale::from_posix("de_DE.UTF-8@euro")?.to_string(), "de-DE");
+    /// assert_eq!(Locale::from_posix("sr_RS@latin")?.to_string(), "sr-Latn-RS");
+    /// assert_eq!(Locale::from_posix("ca_ES.UTF-8@valencia")?.to_string(), "ca-ES-valencia");
+    /// assert!(Locale::from_posix("C.UTF-8").is_err());
+    /// # Ok::<(), lingua_i18n_rs::prelude::LinguaError>(())
+    /// ```
+    pub fn from_posix(name: &str) -> Result<Locale, LinguaError> {
+        let (name, modifier) = name.split_once('@').unwrap_or((name, ""));
+        let tag = name.split('.').next().unwrap_or_default();
+        if tag.eq_ignore_ascii_case("c") || tag.eq_ignore_ascii_case("posix") {
+            return Err(LinguaError::InvalidLocale(name.to_string()));
+        }
+
+        let mut locale = Locale::parse(tag)?;
+        match modifier.to_ascii_lowercase().as_str() {
+            "latin" => locale.script = Some("Latn".to_string()),
+            "cyrillic" => locale.script = Some("Cyrl".to_string()),
+            modifier if matches!(modifier.len(), 5..=8) && is_alpha(modifier) => {
+                locale.variants.push(modifier.to_string());
+            }
+            _ => {}
+        }
+        Ok(locale)
+    }
+
+    /// Get the value of a `-u-` extension keyword, e.g. `"ms"` or `"ca"`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use lingua_i18n_rs::locale::Locale;
+    ///
+    /// let locale = Locale::parse("en-US-u-ca-buddhist-ms-metric")?;
+    /// assert_eq!(locale.keyword("ms"), Some("metric"));
+    /// assert_eq!(locale.keyword("nu"), None);
+    /// # Ok::<(), lingua_i18n_rs::prelude::LinguaError>(())
+    /// ```
+    pub fn keyword(&self, key: &str) -> Option<&str> {
+        self.keywords
+            .iter()
+            .find(|(name, _)| name == key)
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Get the locale translations fall back to, if any.
+    ///
+    /// The parent drops the most specific part of the locale: first the `-u-` extension,
+    /// then the variants, the region and finally the script. A bare language has no parent.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use lingua_i18n_rs::locale::Locale;
+    ///
+    /// let locale = Locale::parse("zh-Hant-TW")?;
+    /// let parent = locale.parent().unwrap();
+    /// assert_eq!(parent.to_string(), "zh-Hant");
+    /// assert_eq!(parent.parent().unwrap().to_string(), "zh");
+    /// assert_eq!(parent.parent().unwrap().parent(), None);
+    /// # Ok::<(), lingua_i18n_rs::prelude::LinguaError>(())
+    /// ```
+    pub fn parent(&self) -> Option<Locale> {
+        let mut parent = self.clone();
+        if !parent.keywords.is_empty() {
+            parent.keywords.clear();
+        } else if !parent.variants.is_empty() {
+            parent.variants.clear();
+        } else if parent.region.is_some() {
+            parent.region = None;
+        } else if parent.script.is_some() {
+            parent.script = None;
+        } else {
+            return None;
+        }
+        Some(parent)
+    }
+}
+
+impl FromStr for Locale {
+    type Err = LinguaError;
+
+    fn from_str(tag: &str) -> Result<Self, Self::Err> {
+        Locale::parse(tag)
+    }
+}
+
+impl fmt::Display for Locale {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.language)?;
+        for subtag in self.script.iter().chain(&self.region).chain(&self.variants) {
+            write!(f, "-{}", subtag)?;
+        }
+        if !self.keywords.is_empty() {
+            f.write_str("-u")?;
+            for (key, value) in &self.keywords {
+                write!(f, "-{}", key)?;
+                if !value.is_empty() {
+                    write!(f, "-{}", value)?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Find the entry for a language in a table keyed by lowercase tags, trying the full tag
+/// first and then dropping subtags from the end, or `default` if nothing matches.
+pub(crate) fn find_locale<'a, T>(lang: &str, table: &'a [(&str, T)], default: &'a T) -> &'a T {
+    let lang = lang.replace('_', "-").to_ascii_lowercase();
+    let mut tag = lang.as_str();
+    loop {
+        if let Some((_, value)) = table.iter().find(|(name, _)| *name == tag) {
+            return value;
+        }
+        match tag.rsplit_once('-') {
+            Some((parent, _)) => tag = parent,
+            None => return default,
+        }
+    }
+}
+
+fn is_alpha(subtag: &str) -> bool {
+    subtag.bytes().all(|b| b.is_ascii_alphabetic())
+}
+
+fn is_digit(subtag: &str) -> bool {
+    subtag.bytes().all(|b| b.is_ascii_digit())
+}
+
+fn is_alphanumeric(subtag: &str) -> bool {
+    subtag.bytes().all(|b| b.is_ascii_alphanumeric())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let locale = Locale::parse("ca-ES-valencia-u-nu-latn-x-private").unwrap();
+        assert_eq!(locale.language, "ca");
+        assert_eq!(locale.script, None);
+        assert_eq!(locale.region.as_deref(), Some("ES"));
+        assert_eq!(locale.variants, ["valencia"]);
+        assert_eq!(locale.keyword("nu"), Some("latn"));
+        assert_eq!(locale.to_string(), "ca-ES-valencia-u-nu-latn");
+
+        assert_eq!(
+            Locale::parse("es_419").unwrap().region.as_deref(),
+            Some("419")
+        );
+        assert_eq!(
+            Locale::parse("SR-latn-rs").unwrap().to_string(),
+            "sr-Latn-RS"
+        );
+        assert_eq!(
+            Locale::parse("de-DE-1996-t-en-u-co-phonebk")
+                .unwrap()
+                .to_string(),
+            "de-DE-1996-u-co-phonebk"
+        );
+
+        for invalid in [
+            "",
+            "e",
+            "toolonglanguage",
+            "de--DE",
+            "de-DE-u",
+            "en-US-x",
+            "de-ä",
+        ] {
+            assert!(Locale::parse(invalid).is_err(), "{}", invalid);
+        }
+    }
+
+    #[test]
+    fn test_parents() {
+        let mut chain = Vec::new();
+        let mut locale = Locale::parse("sr-Latn-RS-ekavsk-u-ca-gregory").ok();
+        while let Some(current) = locale {
+            chain.push(current.to_string());
+            locale = current.parent();
+        }
+        assert_eq!(
+            chain,
+            [
+                "sr-Latn-RS-ekavsk-u-ca-gregory",
+                "sr-Latn-RS-ekavsk",
+                "sr-Latn-RS",
+                "sr-Latn",
+                "sr"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_find_locale() {
+        let table = [("en", 1), ("en-gb", 2), ("zh-hant", 3)];
+        assert_eq!(*find_locale("en_GB", &table, &0), 2);
+        assert_eq!(*find_locale("en-AU-u-ms-metric", &table, &0), 1);
+        assert_eq!(*find_locale("zh-Hant-TW", &table, &0), 3);
+        assert_eq!(*find_locale("zh", &table, &0), 0);
+    }
+}
@@ -0,0 +1,261 @@
+//! Language negotiation.
+//!
+//! Users request a prioritised list of locales, e.g. from an `Accept-Language` header or
+//! `navigator.languages`, and an application has a set of available languages. Negotiation
+//! picks the available languages that serve the requested ones best and reports why each
+//! one matched. A requested locale matches, in order of preference:
+//!
+//! 1. an available language with the same tag ([`MatchReason::Exact`]),
+//! 2. one of its parents, so `de-CH` matches `de` ([`MatchReason::Parent`]),
+//! 3. another available language of the same language and script, so `de` matches `de-AT`
+//!    and `en-AU` matches `en-GB` ([`MatchReason::SameLanguage`]).
+//!
+//! Tags are compared as [`Locale`]s, ignoring case, separators and `-u-` extensions. Tags
+//! that are not valid BCP 47 never match.
+use crate::locale::Locale;
+use std::fmt;
+
+/// How requested locales are matched against the available languages.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NegotiationStrategy {
+    /// Every available language that matches any requested locale, ordered by the priority
+    /// of the requested locales and then by how well they match.
+    Filtering,
+    /// The best available language for each requested locale.
+    #[default]
+    Matching,
+    /// The first available language found by truncating the requested locales in order,
+    /// as in RFC 4647 lookup. Only exact and parent matches count.
+    Lookup,
+}
+
+/// Why a language was chosen during negotiation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MatchReason {
+    /// The language is the requested locale.
+    Exact,
+    /// The language is a parent of the requested locale, e.g. `de` for `de-CH`.
+    Parent,
+    /// The language shares the language and script of the requested locale but differs in
+    /// region or variants, e.g. `de-AT` for `de` or `de-CH`.
+    SameLanguage,
+    /// Nothing matched and the default language was used.
+    Default,
+}
+
+/// A language chosen during negotiation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Negotiated {
+    /// The available language, as it was given.
+    pub language: String,
+    /// The requested locale it matched, or `None` for the default language.
+    pub requested: Option<String>,
+    /// Why the language matched.
+    pub reason: MatchReason,
+}
+
+/// Negotiate the available languages for a list of requested locales.
+///
+/// With [`NegotiationStrategy::Filtering`] and [`NegotiationStrategy::Matching`] the
+/// default language is appended unless it already matched; with
+/// [`NegotiationStrategy::Lookup`] it is only returned when nothing matched.
+///
+/// # Arguments
+///
+/// * `requested` - The requested locales, most preferred first, as strings or [`Locale`]s.
+/// * `available` - The available languages.
+/// * `default` - The language to use when nothing matches.
+/// * `strategy` - How the requested locales are matched.
+///
+/// # Example
+///
+/// ```rust
+/// use lingua_i18n_rs::negotiate::{negotiate_languages, MatchReason, NegotiationStrategy};
+///
+/// let available = ["de", "de-AT", "en", "fr"];
+/// let negotiated = negotiate_languages(
+///     &["de-CH", "fr-FR"],
+///     &available,
+///     Some("en"),
+///     NegotiationStrategy::Matching,
+/// );
+/// let languages: Vec<_> = negotiated.iter().map(|n| n.language.as_str()).collect();
+/// assert_eq!(languages, ["de", "fr", "en"]);
+/// assert_eq!(negotiated[0].reason, MatchReason::Parent);
+/// assert_eq!(negotiated[2].reason, MatchReason::Default);
+/// ```
+pub fn negotiate_languages<R: fmt::Display, A: AsRef<str>>(
+    requested: &[R],
+    available: &[A],
+    default: Option<&str>,
+    strategy: NegotiationStrategy,
+) -> Vec<Negotiated> {
+    let available: Vec<(&str, Locale)> = available
+        .iter()
+        .filter_map(|tag| {
+            let locale = Locale::parse(tag.as_ref()).ok()?;
+            Some((tag.as_ref(), without_keywords(locale)))
+        })
+        .collect();
+
+    let mut result: Vec<Negotiated> = Vec::new();
+    for tag in requested {
+        let tag = tag.to_string();
+        let Ok(locale) = Locale::parse(&tag) else {
+            continue;
+        };
+        let take = match strategy {
+            NegotiationStrategy::Filtering => usize::MAX,
+            NegotiationStrategy::Matching | NegotiationStrategy::Lookup => 1,
+        };
+        let new: Vec<_> = candidates(&without_keywords(locale), &available, strategy)
+            .into_iter()
+            .filter(|(language, _)| !result.iter().any(|n| n.language == *language))
+            .take(take)
+            .collect();
+        for (language, reason) in new {
+            result.push(Negotiated {
+                language: language.to_string(),
+                requested: Some(tag.clone()),
+                reason,
+            });
+        }
+        if strategy == NegotiationStrategy::Lookup && !result.is_empty() {
+            return result;
+        }
+    }
+
+    if let Some(default) = default
+        && !result.iter().any(|n| n.language == default)
+    {
+        result.push(Negotiated {
+            language: default.to_string(),
+            requested: None,
+            reason: MatchReason::Default,
+        });
+    }
+    result
+}
+
+/// Find the available languages matching a requested locale, best match first.
+fn candidates<'a>(
+    requested: &Locale,
+    available: &[(&'a str, Locale)],
+    strategy: NegotiationStrategy,
+) -> Vec<(&'a str, MatchReason)> {
+    let mut candidates = Vec::new();
+    let mut push = |language: &'a str, reason: MatchReason| {
+        if !candidates.iter().any(|(name, _)| *name == language) {
+            candidates.push((language, reason));
+        }
+    };
+
+    for (language, _) in available.iter().filter(|(_, locale)| locale == requested) {
+        push(language, MatchReason::Exact);
+    }
+    let mut parent = requested.parent();
+    while let Some(current) = parent {
+        for (language, _) in available.iter().filter(|(_, locale)| *locale == current) {
+            push(language, MatchReason::Parent);
+        }
+        parent = current.parent();
+    }
+    if strategy != NegotiationStrategy::Lookup {
+        let related = available.iter().filter(|(_, locale)| {
+            locale.language == requested.language
+                && (locale.script.is_none()
+                    || requested.script.is_none()
+                    || locale.script == requested.script)
+        });
+        for (language, _) in related {
+            push(language, MatchReason::SameLanguage);
+        }
+    }
+    candidates
+}
+
+fn without_keywords(mut locale: Locale) -> Locale {
+    locale.keywords.clear();
+    locale
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn languages(negotiated: &[Negotiated]) -> Vec<&str> {
+        negotiated.iter().map(|n| n.language.as_str()).collect()
+    }
+
+    #[test]
+    fn test_strategies() {
+        let available = ["en", "en-GB", "de-AT", "de", "fr-CA", "zh-Hans", "zh-Hant"];
+        let requested = ["de-CH", "fr", "zh-Hant-HK"];
+
+        let filtering = negotiate_languages(
+            &requested,
+            &available,
+            Some("en"),
+            NegotiationStrategy::Filtering,
+        );
+        assert_eq!(
+            languages(&filtering),
+            ["de", "de-AT", "fr-CA", "zh-Hant", "en"]
+        );
+        assert_eq!(
+            filtering.iter().map(|n| n.reason).collect::<Vec<_>>(),
+            [
+                MatchReason::Parent,
+                MatchReason::SameLanguage,
+                MatchReason::SameLanguage,
+                MatchReason::Parent,
+                MatchReason::Default
+            ]
+        );
+
+        let matching =
+            negotiate_languages(&requested, &available, None, NegotiationStrategy::Matching);
+        assert_eq!(languages(&matching), ["de", "fr-CA", "zh-Hant"]);
+        assert_eq!(matching[1].requested.as_deref(), Some("fr"));
+
+        let lookup = negotiate_languages(
+            &["fr", "zh_hant_hk"],
+            &available,
+            Some("en"),
+            NegotiationStrategy::Lookup,
+        );
+        assert_eq!(
+            lookup,
+            [Negotiated {
+                language: "zh-Hant".to_string(),
+                requested: Some("zh_hant_hk".to_string()),
+                reason: MatchReason::Parent,
+            }]
+        );
+    }
+
+    #[test]
+    fn test_default_and_invalid_tags() {
+        let available = ["en-US", "pt-BR"];
+        let negotiated = negotiate_languages(
+            &["not a tag", "en-US-u-ca-gregory", "ja"],
+            &available,
+            Some("pt-BR"),
+            NegotiationStrategy::Matching,
+        );
+        assert_eq!(languages(&negotiated), ["en-US", "pt-BR"]);
+        assert_eq!(negotiated[0].reason, MatchReason::Exact);
+
+        let lookup = negotiate_languages(
+            &["ja", "es-419"],
+            &available,
+            Some("en-US"),
+            NegotiationStrategy::Lookup,
+        );
+        assert_eq!(lookup[0].reason, MatchReason::Default);
+        assert!(
+            negotiate_languages(&["ja"], &available, None, NegotiationStrategy::Filtering)
+                .is_empty()
+        );
+    }
+}
@@ -0,0 +1,828 @@
+//! Locale-aware number formatting.
+//!
+//! Numbers are formatted with the CLDR decimal and grouping symbols, percent patterns,
+//! default digit systems and short compact patterns of the language. Common languages are
+//! built in and matched by their full tag first (e.g. `de-CH`), then by their primary
+//! language subtag; unknown languages use the CLDR root symbols.
+//!
+//! In translation strings, numbers are formatted with `{{name, number}}`,
+//! `{{name, integer}}`, `{{name, percent}}` and `{{name, compact}}` placeholders, or with
+//! `{name, number}` and its `integer`, `percent` and `compact` styles in ICU messages.
+use crate::locale::find_locale;
+use std::fmt;
+use std::str::FromStr;
+
+/// How a number is formatted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NumberStyle {
+    /// Grouped, with up to three fraction digits: `1,234.568`.
+    #[default]
+    Decimal,
+    /// Grouped and rounded to an integer: `1,235`.
+    Integer,
+    /// Multiplied by 100 and rounded to an integer: `12%`.
+    Percent,
+    /// Abbreviated with the short compact patterns of the language: `1.2K`.
+    Compact,
+}
+
+impl NumberStyle {
+    /// The keyword used for the style in placeholders.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            NumberStyle::Decimal => "number",
+            NumberStyle::Integer => "integer",
+            NumberStyle::Percent => "percent",
+            NumberStyle::Compact => "compact",
+        }
+    }
+
+    /// Parse a placeholder keyword, accepting the ICU skeletons `::percent` and
+    /// `::compact-short` as well.
+    pub fn from_keyword(keyword: &str) -> Option<Self> {
+        match keyword.trim().trim_start_matches("::") {
+            "number" | "decimal" => Some(NumberStyle::Decimal),
+            "integer" => Some(NumberStyle::Integer),
+            "percent" => Some(NumberStyle::Percent),
+            "compact" | "compact-short" => Some(NumberStyle::Compact),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for NumberStyle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for NumberStyle {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_keyword(s).ok_or(())
+    }
+}
+
+/// The number symbols and patterns of a language.
+struct Symbols {
+    decimal: &'static str,
+    group: &'static str,
+    minus: &'static str,
+    /// Text before and after a percentage.
+    percent: (&'static str, &'static str),
+    /// The zero of the default digit system; the other digits follow it.
+    zero: char,
+    /// Integers with fewer digits than `3 + min_grouping - 1` are not grouped.
+    min_grouping: usize,
+    /// Group by two above the thousands (lakh and crore).
+    indian: bool,
+    /// Short compact suffixes by power of ten, in ascending order.
+    compact: &'static [(i32, &'static str)],
+}
+
+const NBSP: &str = "\u{a0}";
+
+const ROOT: Symbols = Symbols {
+    decimal: ".",
+    group: ",",
+    minus: "-",
+    percent: ("", "%"),
+    zero: '0',
+    min_grouping: 1,
+    indian: false,
+    compact: &[(3, "K"), (6, "M"), (9, "G"), (12, "T")],
+};
+
+const EN: Symbols = Symbols {
+    compact: &[(3, "K"), (6, "M"), (9, "B"), (12, "T")],
+    ..ROOT
+};
+
+const DE: Symbols = Symbols {
+    decimal: ",",
+    group: ".",
+    percent: ("", "\u{a0}%"),
+    compact: &[(6, "\u{a0}Mio."), (9, "\u{a0}Mrd."), (12, "\u{a0}Bio.")],
+    ..ROOT
+};
+
+const ES: Symbols = Symbols {
+    decimal: ",",
+    group: ".",
+    percent: ("", "\u{a0}%"),
+    min_grouping: 2,
+    compact: &[
+        (3, "\u{a0}mil"),
+        (6, "\u{a0}M"),
+        (9, "\u{a0}mil\u{a0}M"),
+        (12, "\u{a0}B"),
+    ],
+    ..ROOT
+};
+
+const FR: Symbols = Symbols {
+    decimal: ",",
+    group: "\u{202f}",
+    percent: ("", "\u{202f}%"),
+    compact: &[
+        (3, "\u{a0}k"),
+        (6, "\u{a0}M"),
+        (9, "\u{a0}Md"),
+        (12, "\u{a0}Bn"),
+    ],
+    ..ROOT
+};
+
+const PT: Symbols = Symbols {
+    decimal: ",",
+    group: ".",
+    compact: &[
+        (3, "\u{a0}mil"),
+        (6, "\u{a0}mi"),
+        (9, "\u{a0}bi"),
+        (12, "\u{a0}tri"),
+    ],
+    ..ROOT
+};
+
+const ZH: Symbols = Symbols {
+    compact: &[(4, "万"), (8, "亿"), (12, "万亿")],
+    ..ROOT
+};
+
+const HI: Symbols = Symbols {
+    indian: true,
+    compact: &[
+        (3, "\u{a0}हज़ार"),
+        (5, "\u{a0}लाख"),
+        (7, "\u{a0}क॰"),
+        (9, "\u{a0}अ॰"),
+        (11, "\u{a0}ख॰"),
+        (13, "\u{a0}नील"),
+    ],
+    ..ROOT
+};
+
+const AR: Symbols = Symbols {
+    decimal: "٫",
+    group: "٬",
+    minus: "\u{61c}-",
+    percent: ("", "\u{66a}\u{61c}"),
+    zero: '٠',
+    compact: &[
+        (3, "\u{a0}ألف"),
+        (6, "\u{a0}مليون"),
+        (9, "\u{a0}مليار"),
+        (12, "\u{a0}ترليون"),
+    ],
+    ..ROOT
+};
+
+/// Built-in languages, by lowercase tag.
+const LOCALES: &[(&str, Symbols)] = &[
+    ("ar", AR),
+    (
+        "ar-dz",
+        Symbols {
+            decimal: ",",
+            group: ".",
+            minus: "\u{200e}-",
+            percent: ("", "\u{200e}%\u{200e}"),
+            zero: '0',
+            ..AR
+        },
+    ),
+    (
+        "ar-ma",
+        Symbols {
+            decimal: ",",
+            group: ".",
+            minus: "\u{200e}-",
+            percent: ("", "\u{200e}%\u{200e}"),
+            zero: '0',
+            ..AR
+        },
+    ),
+    (
+        "ar-tn",
+        Symbols {
+            decimal: ",",
+            group: ".",
+            minus: "\u{200e}-",
+            percent: ("", "\u{200e}%\u{200e}"),
+            zero: '0',
+            ..AR
+        },
+    ),
+    (
+        "bn",
+        Symbols {
+            zero: '০',
+            compact: &[],
+            ..HI
+        },
+    ),
+    (
+        "cs",
+        Symbols {
+            decimal: ",",
+            group: NBSP,
+            percent: ("", "\u{a0}%"),
+            compact: &[
+                (3, "\u{a0}tis."),
+                (6, "\u{a0}mil."),
+                (9, "\u{a0}mld."),
+                (12, "\u{a0}bil."),
+            ],
+            ..ROOT
+        },
+    ),
+    (
+        "da",
+        Symbols {
+            decimal: ",",
+            group: ".",
+            percent: ("", "\u{a0}%"),
+            compact: &[
+                (3, "\u{a0}t"),
+                (6, "\u{a0}mio."),
+                (9, "\u{a0}mia."),
+                (12, "\u{a0}bio."),
+            ],
+            ..ROOT
+        },
+    ),
+    ("de", DE),
+    ("de-at", Symbols { group: NBSP, ..DE }),
+    (
+        "de-ch",
+        Symbols {
+            decimal: ".",
+            group: "’",
+            percent: ("", "%"),
+            ..DE
+        },
+    ),
+    (
+        "el",
+        Symbols {
+            decimal: ",",
+            group: ".",
+            compact: &[
+                (3, "\u{a0}χιλ."),
+                (6, "\u{a0}εκ."),
+                (9, "\u{a0}δισ."),
+                (12, "\u{a0}τρισ."),
+            ],
+            ..ROOT
+        },
+    ),
+    ("en", EN),
+    ("en-in", Symbols { indian: true, ..EN }),
+    ("es", ES),
+    (
+        "es-419",
+        Symbols {
+            decimal: ".",
+            group: ",",
+            min_grouping: 1,
+            ..ES
+        },
+    ),
+    (
+        "es-mx",
+        Symbols {
+            decimal: ".",
+            group: ",",
+            min_grouping: 1,
+            ..ES
+        },
+    ),
+    (
+        "fa",
+        Symbols {
+            decimal: "٫",
+            group: "٬",
+            minus: "\u{200e}\u{2212}",
+            percent: ("", "\u{66a}"),
+            zero: '۰',
+            compact: &[
+                (3, "\u{a0}هزار"),
+                (6, "\u{a0}میلیون"),
+                (9, "\u{a0}میلیارد"),
+                (12, "\u{a0}تریلیون"),
+            ],
+            ..ROOT
+        },
+    ),
+    (
+        "fi",
+        Symbols {
+            decimal: ",",
+            group: NBSP,
+            minus: "\u{2212}",
+            percent: ("", "\u{a0}%"),
+            compact: &[
+                (3, "\u{a0}t."),
+                (6, "\u{a0}milj."),
+                (9, "\u{a0}mrd."),
+                (12, "\u{a0}bilj."),
+            ],
+            ..ROOT
+        },
+    ),
+    ("fr", FR),
+    (
+        "fr-ca",
+        Symbols {
+            group: NBSP,
+            percent: ("", "\u{a0}%"),
+            compact: &[
+                (3, "\u{a0}k"),
+                (6, "\u{a0}M"),
+                (9, "\u{a0}G"),
+                (12, "\u{a0}T"),
+            ],
+            ..FR
+        },
+    ),
+    (
+        "fr-ch",
+        Symbols {
+            percent: ("", "%"),
+            ..FR
+        },
+    ),
+    (
+        "he",
+        Symbols {
+            minus: "\u{200e}-",
+            ..EN
+        },
+    ),
+    ("hi", HI),
+    (
+        "hu",
+        Symbols {
+            decimal: ",",
+            group: NBSP,
+            compact: &[
+                (3, "\u{a0}E"),
+                (6, "\u{a0}M"),
+                (9, "\u{a0}Mrd"),
+                (12, "\u{a0}B"),
+            ],
+            ..ROOT
+        },
+    ),
+    (
+        "id",
+        Symbols {
+            decimal: ",",
+            group: ".",
+            compact: &[
+                (3, "\u{a0}rb"),
+                (6, "\u{a0}jt"),
+                (9, "\u{a0}M"),
+                (12, "\u{a0}T"),
+            ],
+            ..ROOT
+        },
+    ),
+    (
+        "it",
+        Symbols {
+            decimal: ",",
+            group: ".",
+            compact: &[(6, "\u{a0}Mln"), (9, "\u{a0}Mrd"), (12, "\u{a0}Bln")],
+            ..ROOT
+        },
+    ),
+    (
+        "ja",
+        Symbols {
+            compact: &[(4, "万"), (8, "億"), (12, "兆")],
+            ..ROOT
+        },
+    ),
+    (
+        "ko",
+        Symbols {
+            compact: &[(3, "천"), (4, "만"), (8, "억"), (12, "조")],
+            ..ROOT
+        },
+    ),
+    (
+        "mr",
+        Symbols {
+            zero: '०',
+            compact: &[],
+            ..HI
+        },
+    ),
+    (
+        "my",
+        Symbols {
+            zero: '၀',
+            compact: &[],
+            ..ROOT
+        },
+    ),
+    (
+        "nb",
+        Symbols {
+            decimal: ",",
+            group: NBSP,
+            minus: "\u{2212}",
+            percent: ("", "\u{a0}%"),
+            compact: &[
+                (3, "k"),
+                (6, "\u{a0}mill."),
+                (9, "\u{a0}mrd."),
+                (12, "\u{a0}bill."),
+            ],
+            ..ROOT
+        },
+    ),
+    (
+        "ne",
+        Symbols {
+            zero: '०',
+            compact: &[],
+            ..HI
+        },
+    ),
+    (
+        "nl",
+        Symbols {
+            decimal: ",",
+            group: ".",
+            compact: &[
+                (3, "K"),
+                (6, "\u{a0}mln."),
+                (9, "\u{a0}mld."),
+                (12, "\u{a0}bln."),
+            ],
+            ..ROOT
+        },
+    ),
+    (
+        "no",
+        Symbols {
+            decimal: ",",
+            group: NBSP,
+            minus: "\u{2212}",
+            percent: ("", "\u{a0}%"),
+            compact: &[
+                (3, "k"),
+                (6, "\u{a0}mill."),
+                (9, "\u{a0}mrd."),
+                (12, "\u{a0}bill."),
+            ],
+            ..ROOT
+        },
+    ),
+    (
+        "pl",
+        Symbols {
+            decimal: ",",
+            group: NBSP,
+            min_grouping: 2,
+            compact: &[
+                (3, "\u{a0}tys."),
+                (6, "\u{a0}mln"),
+                (9, "\u{a0}mld"),
+                (12, "\u{a0}bln"),
+            ],
+            ..ROOT
+        },
+    ),
+    ("pt", PT),
+    (
+        "pt-pt",
+        Symbols {
+            group: NBSP,
+            min_grouping: 2,
+            compact: &[
+                (3, "\u{a0}mil"),
+                (6, "\u{a0}M"),
+                (9, "\u{a0}mM"),
+                (12, "\u{a0}Bi"),
+            ],
+            ..PT
+        },
+    ),
+    (
+        "ro",
+        Symbols {
+            decimal: ",",
+            group: ".",
+            percent: ("", "\u{a0}%"),
+            compact: &[
+                (3, "\u{a0}K"),
+                (6, "\u{a0}mil."),
+                (9, "\u{a0}mld."),
+                (12, "\u{a0}tril."),
+            ],
+            ..ROOT
+        },
+    ),
+    (
+        "ru",
+        Symbols {
+            decimal: ",",
+            group: NBSP,
+            percent: ("", "\u{a0}%"),
+            compact: &[
+                (3, "\u{a0}тыс."),
+                (6, "\u{a0}млн"),
+                (9, "\u{a0}млрд"),
+                (12, "\u{a0}трлн"),
+            ],
+            ..ROOT
+        },
+    ),
+    (
+        "sv",
+        Symbols {
+            decimal: ",",
+            group: NBSP,
+            minus: "\u{2212}",
+            percent: ("", "\u{a0}%"),
+            compact: &[
+                (3, "\u{a0}tn"),
+                (6, "\u{a0}mn"),
+                (9, "\u{a0}md"),
+                (12, "\u{a0}bn"),
+            ],
+            ..ROOT
+        },
+    ),
+    ("th", EN),
+    (
+        "tr",
+        Symbols {
+            decimal: ",",
+            group: ".",
+            percent: ("%", ""),
+            compact: &[
+                (3, "\u{a0}B"),
+                (6, "\u{a0}Mn"),
+                (9, "\u{a0}Mr"),
+                (12, "\u{a0}Tn"),
+            ],
+            ..ROOT
+        },
+    ),
+    (
+        "uk",
+        Symbols {
+            decimal: ",",
+            group: NBSP,
+            compact: &[
+                (3, "\u{a0}тис."),
+                (6, "\u{a0}млн"),
+                (9, "\u{a0}млрд"),
+                (12, "\u{a0}трлн"),
+            ],
+            ..ROOT
+        },
+    ),
+    (
+        "vi",
+        Symbols {
+            decimal: ",",
+            group: ".",
+            compact: &[
+                (3, "\u{a0}N"),
+                (6, "\u{a0}Tr"),
+                (9, "\u{a0}T"),
+                (12, "\u{a0}NT"),
+            ],
+            ..ROOT
+        },
+    ),
+    ("zh", ZH),
+    (
+        "zh-hant",
+        Symbols {
+            compact: &[(4, "萬"), (8, "億"), (12, "兆")],
+            ..ZH
+        },
+    ),
+];
+
+/// Find the symbols of a language; Taiwan, Hong Kong and Macau use Traditional Chinese.
+fn symbols(lang: &str) -> &'static Symbols {
+    let lang = lang.replace('_', "-").to_ascii_lowercase();
+    let lang = match lang.as_str() {
+        "zh-tw" | "zh-hk" | "zh-mo" => "zh-hant",
+        lang => lang,
+    };
+    find_locale(lang, LOCALES, &ROOT)
+}
+
+/// Format a number for a language.
+///
+/// # Arguments
+///
+/// * `lang` - A language code, e.g. `"de"` or `"de-CH"`.
+/// * `value` - The number to format; infinite and NaN values are written as they are.
+/// * `style` - How to format the number.
+///
+/// # Example
+///
+/// ```rust
+/// use lingua_i18n_rs::number::{format, NumberStyle};
+///
+/// assert_eq!(format("en", 1234.5, NumberStyle::Decimal), "1,234.5");
+/// assert_eq!(format("de", 1234.5, NumberStyle::Decimal), "1.234,5");
+/// assert_eq!(format("en", 0.256, NumberStyle::Percent), "26%");
+/// assert_eq!(format("en", 1_260_000.0, NumberStyle::Compact), "1.3M");
+/// ```
+pub fn format(lang: &str, value: f64, style: NumberStyle) -> String {
+    if !value.is_finite() {
+        return value.to_string();
+    }
+    let symbols = symbols(lang);
+
+    let (digits, prefix, suffix, min_grouping) = match style {
+        NumberStyle::Decimal => (fixed(value.abs(), 3), "", "", symbols.min_grouping),
+        NumberStyle::Integer => (fixed(value.abs(), 0), "", "", symbols.min_grouping),
+        NumberStyle::Percent => {
+            let (prefix, suffix) = symbols.percent;
+            (
+                fixed(value.abs() * 100.0, 0),
+                prefix,
+                suffix,
+                symbols.min_grouping,
+            )
+        }
+        NumberStyle::Compact => {
+            let (digits, suffix) = compact(value.abs(), symbols.compact);
+            (digits, "", suffix, symbols.min_grouping.max(2))
+        }
+    };
+
+    let mut out = String::new();
+    if value < 0.0 && is_nonzero(&digits) {
+        out.push_str(symbols.minus);
+    }
+    out.push_str(prefix);
+    write_digits(&digits, symbols, min_grouping, &mut out);
+    out.push_str(suffix);
+    localize(out, symbols)
+}
+
+/// Format the absolute value of a number with exactly `fraction` fraction digits, for
+/// patterns that place the sign themselves. Also returns whether the number is negative
+/// once rounded.
+pub(crate) fn format_unsigned(lang: &str, value: f64, fraction: usize) -> (String, bool) {
+    let symbols = symbols(lang);
+    let digits = format!("{:.*}", fraction, value.abs());
+    let negative = value < 0.0 && is_nonzero(&digits);
+    let mut out = String::new();
+    write_digits(&digits, symbols, symbols.min_grouping, &mut out);
+    (localize(out, symbols), negative)
+}
+
+/// The minus sign of a language.
+pub(crate) fn minus_sign(lang: &str) -> &'static str {
+    symbols(lang).minus
+}
+
+fn is_nonzero(digits: &str) -> bool {
+    digits.bytes().any(|b| b.is_ascii_digit() && b != b'0')
+}
+
+/// Write ASCII digits with the decimal and group separators of the language.
+fn write_digits(digits: &str, symbols: &Symbols, min_grouping: usize, out: &mut String) {
+    let (int_part, frac_part) = digits.split_once('.').unwrap_or((digits, ""));
+    group(int_part, symbols, min_grouping, out);
+    if !frac_part.is_empty() {
+        out.push_str(symbols.decimal);
+        out.push_str(frac_part);
+    }
+}
+
+/// Replace ASCII digits with the digits of the default digit system of the language.
+fn localize(text: String, symbols: &Symbols) -> String {
+    if symbols.zero == '0' {
+        return text;
+    }
+    text.chars()
+        .map(|c| match c {
+            '0'..='9' => char::from_u32(symbols.zero as u32 + (c as u32 - '0' as u32)).unwrap_or(c),
+            _ => c,
+        })
+        .collect()
+}
+
+/// Write a non-negative number with at most `fraction` fraction digits, without trailing zeros.
+pub(crate) fn fixed(value: f64, fraction: usize) -> String {
+    let text = format!("{:.*}", fraction, value);
+    if text.contains('.') {
+        text.trim_end_matches('0').trim_end_matches('.').to_string()
+    } else {
+        text
+    }
+}
+
+/// Scale a non-negative number by the largest compact pattern not exceeding it and round
+/// it to two significant digits, or to an integer once it has two or more.
+fn compact(value: f64, patterns: &'static [(i32, &'static str)]) -> (String, &'static str) {
+    let candidates: Vec<(i32, &str)> = std::iter::once((0, ""))
+        .chain(patterns.iter().copied())
+        .collect();
+    let mut index = candidates
+        .iter()
+        .rposition(|(power, _)| value >= 10f64.powi(*power))
+        .unwrap_or(0);
+
+    loop {
+        let (power, suffix) = candidates[index];
+        let scaled = value / 10f64.powi(power);
+        let digits = fixed(scaled, if scaled < 10.0 { 1 } else { 0 });
+        // Rounding can reach the next pattern, e.g. 999,999 to 1000K.
+        let rounded = digits.parse::<f64>().unwrap_or(scaled) * 10f64.powi(power);
+        match candidates.get(index + 1) {
+            Some((next, _)) if rounded >= 10f64.powi(*next) => index += 1,
+            _ => return (digits, suffix),
+        }
+    }
+}
+
+/// Write the integer digits with the group separators of the language.
+fn group(digits: &str, symbols: &Symbols, min_grouping: usize, out: &mut String) {
+    if digits.len() < 3 + min_grouping {
+        out.push_str(digits);
+        return;
+    }
+
+    let (head, last) = digits.split_at(digits.len() - 3);
+    let size = if symbols.indian { 2 } else { 3 };
+    let first = head.len() % size;
+    let mut groups = Vec::new();
+    if first > 0 {
+        groups.push(&head[..first]);
+    }
+    groups.extend(
+        head.as_bytes()[first..]
+            .chunks(size)
+            .filter_map(|chunk| std::str::from_utf8(chunk).ok()),
+    );
+    groups.push(last);
+    out.push_str(&groups.join(symbols.group));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decimal_and_grouping() {
+        assert_eq!(
+            format("en", 1234567.891, NumberStyle::Decimal),
+            "1,234,567.891"
+        );
+        assert_eq!(format("de", -1234.5, NumberStyle::Decimal), "-1.234,5");
+        assert_eq!(format("de-CH", 1234.5, NumberStyle::Decimal), "1’234.5");
+        assert_eq!(
+            format("fr_FR", 1234.5, NumberStyle::Decimal),
+            "1\u{202f}234,5"
+        );
+        assert_eq!(format("es", 1234.0, NumberStyle::Decimal), "1234");
+        assert_eq!(format("es", 12345.0, NumberStyle::Decimal), "12.345");
+        assert_eq!(
+            format("hi", 12345678.0, NumberStyle::Decimal),
+            "1,23,45,678"
+        );
+        assert_eq!(format("ar", 1234.5, NumberStyle::Decimal), "١٬٢٣٤٫٥");
+        assert_eq!(format("fa", 12.0, NumberStyle::Integer), "۱۲");
+        assert_eq!(format("xx", 1234.5, NumberStyle::Integer), "1,234");
+        assert_eq!(format("en", -0.0001, NumberStyle::Decimal), "0");
+    }
+
+    #[test]
+    fn test_percent() {
+        assert_eq!(format("en", 0.256, NumberStyle::Percent), "26%");
+        assert_eq!(format("de", 0.5, NumberStyle::Percent), "50\u{a0}%");
+        assert_eq!(format("tr", -0.5, NumberStyle::Percent), "-%50");
+    }
+
+    #[test]
+    fn test_compact() {
+        assert_eq!(format("en", 999.0, NumberStyle::Compact), "999");
+        assert_eq!(format("en", 1234.0, NumberStyle::Compact), "1.2K");
+        assert_eq!(format("en", 12345.0, NumberStyle::Compact), "12K");
+        assert_eq!(format("en", 999_999.0, NumberStyle::Compact), "1M");
+        assert_eq!(format("de", 12345.0, NumberStyle::Compact), "12.345");
+        assert_eq!(
+            format("de", 2_500_000.0, NumberStyle::Compact),
+            "2,5\u{a0}Mio."
+        );
+        assert_eq!(format("ja", 123_456_789.0, NumberStyle::Compact), "1.2億");
+        assert_eq!(format("ja", 12_345.0, NumberStyle::Compact), "1.2万");
+    }
+}
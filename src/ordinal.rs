@@ -0,0 +1,113 @@
+//! Ordinal number formatting.
+//!
+//! Ordinals are written with the suffix the CLDR ordinal plural category of the number
+//! calls for in the language: "1st", "2nd", "3rd" and "4th" in English, "1." in German,
+//! "1er" and "2e" in French. Common languages are built in and matched by their full tag
+//! first, then by their primary language subtag; unknown languages get the bare number.
+//!
+//! In translation strings, `{{name, ordinal}}` uses the built-in suffixes. Translators can
+//! supply their own per category as style, with `#` standing for the number:
+//! `{{place, ordinal:one:#st|two:#nd|few:#rd|other:#th}}`.
+use crate::locale::find_locale;
+use crate::number::{self, NumberStyle};
+use crate::plural::{self, PluralOperands};
+use crate::relative;
+
+/// Ordinal patterns by lowercase tag.
+///
+/// Patterns hold `#` for the number and are either a single pattern for all ordinal
+/// categories or `category:pattern` alternatives separated by `|`.
+const LOCALES: &[(&str, &str)] = &[
+    ("ca", "one:#r|two:#n|few:#t|other:#è"),
+    ("cs", "#."),
+    ("da", "#."),
+    ("de", "#."),
+    ("en", "one:#st|two:#nd|few:#rd|other:#th"),
+    ("es", "#.º"),
+    ("fi", "#."),
+    ("fr", "one:#er|other:#e"),
+    ("hu", "#."),
+    ("it", "#º"),
+    ("ja", "第#"),
+    ("ko", "#번째"),
+    ("nb", "#."),
+    ("nl", "#e"),
+    ("no", "#."),
+    ("pl", "#."),
+    ("pt", "#º"),
+    ("ru", "#-й"),
+    ("sk", "#."),
+    ("sv", "one:#:a|other:#:e"),
+    ("tr", "#."),
+    ("uk", "#-й"),
+    ("zh", "第#"),
+];
+
+/// Find the ordinal patterns of a language.
+fn patterns(lang: &str) -> &'static str {
+    find_locale::<&str>(lang, LOCALES, &"#")
+}
+
+/// Format an ordinal number for a language.
+///
+/// # Arguments
+///
+/// * `lang` - A language code, e.g. `"de"`.
+/// * `value` - The number.
+///
+/// # Example
+///
+/// ```rust
+/// use lingua_i18n_rs::ordinal::format_ordinal;
+///
+/// assert_eq!(format_ordinal("en", 1), "1st");
+/// assert_eq!(format_ordinal("en", 22), "22nd");
+/// assert_eq!(format_ordinal("en", 113), "113th");
+/// assert_eq!(format_ordinal("de", 3), "3.");
+/// assert_eq!(format_ordinal("fr", 1), "1er");
+/// ```
+pub fn format_ordinal(lang: &str, value: i64) -> String {
+    format_with(lang, value, patterns(lang))
+}
+
+/// Format an ordinal number with `category:pattern` alternatives supplied by a translator.
+pub(crate) fn format_with(lang: &str, value: i64, patterns: &str) -> String {
+    let category = plural::ordinal(lang, &PluralOperands::from(value));
+    relative::plural_form(patterns, category).replace(
+        '#',
+        &number::format(lang, value as f64, NumberStyle::Integer),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_ordinal() {
+        let en: Vec<String> = [1, 2, 3, 4, 11, 12, 13, 21, 101, 1002]
+            .iter()
+            .map(|n| format_ordinal("en_US", *n))
+            .collect();
+        assert_eq!(
+            en,
+            [
+                "1st", "2nd", "3rd", "4th", "11th", "12th", "13th", "21st", "101st", "1,002nd"
+            ]
+        );
+        assert_eq!(format_ordinal("fr", 2), "2e");
+        assert_eq!(format_ordinal("sv", 2), "2:a");
+        assert_eq!(format_ordinal("sv", 12), "12:e");
+        assert_eq!(format_ordinal("ca", 4), "4t");
+        assert_eq!(format_ordinal("ja", 3), "第3");
+        assert_eq!(format_ordinal("xx", 3), "3");
+    }
+
+    #[test]
+    fn test_translator_patterns() {
+        let patterns = "one:#st|other:#th";
+        assert_eq!(format_with("en", 1, patterns), "1st");
+        assert_eq!(format_with("en", 2, patterns), "2th");
+        assert_eq!(format_with("de", 1000, "# Mal"), "1.000 Mal");
+    }
+}
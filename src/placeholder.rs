@@ -0,0 +1,377 @@
+//! Rendering of `{{name}}` and formatted `{{name, format}}` placeholders.
+use crate::currency::{self, CurrencyDisplay, CurrencySign};
+use crate::datetime::{self, DateLength, DateTime};
+use crate::error::LinguaError;
+use crate::list::{self, ListType};
+use crate::number::{self, NumberStyle};
+use crate::ordinal;
+use crate::relative::{self, Width};
+use crate::unit::{self, MeasureUnit};
+use std::time::Duration;
+
+/// Replace the placeholders of a message with their parameters.
+///
+/// A placeholder is `{{name}}` or `{{name, format}}`, optionally with a style after a colon
+/// (`{{name, format:style}}`). Placeholders without a matching parameter are left as they
+/// are, and parameters with an unknown format are inserted unchanged.
+pub(crate) fn substitute(
+    lang: &str,
+    key: &str,
+    message: &str,
+    params: &[(&str, &str)],
+) -> Result<String, LinguaError> {
+    let mut out = String::with_capacity(message.len());
+    let mut rest = message;
+    while let Some(start) = rest.find("{{") {
+        let Some(end) = rest[start + 2..].find("}}") else {
+            break;
+        };
+        out.push_str(&rest[..start]);
+        let placeholder = &rest[start..start + 2 + end + 2];
+        rest = &rest[start + 2 + end + 2..];
+
+        let inner = &placeholder[2..placeholder.len() - 2];
+        let (name, spec) = match inner.split_once(',') {
+            Some((name, spec)) => (name.trim(), Some(spec.trim())),
+            None => (inner.trim(), None),
+        };
+        let Some(value) = params
+            .iter()
+            .find(|(param, _)| *param == name)
+            .map(|(_, value)| *value)
+        else {
+            out.push_str(placeholder);
+            continue;
+        };
+
+        let formatted = match spec {
+            Some(spec) => {
+                let (format, style) = match spec.split_once(':') {
+                    Some((format, style)) => (format.trim(), Some(style.trim())),
+                    None => (spec, None),
+                };
+                format_argument(lang, key, name, format, style, value)?
+            }
+            None => None,
+        };
+        out.push_str(formatted.as_deref().unwrap_or(value));
+    }
+    out.push_str(rest);
+    Ok(out)
+}
+
+/// Format a parameter value with a named format and optional style.
+///
+/// Supported are `number` (with the styles `integer`, `percent` and `compact`), `integer`,
+/// `percent` and `compact`, as well as `date`, `time` and `datetime` with a length (`full`,
+/// `long`, `medium` or `short`) or a skeleton (`yMMMd`, each field once) as style, and
+/// `relative` (signed seconds from now) and `duration` (seconds) with a width (`long`,
+/// `short` or `narrow`) as style. `list` joins a list-valued parameter (see
+/// [`list::to_param`]) and takes a type (`and`, `or` or `unit`), a width, or both joined
+/// with `-` as style (`list:or-short`). `currency` requires an ISO 4217 code and optionally
+/// takes a display and sign joined with `-` as style (`currency:EUR-code-accounting`), and
+/// is also available as the ICU skeleton `number, ::currency/EUR`. `unit` takes a unit
+/// identifier, optionally followed by `-` and a width (`unit:kilometer-per-hour-long`), and
+/// is also available as the ICU skeleton `number, ::unit/kilometer`. `ordinal` writes an
+/// integer with the built-in ordinal suffixes of the language, or with `category:pattern`
+/// alternatives given as style (`ordinal:one:#st|other:#th`). Returns `None` for unknown
+/// formats.
+pub(crate) fn format_argument(
+    lang: &str,
+    key: &str,
+    name: &str,
+    format: &str,
+    style: Option<&str>,
+    value: &str,
+) -> Result<Option<String>, LinguaError> {
+    let invalid = || LinguaError::InvalidArgument {
+        key: key.to_string(),
+        name: name.to_string(),
+        value: value.to_string(),
+    };
+
+    let number_style = match (format, style) {
+        ("number", Some(style)) => NumberStyle::from_keyword(style),
+        (format, _) => NumberStyle::from_keyword(format),
+    };
+    if let Some(number_style) = number_style {
+        let number = value.trim().parse::<f64>().map_err(|_| invalid())?;
+        return Ok(Some(number::format(lang, number, number_style)));
+    }
+
+    let currency_style = match (format, style) {
+        ("currency", style) => Some(style.unwrap_or_default()),
+        ("number", Some(style)) if style.trim_start_matches("::").starts_with("currency/") => {
+            Some(style.trim_start_matches("::"))
+        }
+        _ => None,
+    };
+    if let Some(style) = currency_style {
+        let amount = value.trim().parse::<f64>().map_err(|_| invalid())?;
+        let mut code = None;
+        let mut display = CurrencyDisplay::default();
+        let mut sign = CurrencySign::default();
+        for token in style.split([' ', '/']) {
+            match token {
+                "unit-width-narrow" => display = CurrencyDisplay::Narrow,
+                "unit-width-iso-code" => display = CurrencyDisplay::Code,
+                "sign-accounting" => sign = CurrencySign::Accounting,
+                token => {
+                    for token in token.split('-') {
+                        if let Some(parsed) = CurrencyDisplay::from_keyword(token) {
+                            display = parsed;
+                        } else if let Some(parsed) = CurrencySign::from_keyword(token) {
+                            sign = parsed;
+                        } else if token.len() == 3 && token.chars().all(|c| c.is_ascii_alphabetic())
+                        {
+                            code = Some(token);
+                        }
+                    }
+                }
+            }
+        }
+        // Without a currency the amount would be shown unformatted, e.g. at a checkout.
+        let code = code.ok_or_else(invalid)?;
+        return Ok(Some(currency::format_currency(
+            lang, amount, code, display, sign,
+        )));
+    }
+
+    let unit_style = match (format, style) {
+        ("unit", style) => Some(style.unwrap_or_default()),
+        ("number", Some(style)) if style.trim_start_matches("::").starts_with("unit/") => {
+            Some(style.trim_start_matches("::"))
+        }
+        _ => None,
+    };
+    if let Some(style) = unit_style {
+        let number = value.trim().parse::<f64>().map_err(|_| invalid())?;
+        let mut measure_unit = None;
+        let mut width = Width::Short;
+        for token in style.split([' ', '/']) {
+            match token {
+                "unit-width-full-name" => width = Width::Long,
+                "unit-width-short" => width = Width::Short,
+                "unit-width-narrow" => width = Width::Narrow,
+                token => {
+                    let token = match token.rsplit_once('-') {
+                        Some((rest, suffix)) if Width::from_keyword(suffix).is_some() => {
+                            width = Width::from_keyword(suffix).unwrap_or_default();
+                            rest
+                        }
+                        _ => token,
+                    };
+                    measure_unit = MeasureUnit::from_keyword(token).or(measure_unit);
+                }
+            }
+        }
+        return Ok(
+            measure_unit.map(|measure_unit| unit::format_unit(lang, number, measure_unit, width))
+        );
+    }
+
+    if format == "ordinal" {
+        let number = value
+            .trim()
+            .parse::<f64>()
+            .ok()
+            .filter(|number| number.fract() == 0.0 && number.abs() < i64::MAX as f64)
+            .ok_or_else(invalid)?;
+        return Ok(Some(match style.filter(|style| !style.is_empty()) {
+            Some(patterns) => ordinal::format_with(lang, number as i64, patterns),
+            None => ordinal::format_ordinal(lang, number as i64),
+        }));
+    }
+
+    if matches!(format, "relative" | "duration") {
+        let seconds = value
+            .trim()
+            .parse::<f64>()
+            .ok()
+            .filter(|seconds| seconds.is_finite())
+            .ok_or_else(invalid)?;
+        let width = style.and_then(Width::from_keyword).unwrap_or_default();
+        return Ok(Some(if format == "relative" {
+            relative::format_relative_seconds(lang, seconds.round() as i64, width)
+        } else {
+            let duration = Duration::try_from_secs_f64(seconds).map_err(|_| invalid())?;
+            relative::format_duration(lang, duration, width)
+        }));
+    }
+
+    if format == "list" {
+        let mut list_type = ListType::default();
+        let mut width = Width::default();
+        for token in style.into_iter().flat_map(|style| style.split('-')) {
+            if let Some(parsed) = ListType::from_keyword(token) {
+                list_type = parsed;
+            } else if let Some(parsed) = Width::from_keyword(token) {
+                width = parsed;
+            }
+        }
+        let items = list::from_param(value);
+        return Ok(Some(list::format_list(lang, &items, list_type, width)));
+    }
+
+    if matches!(format, "date" | "time" | "datetime") {
+        let when = value.parse::<DateTime>().map_err(|_| invalid())?;
+        let style = style.map(|style| style.trim_start_matches("::"));
+        let length = match style {
+            Some(style) => match DateLength::from_keyword(style) {
+                Some(length) => length,
+                None if datetime::is_skeleton(style) => {
+                    return Ok(Some(datetime::format_skeleton(lang, &when, style)));
+                }
+                None => return Err(invalid()),
+            },
+            None => DateLength::Medium,
+        };
+        return Ok(Some(match format {
+            "date" => datetime::format_date(lang, &when, length),
+            "time" => datetime::format_time(lang, &when, length),
+            _ => datetime::format_datetime(lang, &when, length),
+        }));
+    }
+
+    Ok(None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_substitute() {
+        let params = [("name", "Ada"), ("amount", "1234.5"), ("ratio", "0.25")];
+        assert_eq!(
+            substitute(
+                "de",
+                "k",
+                "{{name}}: {{ amount, number }} ({{ratio, percent}}) {{missing}}",
+                &params
+            )
+            .unwrap(),
+            "Ada: 1.234,5 (25\u{a0}%) {{missing}}"
+        );
+        assert_eq!(
+            substitute(
+                "en",
+                "k",
+                "{{amount, number:integer}} {{name, unknown}}",
+                &params
+            )
+            .unwrap(),
+            "1,234 Ada"
+        );
+        assert_eq!(
+            substitute(
+                "de",
+                "k",
+                "{{when, date}}, {{when, time:short}}, {{when, date:yMMMMd}}",
+                &[("when", "2024-03-05T14:30:00Z")]
+            )
+            .unwrap(),
+            "05.03.2024, 14:30, 5. März 2024"
+        );
+        assert_eq!(
+            substitute(
+                "en",
+                "k",
+                "Edited {{delta, relative}}, took {{took, duration:narrow}}",
+                &[("delta", "-180"), ("took", "4800")]
+            )
+            .unwrap(),
+            "Edited 3 minutes ago, took 1h 20m"
+        );
+        assert_eq!(
+            substitute(
+                "en",
+                "k",
+                "Shared with {{names, list}}; ask {{admins, list:or-short}}",
+                &[
+                    ("names", &list::to_param(&["Ada", "Bob", "Cy"])),
+                    ("admins", "[\"Dee\",\"Eve\"]")
+                ]
+            )
+            .unwrap(),
+            "Shared with Ada, Bob, and Cy; ask Dee or Eve"
+        );
+        assert_eq!(
+            substitute(
+                "de",
+                "k",
+                "{{price, currency:EUR}} / {{price, currency:usd-code}} / {{refund, currency:EUR-accounting}}",
+                &[("price", "1234.5"), ("refund", "-5")]
+            )
+            .unwrap(),
+            "1.234,50\u{a0}€ / 1.234,50\u{a0}USD / -5,00\u{a0}€"
+        );
+        assert_eq!(
+            substitute(
+                "de",
+                "k",
+                "{{size, unit:megabyte}}, {{distance, unit:kilometer-long}}",
+                &[("size", "3.2"), ("distance", "5")]
+            )
+            .unwrap(),
+            "3,2 MB, 5 Kilometer"
+        );
+        assert_eq!(
+            substitute(
+                "en",
+                "k",
+                "{{n, ordinal}} / {{n, ordinal:one:#er|other:#e}}",
+                &[("n", "22")]
+            )
+            .unwrap(),
+            "22nd / 22e"
+        );
+        assert!(matches!(
+            substitute("en", "k", "{{n, ordinal}}", &[("n", "1.5")]),
+            Err(LinguaError::InvalidArgument { .. })
+        ));
+        assert!(matches!(
+            substitute("en", "k", "{{name, number}}", &params),
+            Err(LinguaError::InvalidArgument { .. })
+        ));
+    }
+
+    #[test]
+    fn test_invalid_styles() {
+        let when = [("when", "2024-03-05T14:30:00Z")];
+        for message in [
+            "{{when, date:medum}}",
+            "{{when, date:yMMMd!}}",
+            "{{when, time:::}}",
+        ] {
+            assert!(
+                matches!(
+                    substitute("en", "k", message, &when),
+                    Err(LinguaError::InvalidArgument { .. })
+                ),
+                "{}",
+                message
+            );
+        }
+        assert_eq!(
+            substitute("en", "k", "{{when, date:::yMMMd}}", &when).unwrap(),
+            "Mar 5, 2024"
+        );
+
+        let price = [("price", "1234.5")];
+        for message in [
+            "{{price, currency}}",
+            "{{price, currency:EURO}}",
+            "{{price, currency:code}}",
+        ] {
+            assert!(
+                matches!(
+                    substitute("en", "k", message, &price),
+                    Err(LinguaError::InvalidArgument { .. })
+                ),
+                "{}",
+                message
+            );
+        }
+    }
+}
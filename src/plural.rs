@@ -0,0 +1,973 @@
+//! CLDR plural rules.
+//!
+//! The cardinal and ordinal rules of all CLDR locales are built in. Languages are matched by their
+//! primary language subtag, except for regional variants that have their own rules
+//! (currently `pt-PT`). Unknown languages only use the `other` category.
+use std::fmt;
+use std::str::FromStr;
+
+/// A CLDR plural category.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PluralCategory {
+    Zero,
+    One,
+    Two,
+    Few,
+    Many,
+    Other,
+}
+
+impl PluralCategory {
+    /// All categories, in CLDR order.
+    pub const ALL: [PluralCategory; 6] = [
+        PluralCategory::Zero,
+        PluralCategory::One,
+        PluralCategory::Two,
+        PluralCategory::Few,
+        PluralCategory::Many,
+        PluralCategory::Other,
+    ];
+
+    /// The CLDR keyword of the category, e.g. `"one"`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PluralCategory::Zero => "zero",
+            PluralCategory::One => "one",
+            PluralCategory::Two => "two",
+            PluralCategory::Few => "few",
+            PluralCategory::Many => "many",
+            PluralCategory::Other => "other",
+        }
+    }
+
+    /// Parse a CLDR keyword.
+    pub fn from_keyword(keyword: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|c| c.as_str() == keyword)
+    }
+}
+
+impl fmt::Display for PluralCategory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// The operands of a number as defined by CLDR (`n`, `i`, `v`, `w`, `f`, `t`).
+///
+/// Operands are derived from the decimal representation, so `"1"` and `"1.0"` differ in
+/// their visible fraction digits and may select different categories.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PluralOperands {
+    /// Absolute value of the number.
+    pub n: f64,
+    /// Integer digits of `n`.
+    pub i: u64,
+    /// Number of visible fraction digits, with trailing zeros.
+    pub v: usize,
+    /// Number of visible fraction digits, without trailing zeros.
+    pub w: usize,
+    /// Visible fraction digits, with trailing zeros, as an integer.
+    pub f: u64,
+    /// Visible fraction digits, without trailing zeros, as an integer.
+    pub t: u64,
+}
+
+impl FromStr for PluralOperands {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let s = s.strip_prefix('-').unwrap_or(s);
+        let (int_part, frac_part) = match s.split_once('.') {
+            Some((int_part, frac_part)) => (int_part, frac_part),
+            None => (s, ""),
+        };
+
+        if int_part.is_empty() || !int_part.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(());
+        }
+        if !frac_part.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(());
+        }
+
+        let trimmed = frac_part.trim_end_matches('0');
+        let i = int_part.parse::<u64>().map_err(|_| ())?;
+        let f = if frac_part.is_empty() {
+            0
+        } else {
+            frac_part.parse::<u64>().map_err(|_| ())?
+        };
+        let t = if trimmed.is_empty() {
+            0
+        } else {
+            trimmed.parse::<u64>().map_err(|_| ())?
+        };
+        let n = s.parse::<f64>().map_err(|_| ())?;
+
+        Ok(Self {
+            n,
+            i,
+            v: frac_part.len(),
+            w: trimmed.len(),
+            f,
+            t,
+        })
+    }
+}
+
+impl From<u64> for PluralOperands {
+    fn from(n: u64) -> Self {
+        Self {
+            n: n as f64,
+            i: n,
+            v: 0,
+            w: 0,
+            f: 0,
+            t: 0,
+        }
+    }
+}
+
+impl From<i64> for PluralOperands {
+    fn from(n: i64) -> Self {
+        Self::from(n.unsigned_abs())
+    }
+}
+
+impl From<f64> for PluralOperands {
+    fn from(n: f64) -> Self {
+        n.to_string().parse().unwrap_or(Self {
+            n: n.abs(),
+            i: n.abs().trunc() as u64,
+            v: 0,
+            w: 0,
+            f: 0,
+            t: 0,
+        })
+    }
+}
+
+/// Whether `x` is an integer within `lo..=hi`, as CLDR ranges only match integers.
+fn within(x: f64, lo: u64, hi: u64) -> bool {
+    x.fract() == 0.0 && x >= lo as f64 && x <= hi as f64
+}
+
+fn is(x: f64, value: u64) -> bool {
+    x == value as f64
+}
+
+/// Normalize a language code to the form used for rule lookup, e.g. `pt_PT` to `pt-pt`.
+fn normalize(lang: &str) -> String {
+    lang.replace('_', "-").to_ascii_lowercase()
+}
+
+/// Determine the cardinal plural category of a number in a language.
+///
+/// # Arguments
+///
+/// * `lang` - A language code, e.g. `"pl"` or `"pt-PT"`.
+/// * `operands` - The operands of the number.
+///
+/// # Example
+///
+/// ```rust
+/// use lingua_i18n_rs::plural::{cardinal, PluralCategory};
+///
+/// assert_eq!(cardinal("en", &1u64.into()), PluralCategory::One);
+/// assert_eq!(cardinal("pl", &5u64.into()), PluralCategory::Many);
+/// assert_eq!(cardinal("en", &"1.0".parse().unwrap()), PluralCategory::Other);
+/// ```
+pub fn cardinal(lang: &str, operands: &PluralOperands) -> PluralCategory {
+    use PluralCategory::*;
+
+    let lang = normalize(lang);
+    let PluralOperands { n, i, v, f, t, .. } = *operands;
+    let i10 = i % 10;
+    let i100 = i % 100;
+    let f10 = f % 10;
+    let f100 = f % 100;
+    let n10 = n % 10.0;
+    let n100 = n % 100.0;
+
+    if lang == "pt-pt" {
+        return if i == 1 && v == 0 {
+            One
+        } else if i != 0 && i % 1_000_000 == 0 && v == 0 {
+            Many
+        } else {
+            Other
+        };
+    }
+
+    let primary = lang.split('-').next().unwrap_or("");
+    match primary {
+        "bm" | "bo" | "dz" | "hnj" | "id" | "ig" | "ii" | "in" | "ja" | "jbo" | "jv" | "jw"
+        | "kde" | "kea" | "km" | "ko" | "lkt" | "lo" | "ms" | "my" | "nqo" | "osa" | "root"
+        | "sah" | "ses" | "sg" | "su" | "th" | "to" | "tpi" | "vi" | "wo" | "yo" | "yue" | "zh" => {
+            Other
+        }
+
+        "am" | "as" | "bn" | "doi" | "fa" | "gu" | "hi" | "kn" | "pcm" | "zu" => {
+            if i == 0 || is(n, 1) { One } else { Other }
+        }
+
+        "ff" | "hy" | "kab" => {
+            if i == 0 || i == 1 {
+                One
+            } else {
+                Other
+            }
+        }
+
+        "ast" | "de" | "en" | "et" | "fi" | "fy" | "gl" | "ia" | "io" | "ji" | "lij" | "nl"
+        | "sc" | "scn" | "sv" | "sw" | "ur" | "yi" => {
+            if i == 1 && v == 0 {
+                One
+            } else {
+                Other
+            }
+        }
+
+        "ca" | "it" | "vec" | "lld" => {
+            if i == 1 && v == 0 {
+                One
+            } else if i != 0 && i % 1_000_000 == 0 && v == 0 {
+                Many
+            } else {
+                Other
+            }
+        }
+
+        "es" => {
+            if is(n, 1) {
+                One
+            } else if i != 0 && i % 1_000_000 == 0 && v == 0 {
+                Many
+            } else {
+                Other
+            }
+        }
+
+        "fr" => {
+            if i == 0 || i == 1 {
+                One
+            } else if i != 0 && i % 1_000_000 == 0 && v == 0 {
+                Many
+            } else {
+                Other
+            }
+        }
+
+        "pt" => {
+            if within(i as f64, 0, 1) {
+                One
+            } else if i != 0 && i % 1_000_000 == 0 && v == 0 {
+                Many
+            } else {
+                Other
+            }
+        }
+
+        "ak" | "bho" | "guw" | "ln" | "mg" | "nso" | "pa" | "ti" | "wa" => {
+            if within(n, 0, 1) {
+                One
+            } else {
+                Other
+            }
+        }
+
+        "tzm" => {
+            if within(n, 0, 1) || within(n, 11, 99) {
+                One
+            } else {
+                Other
+            }
+        }
+
+        "af" | "an" | "asa" | "az" | "bal" | "bem" | "bez" | "bg" | "brx" | "ce" | "cgg"
+        | "chr" | "ckb" | "dv" | "ee" | "el" | "eo" | "eu" | "fo" | "fur" | "gsw" | "ha"
+        | "haw" | "hu" | "jgo" | "jmc" | "ka" | "kaj" | "kcg" | "kk" | "kkj" | "kl" | "ks"
+        | "ksb" | "ku" | "ky" | "lb" | "lg" | "mas" | "mgo" | "ml" | "mn" | "mr" | "nah" | "nb"
+        | "nd" | "ne" | "nn" | "nnh" | "no" | "nr" | "ny" | "nyn" | "om" | "or" | "os" | "pap"
+        | "ps" | "rm" | "rof" | "rwk" | "saq" | "sd" | "sdh" | "seh" | "sn" | "so" | "sq"
+        | "ss" | "ssy" | "st" | "syr" | "ta" | "te" | "teo" | "tig" | "tk" | "tn" | "tr" | "ts"
+        | "ug" | "uz" | "ve" | "vo" | "vun" | "wae" | "xh" | "xog" => {
+            if is(n, 1) {
+                One
+            } else {
+                Other
+            }
+        }
+
+        "da" => {
+            if is(n, 1) || (t != 0 && (i == 0 || i == 1)) {
+                One
+            } else {
+                Other
+            }
+        }
+
+        "is" => {
+            if (t == 0 && i10 == 1 && i100 != 11) || (t % 10 == 1 && t % 100 != 11) {
+                One
+            } else {
+                Other
+            }
+        }
+
+        "mk" => {
+            if (v == 0 && i10 == 1 && i100 != 11) || (f10 == 1 && f100 != 11) {
+                One
+            } else {
+                Other
+            }
+        }
+
+        "ceb" | "fil" | "tl" => {
+            if (v == 0 && !matches!(i10, 4 | 6 | 9)) || (v != 0 && !matches!(f10, 4 | 6 | 9)) {
+                One
+            } else {
+                Other
+            }
+        }
+
+        "lv" | "prg" => {
+            if is(n10, 0) || within(n100, 11, 19) || (v == 2 && (11..=19).contains(&f100)) {
+                Zero
+            } else if (is(n10, 1) && !is(n100, 11)) || (f10 == 1 && (v != 2 || f100 != 11)) {
+                One
+            } else {
+                Other
+            }
+        }
+
+        "lag" => {
+            if is(n, 0) {
+                Zero
+            } else if (i == 0 || i == 1) && !is(n, 0) {
+                One
+            } else {
+                Other
+            }
+        }
+
+        "ksh" | "blo" => {
+            if is(n, 0) {
+                Zero
+            } else if is(n, 1) {
+                One
+            } else {
+                Other
+            }
+        }
+
+        "he" | "iw" => {
+            if (i == 1 && v == 0) || (i == 0 && v != 0) {
+                One
+            } else if i == 2 && v == 0 {
+                Two
+            } else {
+                Other
+            }
+        }
+
+        "iu" | "naq" | "sat" | "se" | "sma" | "smi" | "smj" | "smn" | "sms" => {
+            if is(n, 1) {
+                One
+            } else if is(n, 2) {
+                Two
+            } else {
+                Other
+            }
+        }
+
+        "shi" => {
+            if i == 0 || is(n, 1) {
+                One
+            } else if within(n, 2, 10) {
+                Few
+            } else {
+                Other
+            }
+        }
+
+        "mo" | "ro" => {
+            if i == 1 && v == 0 {
+                One
+            } else if v != 0 || is(n, 0) || (!is(n, 1) && within(n100, 1, 19)) {
+                Few
+            } else {
+                Other
+            }
+        }
+
+        "bs" | "hr" | "sh" | "sr" => {
+            if (v == 0 && i10 == 1 && i100 != 11) || (f10 == 1 && f100 != 11) {
+                One
+            } else if (v == 0 && (2..=4).contains(&i10) && !(12..=14).contains(&i100))
+                || ((2..=4).contains(&f10) && !(12..=14).contains(&f100))
+            {
+                Few
+            } else {
+                Other
+            }
+        }
+
+        "gd" => {
+            if is(n, 1) || is(n, 11) {
+                One
+            } else if is(n, 2) || is(n, 12) {
+                Two
+            } else if within(n, 3, 10) || within(n, 13, 19) {
+                Few
+            } else {
+                Other
+            }
+        }
+
+        "sl" => {
+            if v == 0 && i100 == 1 {
+                One
+            } else if v == 0 && i100 == 2 {
+                Two
+            } else if v != 0 || (3..=4).contains(&i100) {
+                Few
+            } else {
+                Other
+            }
+        }
+
+        "dsb" | "hsb" => {
+            if (v == 0 && i100 == 1) || f100 == 1 {
+                One
+            } else if (v == 0 && i100 == 2) || f100 == 2 {
+                Two
+            } else if (v == 0 && (3..=4).contains(&i100)) || (3..=4).contains(&f100) {
+                Few
+            } else {
+                Other
+            }
+        }
+
+        "cs" | "sk" => {
+            if i == 1 && v == 0 {
+                One
+            } else if (2..=4).contains(&i) && v == 0 {
+                Few
+            } else if v != 0 {
+                Many
+            } else {
+                Other
+            }
+        }
+
+        "pl" => {
+            if i == 1 && v == 0 {
+                One
+            } else if v == 0 && (2..=4).contains(&i10) && !(12..=14).contains(&i100) {
+                Few
+            } else if v == 0
+                && ((i != 1 && i10 <= 1) || (5..=9).contains(&i10) || (12..=14).contains(&i100))
+            {
+                Many
+            } else {
+                Other
+            }
+        }
+
+        "be" => {
+            if is(n10, 1) && !is(n100, 11) {
+                One
+            } else if within(n10, 2, 4) && !within(n100, 12, 14) {
+                Few
+            } else if is(n10, 0) || within(n10, 5, 9) || within(n100, 11, 14) {
+                Many
+            } else {
+                Other
+            }
+        }
+
+        "lt" => {
+            if is(n10, 1) && !within(n100, 11, 19) {
+                One
+            } else if within(n10, 2, 9) && !within(n100, 11, 19) {
+                Few
+            } else if f != 0 {
+                Many
+            } else {
+                Other
+            }
+        }
+
+        "ru" | "uk" => {
+            if v == 0 && i10 == 1 && i100 != 11 {
+                One
+            } else if v == 0 && (2..=4).contains(&i10) && !(12..=14).contains(&i100) {
+                Few
+            } else if v == 0 && (i10 == 0 || (5..=9).contains(&i10) || (11..=14).contains(&i100)) {
+                Many
+            } else {
+                Other
+            }
+        }
+
+        "br" => {
+            if is(n10, 1) && !(is(n100, 11) || is(n100, 71) || is(n100, 91)) {
+                One
+            } else if is(n10, 2) && !(is(n100, 12) || is(n100, 72) || is(n100, 92)) {
+                Two
+            } else if (within(n10, 3, 4) || is(n10, 9))
+                && !(within(n100, 10, 19) || within(n100, 70, 79) || within(n100, 90, 99))
+            {
+                Few
+            } else if !is(n, 0) && is(n % 1_000_000.0, 0) {
+                Many
+            } else {
+                Other
+            }
+        }
+
+        "mt" => {
+            if is(n, 1) {
+                One
+            } else if is(n, 2) {
+                Two
+            } else if is(n, 0) || within(n100, 3, 10) {
+                Few
+            } else if within(n100, 11, 19) {
+                Many
+            } else {
+                Other
+            }
+        }
+
+        "ga" => {
+            if is(n, 1) {
+                One
+            } else if is(n, 2) {
+                Two
+            } else if within(n, 3, 6) {
+                Few
+            } else if within(n, 7, 10) {
+                Many
+            } else {
+                Other
+            }
+        }
+
+        "gv" => {
+            if v == 0 && i10 == 1 {
+                One
+            } else if v == 0 && i10 == 2 {
+                Two
+            } else if v == 0 && matches!(i100, 0 | 20 | 40 | 60 | 80) {
+                Few
+            } else if v != 0 {
+                Many
+            } else {
+                Other
+            }
+        }
+
+        "kw" => {
+            let n1000 = n % 1000.0;
+            let n100000 = n % 100_000.0;
+            if is(n, 0) {
+                Zero
+            } else if is(n, 1) {
+                One
+            } else if [2, 22, 42, 62, 82].iter().any(|&x| is(n100, x))
+                || (is(n1000, 0)
+                    && (within(n100000, 1000, 20000)
+                        || [40000, 60000, 80000].iter().any(|&x| is(n100000, x))))
+                || (!is(n, 0) && is(n % 1_000_000.0, 100_000))
+            {
+                Two
+            } else if [3, 23, 43, 63, 83].iter().any(|&x| is(n100, x)) {
+                Few
+            } else if !is(n, 1) && [1, 21, 41, 61, 81].iter().any(|&x| is(n100, x)) {
+                Many
+            } else {
+                Other
+            }
+        }
+
+        "ar" | "ars" => {
+            if is(n, 0) {
+                Zero
+            } else if is(n, 1) {
+                One
+            } else if is(n, 2) {
+                Two
+            } else if within(n100, 3, 10) {
+                Few
+            } else if within(n100, 11, 99) {
+                Many
+            } else {
+                Other
+            }
+        }
+
+        "cy" => {
+            if is(n, 0) {
+                Zero
+            } else if is(n, 1) {
+                One
+            } else if is(n, 2) {
+                Two
+            } else if is(n, 3) {
+                Few
+            } else if is(n, 6) {
+                Many
+            } else {
+                Other
+            }
+        }
+
+        "si" => {
+            if is(n, 0) || is(n, 1) || (i == 0 && f == 1) {
+                One
+            } else {
+                Other
+            }
+        }
+
+        _ => Other,
+    }
+}
+
+/// Determine the ordinal plural category of a number in a language, e.g. for "1st" or "2nd".
+///
+/// # Arguments
+///
+/// * `lang` - A language code, e.g. `"en"`.
+/// * `operands` - The operands of the number.
+///
+/// # Example
+///
+/// ```rust
+/// use lingua_i18n_rs::plural::{ordinal, PluralCategory};
+///
+/// assert_eq!(ordinal("en", &22u64.into()), PluralCategory::Two);
+/// assert_eq!(ordinal("en", &13u64.into()), PluralCategory::Other);
+/// ```
+pub fn ordinal(lang: &str, operands: &PluralOperands) -> PluralCategory {
+    use PluralCategory::*;
+
+    let lang = normalize(lang);
+    let PluralOperands { n, i, .. } = *operands;
+    let i10 = i % 10;
+    let i100 = i % 100;
+    let n10 = n % 10.0;
+    let n100 = n % 100.0;
+    let any = |x: f64, values: &[u64]| values.iter().any(|&v| is(x, v));
+
+    let primary = lang.split('-').next().unwrap_or("");
+    match primary {
+        "en" => {
+            if is(n10, 1) && !is(n100, 11) {
+                One
+            } else if is(n10, 2) && !is(n100, 12) {
+                Two
+            } else if is(n10, 3) && !is(n100, 13) {
+                Few
+            } else {
+                Other
+            }
+        }
+
+        "sv" => {
+            if any(n10, &[1, 2]) && !any(n100, &[11, 12]) {
+                One
+            } else {
+                Other
+            }
+        }
+
+        "fil" | "fr" | "ga" | "hy" | "lo" | "mo" | "ms" | "ro" | "tl" | "vi" => {
+            if is(n, 1) {
+                One
+            } else {
+                Other
+            }
+        }
+
+        "hu" => {
+            if any(n, &[1, 5]) {
+                One
+            } else {
+                Other
+            }
+        }
+
+        "ne" => {
+            if within(n, 1, 4) {
+                One
+            } else {
+                Other
+            }
+        }
+
+        "be" => {
+            if any(n10, &[2, 3]) && !any(n100, &[12, 13]) {
+                Few
+            } else {
+                Other
+            }
+        }
+
+        "uk" => {
+            if is(n10, 3) && !is(n100, 13) {
+                Few
+            } else {
+                Other
+            }
+        }
+
+        "tk" => {
+            if any(n10, &[6, 9]) || is(n, 10) {
+                Few
+            } else {
+                Other
+            }
+        }
+
+        "kk" => {
+            if any(n10, &[6, 9]) || (is(n10, 0) && !is(n, 0)) {
+                Many
+            } else {
+                Other
+            }
+        }
+
+        "it" | "sc" | "scn" => {
+            if any(n, &[11, 8, 80, 800]) {
+                Many
+            } else {
+                Other
+            }
+        }
+
+        "lij" => {
+            if any(n, &[11, 8]) || within(n, 80, 89) || within(n, 800, 899) {
+                Many
+            } else {
+                Other
+            }
+        }
+
+        "ka" => {
+            if i == 1 {
+                One
+            } else if i == 0 || (2..=20).contains(&i100) || matches!(i100, 40 | 60 | 80) {
+                Many
+            } else {
+                Other
+            }
+        }
+
+        "sq" => {
+            if is(n, 1) {
+                One
+            } else if is(n10, 4) && !is(n100, 14) {
+                Many
+            } else {
+                Other
+            }
+        }
+
+        "cy" => {
+            if any(n, &[0, 7, 8, 9]) {
+                Zero
+            } else if is(n, 1) {
+                One
+            } else if is(n, 2) {
+                Two
+            } else if any(n, &[3, 4]) {
+                Few
+            } else if any(n, &[5, 6]) {
+                Many
+            } else {
+                Other
+            }
+        }
+
+        "ca" => {
+            if any(n, &[1, 3]) {
+                One
+            } else if is(n, 2) {
+                Two
+            } else if is(n, 4) {
+                Few
+            } else {
+                Other
+            }
+        }
+
+        "mr" => {
+            if is(n, 1) {
+                One
+            } else if any(n, &[2, 3]) {
+                Two
+            } else if is(n, 4) {
+                Few
+            } else {
+                Other
+            }
+        }
+
+        "gu" | "hi" => {
+            if is(n, 1) {
+                One
+            } else if any(n, &[2, 3]) {
+                Two
+            } else if is(n, 4) {
+                Few
+            } else if is(n, 6) {
+                Many
+            } else {
+                Other
+            }
+        }
+
+        "as" | "bn" => {
+            if any(n, &[1, 5, 7, 8, 9, 10]) {
+                One
+            } else if any(n, &[2, 3]) {
+                Two
+            } else if is(n, 4) {
+                Few
+            } else if is(n, 6) {
+                Many
+            } else {
+                Other
+            }
+        }
+
+        "or" => {
+            if any(n, &[1, 5]) || within(n, 7, 9) {
+                One
+            } else if any(n, &[2, 3]) {
+                Two
+            } else if is(n, 4) {
+                Few
+            } else if is(n, 6) {
+                Many
+            } else {
+                Other
+            }
+        }
+
+        "az" => {
+            if matches!(i10, 1 | 2 | 5 | 7 | 8) || matches!(i100, 20 | 50 | 70 | 80) {
+                One
+            } else if matches!(i10, 3 | 4) || (i % 1000 != 0 && i % 100 == 0) {
+                Few
+            } else if i == 0 || i10 == 6 || matches!(i100, 40 | 60 | 90) {
+                Many
+            } else {
+                Other
+            }
+        }
+
+        "gd" => {
+            if any(n, &[1, 11]) {
+                One
+            } else if any(n, &[2, 12]) {
+                Two
+            } else if any(n, &[3, 13]) {
+                Few
+            } else {
+                Other
+            }
+        }
+
+        "kw" => {
+            if within(n, 1, 4)
+                || within(n100, 1, 4)
+                || within(n100, 21, 24)
+                || within(n100, 41, 44)
+                || within(n100, 61, 64)
+                || within(n100, 81, 84)
+            {
+                One
+            } else if is(n, 5) || is(n100, 5) {
+                Many
+            } else {
+                Other
+            }
+        }
+
+        "mk" => {
+            if i10 == 1 && i100 != 11 {
+                One
+            } else if i10 == 2 && i100 != 12 {
+                Two
+            } else if matches!(i10, 7 | 8) && !matches!(i100, 17 | 18) {
+                Many
+            } else {
+                Other
+            }
+        }
+
+        _ => Other,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cat(lang: &str, n: &str) -> PluralCategory {
+        cardinal(lang, &n.parse().unwrap())
+    }
+
+    #[test]
+    fn test_operands() {
+        let op: PluralOperands = "1.50".parse().unwrap();
+        assert_eq!((op.i, op.v, op.w, op.f, op.t), (1, 2, 1, 50, 5));
+        assert!("abc".parse::<PluralOperands>().is_err());
+        assert!("1.2.3".parse::<PluralOperands>().is_err());
+    }
+
+    #[test]
+    fn test_cardinal_rules() {
+        assert_eq!(cat("en", "1"), PluralCategory::One);
+        assert_eq!(cat("en", "1.0"), PluralCategory::Other);
+        assert_eq!(cat("en", "0"), PluralCategory::Other);
+        assert_eq!(cat("fr", "0"), PluralCategory::One);
+        assert_eq!(cat("fr", "1000000"), PluralCategory::Many);
+        assert_eq!(cat("ja", "1"), PluralCategory::Other);
+
+        assert_eq!(cat("pl", "1"), PluralCategory::One);
+        assert_eq!(cat("pl", "3"), PluralCategory::Few);
+        assert_eq!(cat("pl", "13"), PluralCategory::Many);
+        assert_eq!(cat("pl", "22"), PluralCategory::Few);
+        assert_eq!(cat("pl", "1.5"), PluralCategory::Other);
+
+        assert_eq!(cat("ru", "21"), PluralCategory::One);
+        assert_eq!(cat("ru", "11"), PluralCategory::Many);
+
+        assert_eq!(cat("ar", "0"), PluralCategory::Zero);
+        assert_eq!(cat("ar", "2"), PluralCategory::Two);
+        assert_eq!(cat("ar", "103"), PluralCategory::Few);
+        assert_eq!(cat("ar", "111"), PluralCategory::Many);
+        assert_eq!(cat("ar", "100"), PluralCategory::Other);
+
+        assert_eq!(cat("pt", "0"), PluralCategory::One);
+        assert_eq!(cat("pt-PT", "0"), PluralCategory::Other);
+        assert_eq!(cat("cy", "6"), PluralCategory::Many);
+    }
+
+    #[test]
+    fn test_ordinal_rules() {
+        let ord = |lang: &str, n: u64| ordinal(lang, &n.into());
+        assert_eq!(ord("en", 1), PluralCategory::One);
+        assert_eq!(ord("en", 11), PluralCategory::Other);
+        assert_eq!(ord("en", 23), PluralCategory::Few);
+        assert_eq!(ord("de", 1), PluralCategory::Other);
+        assert_eq!(ord("fr", 1), PluralCategory::One);
+        assert_eq!(ord("it", 8), PluralCategory::Many);
+        assert_eq!(ord("az", 300), PluralCategory::Few);
+    }
+}
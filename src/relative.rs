@@ -0,0 +1,959 @@
+//! Relative time and duration formatting.
+//!
+//! Relative times ("3 minutes ago", "in 2 days", "yesterday") and durations
+//! ("1 hour, 20 minutes", "1 hr, 20 min", "1h 20m") are formatted with the CLDR relative
+//! time and unit patterns of the language, in the plural form the number requires. Common
+//! languages are built in and matched by their full tag first, then by their primary
+//! language subtag; unknown languages use the English patterns.
+//!
+//! In translation strings, `{{name, relative}}` formats a signed number of seconds relative
+//! to now and `{{name, duration}}` a number of seconds, optionally with a width
+//! (`{{name, relative:short}}`, `{{name, duration:narrow}}`).
+use crate::list::{self, ListType};
+use crate::locale::find_locale;
+use crate::number::{self, NumberStyle};
+use crate::plural::{self, PluralCategory, PluralOperands};
+use std::fmt;
+use std::time::Duration;
+
+/// The width of relative time and duration patterns.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Width {
+    /// E.g. `in 3 minutes` or `1 hour, 20 minutes`.
+    #[default]
+    Long,
+    /// E.g. `in 3 min.` or `1 hr, 20 min`.
+    Short,
+    /// E.g. `in 3 min.` or `1h 20m`.
+    Narrow,
+}
+
+impl Width {
+    /// The keyword used for the width in placeholders.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Width::Long => "long",
+            Width::Short => "short",
+            Width::Narrow => "narrow",
+        }
+    }
+
+    /// Parse a placeholder keyword.
+    pub fn from_keyword(keyword: &str) -> Option<Self> {
+        match keyword.trim() {
+            "long" => Some(Width::Long),
+            "short" => Some(Width::Short),
+            "narrow" => Some(Width::Narrow),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn index(self) -> usize {
+        self as usize
+    }
+}
+
+impl fmt::Display for Width {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// The unit of a relative time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RelativeUnit {
+    Second,
+    Minute,
+    Hour,
+    Day,
+    Week,
+    Month,
+    Year,
+}
+
+impl RelativeUnit {
+    fn index(self) -> usize {
+        self as usize
+    }
+}
+
+/// The relative time and unit patterns of a language.
+///
+/// Patterns hold `{0}` for the number and are either a single pattern for all plural
+/// categories or `category:pattern` alternatives separated by `|`.
+struct Patterns {
+    /// Future and past patterns by width (long and short) and unit.
+    relative: [[(&'static str, &'static str); 7]; 2],
+    yesterday: &'static str,
+    tomorrow: &'static str,
+    now: &'static str,
+    /// Day, hour, minute and second patterns by width.
+    units: [[&'static str; 4]; 3],
+}
+
+const EN: Patterns = Patterns {
+    relative: [
+        [
+            (
+                "one:in {0} second|other:in {0} seconds",
+                "one:{0} second ago|other:{0} seconds ago",
+            ),
+            (
+                "one:in {0} minute|other:in {0} minutes",
+                "one:{0} minute ago|other:{0} minutes ago",
+            ),
+            (
+                "one:in {0} hour|other:in {0} hours",
+                "one:{0} hour ago|other:{0} hours ago",
+            ),
+            (
+                "one:in {0} day|other:in {0} days",
+                "one:{0} day ago|other:{0} days ago",
+            ),
+            (
+                "one:in {0} week|other:in {0} weeks",
+                "one:{0} week ago|other:{0} weeks ago",
+            ),
+            (
+                "one:in {0} month|other:in {0} months",
+                "one:{0} month ago|other:{0} months ago",
+            ),
+            (
+                "one:in {0} year|other:in {0} years",
+                "one:{0} year ago|other:{0} years ago",
+            ),
+        ],
+        [
+            ("in {0} sec.", "{0} sec. ago"),
+            ("in {0} min.", "{0} min. ago"),
+            ("in {0} hr.", "{0} hr. ago"),
+            (
+                "one:in {0} day|other:in {0} days",
+                "one:{0} day ago|other:{0} days ago",
+            ),
+            ("in {0} wk.", "{0} wk. ago"),
+            ("in {0} mo.", "{0} mo. ago"),
+            ("in {0} yr.", "{0} yr. ago"),
+        ],
+    ],
+    yesterday: "yesterday",
+    tomorrow: "tomorrow",
+    now: "now",
+    units: [
+        [
+            "one:{0} day|other:{0} days",
+            "one:{0} hour|other:{0} hours",
+            "one:{0} minute|other:{0} minutes",
+            "one:{0} second|other:{0} seconds",
+        ],
+        ["one:{0} day|other:{0} days", "{0} hr", "{0} min", "{0} sec"],
+        ["{0}d", "{0}h", "{0}m", "{0}s"],
+    ],
+};
+
+/// Built-in languages, by lowercase tag.
+const LOCALES: &[(&str, Patterns)] = &[
+    (
+        "de",
+        Patterns {
+            relative: [
+                [
+                    (
+                        "one:in {0} Sekunde|other:in {0} Sekunden",
+                        "one:vor {0} Sekunde|other:vor {0} Sekunden",
+                    ),
+                    (
+                        "one:in {0} Minute|other:in {0} Minuten",
+                        "one:vor {0} Minute|other:vor {0} Minuten",
+                    ),
+                    (
+                        "one:in {0} Stunde|other:in {0} Stunden",
+                        "one:vor {0} Stunde|other:vor {0} Stunden",
+                    ),
+                    (
+                        "one:in {0} Tag|other:in {0} Tagen",
+                        "one:vor {0} Tag|other:vor {0} Tagen",
+                    ),
+                    (
+                        "one:in {0} Woche|other:in {0} Wochen",
+                        "one:vor {0} Woche|other:vor {0} Wochen",
+                    ),
+                    (
+                        "one:in {0} Monat|other:in {0} Monaten",
+                        "one:vor {0} Monat|other:vor {0} Monaten",
+                    ),
+                    (
+                        "one:in {0} Jahr|other:in {0} Jahren",
+                        "one:vor {0} Jahr|other:vor {0} Jahren",
+                    ),
+                ],
+                [
+                    ("in {0} Sek.", "vor {0} Sek."),
+                    ("in {0} Min.", "vor {0} Min."),
+                    ("in {0} Std.", "vor {0} Std."),
+                    (
+                        "one:in {0} Tag|other:in {0} Tagen",
+                        "one:vor {0} Tag|other:vor {0} Tagen",
+                    ),
+                    (
+                        "one:in {0} Woche|other:in {0} Wochen",
+                        "one:vor {0} Woche|other:vor {0} Wochen",
+                    ),
+                    (
+                        "one:in {0} Monat|other:in {0} Monaten",
+                        "one:vor {0} Monat|other:vor {0} Monaten",
+                    ),
+                    (
+                        "one:in {0} Jahr|other:in {0} Jahren",
+                        "one:vor {0} Jahr|other:vor {0} Jahren",
+                    ),
+                ],
+            ],
+            yesterday: "gestern",
+            tomorrow: "morgen",
+            now: "jetzt",
+            units: [
+                [
+                    "one:{0} Tag|other:{0} Tage",
+                    "one:{0} Stunde|other:{0} Stunden",
+                    "one:{0} Minute|other:{0} Minuten",
+                    "one:{0} Sekunde|other:{0} Sekunden",
+                ],
+                ["{0} Tg.", "{0} Std.", "{0} Min.", "{0} Sek."],
+                ["{0} T", "{0} Std.", "{0} Min.", "{0} Sek."],
+            ],
+        },
+    ),
+    ("en", EN),
+    (
+        "es",
+        Patterns {
+            relative: [
+                [
+                    (
+                        "one:dentro de {0} segundo|other:dentro de {0} segundos",
+                        "one:hace {0} segundo|other:hace {0} segundos",
+                    ),
+                    (
+                        "one:dentro de {0} minuto|other:dentro de {0} minutos",
+                        "one:hace {0} minuto|other:hace {0} minutos",
+                    ),
+                    (
+                        "one:dentro de {0} hora|other:dentro de {0} horas",
+                        "one:hace {0} hora|other:hace {0} horas",
+                    ),
+                    (
+                        "one:dentro de {0} día|other:dentro de {0} días",
+                        "one:hace {0} día|other:hace {0} días",
+                    ),
+                    (
+                        "one:dentro de {0} semana|other:dentro de {0} semanas",
+                        "one:hace {0} semana|other:hace {0} semanas",
+                    ),
+                    (
+                        "one:dentro de {0} mes|other:dentro de {0} meses",
+                        "one:hace {0} mes|other:hace {0} meses",
+                    ),
+                    (
+                        "one:dentro de {0} año|other:dentro de {0} años",
+                        "one:hace {0} año|other:hace {0} años",
+                    ),
+                ],
+                [
+                    ("dentro de {0} s", "hace {0} s"),
+                    ("dentro de {0} min", "hace {0} min"),
+                    ("dentro de {0} h", "hace {0} h"),
+                    ("dentro de {0} d", "hace {0} d"),
+                    ("dentro de {0} sem.", "hace {0} sem."),
+                    ("dentro de {0} m", "hace {0} m"),
+                    ("dentro de {0} a", "hace {0} a"),
+                ],
+            ],
+            yesterday: "ayer",
+            tomorrow: "mañana",
+            now: "ahora",
+            units: [
+                [
+                    "one:{0} día|other:{0} días",
+                    "one:{0} hora|other:{0} horas",
+                    "one:{0} minuto|other:{0} minutos",
+                    "one:{0} segundo|other:{0} segundos",
+                ],
+                ["{0} d", "{0} h", "{0} min", "{0} s"],
+                ["{0}d", "{0}h", "{0}min", "{0}s"],
+            ],
+        },
+    ),
+    (
+        "fr",
+        Patterns {
+            relative: [
+                [
+                    (
+                        "one:dans {0} seconde|other:dans {0} secondes",
+                        "one:il y a {0} seconde|other:il y a {0} secondes",
+                    ),
+                    (
+                        "one:dans {0} minute|other:dans {0} minutes",
+                        "one:il y a {0} minute|other:il y a {0} minutes",
+                    ),
+                    (
+                        "one:dans {0} heure|other:dans {0} heures",
+                        "one:il y a {0} heure|other:il y a {0} heures",
+                    ),
+                    (
+                        "one:dans {0} jour|other:dans {0} jours",
+                        "one:il y a {0} jour|other:il y a {0} jours",
+                    ),
+                    (
+                        "one:dans {0} semaine|other:dans {0} semaines",
+                        "one:il y a {0} semaine|other:il y a {0} semaines",
+                    ),
+                    ("dans {0} mois", "il y a {0} mois"),
+                    (
+                        "one:dans {0} an|other:dans {0} ans",
+                        "one:il y a {0} an|other:il y a {0} ans",
+                    ),
+                ],
+                [
+                    ("dans {0} s", "il y a {0} s"),
+                    ("dans {0} min", "il y a {0} min"),
+                    ("dans {0} h", "il y a {0} h"),
+                    ("dans {0} j", "il y a {0} j"),
+                    ("dans {0} sem.", "il y a {0} sem."),
+                    ("dans {0} m.", "il y a {0} m."),
+                    ("dans {0} a", "il y a {0} a"),
+                ],
+            ],
+            yesterday: "hier",
+            tomorrow: "demain",
+            now: "maintenant",
+            units: [
+                [
+                    "one:{0} jour|other:{0} jours",
+                    "one:{0} heure|other:{0} heures",
+                    "one:{0} minute|other:{0} minutes",
+                    "one:{0} seconde|other:{0} secondes",
+                ],
+                ["{0} j", "{0} h", "{0} min", "{0} s"],
+                ["{0}j", "{0}h", "{0}min", "{0}s"],
+            ],
+        },
+    ),
+    (
+        "it",
+        Patterns {
+            relative: [
+                [
+                    (
+                        "one:tra {0} secondo|other:tra {0} secondi",
+                        "one:{0} secondo fa|other:{0} secondi fa",
+                    ),
+                    (
+                        "one:tra {0} minuto|other:tra {0} minuti",
+                        "one:{0} minuto fa|other:{0} minuti fa",
+                    ),
+                    (
+                        "one:tra {0} ora|other:tra {0} ore",
+                        "one:{0} ora fa|other:{0} ore fa",
+                    ),
+                    (
+                        "one:tra {0} giorno|other:tra {0} giorni",
+                        "one:{0} giorno fa|other:{0} giorni fa",
+                    ),
+                    (
+                        "one:tra {0} settimana|other:tra {0} settimane",
+                        "one:{0} settimana fa|other:{0} settimane fa",
+                    ),
+                    (
+                        "one:tra {0} mese|other:tra {0} mesi",
+                        "one:{0} mese fa|other:{0} mesi fa",
+                    ),
+                    (
+                        "one:tra {0} anno|other:tra {0} anni",
+                        "one:{0} anno fa|other:{0} anni fa",
+                    ),
+                ],
+                [
+                    ("tra {0} s", "{0} s fa"),
+                    ("tra {0} min", "{0} min fa"),
+                    ("tra {0} h", "{0} h fa"),
+                    ("tra {0} g", "{0} g fa"),
+                    ("tra {0} sett.", "{0} sett. fa"),
+                    (
+                        "one:tra {0} mese|other:tra {0} mesi",
+                        "one:{0} mese fa|other:{0} mesi fa",
+                    ),
+                    (
+                        "one:tra {0} anno|other:tra {0} anni",
+                        "one:{0} anno fa|other:{0} anni fa",
+                    ),
+                ],
+            ],
+            yesterday: "ieri",
+            tomorrow: "domani",
+            now: "ora",
+            units: [
+                [
+                    "one:{0} giorno|other:{0} giorni",
+                    "one:{0} ora|other:{0} ore",
+                    "one:{0} minuto|other:{0} minuti",
+                    "one:{0} secondo|other:{0} secondi",
+                ],
+                ["{0} g", "{0} h", "{0} min", "{0} s"],
+                ["{0}g", "{0}h", "{0}min", "{0}s"],
+            ],
+        },
+    ),
+    (
+        "ja",
+        Patterns {
+            relative: [
+                [
+                    ("{0} 秒後", "{0} 秒前"),
+                    ("{0} 分後", "{0} 分前"),
+                    ("{0} 時間後", "{0} 時間前"),
+                    ("{0} 日後", "{0} 日前"),
+                    ("{0} 週間後", "{0} 週間前"),
+                    ("{0} か月後", "{0} か月前"),
+                    ("{0} 年後", "{0} 年前"),
+                ],
+                [
+                    ("{0} 秒後", "{0} 秒前"),
+                    ("{0} 分後", "{0} 分前"),
+                    ("{0} 時間後", "{0} 時間前"),
+                    ("{0} 日後", "{0} 日前"),
+                    ("{0} 週間後", "{0} 週間前"),
+                    ("{0} か月後", "{0} か月前"),
+                    ("{0} 年後", "{0} 年前"),
+                ],
+            ],
+            yesterday: "昨日",
+            tomorrow: "明日",
+            now: "今",
+            units: [
+                ["{0} 日", "{0} 時間", "{0} 分", "{0} 秒"],
+                ["{0} 日", "{0} 時間", "{0} 分", "{0} 秒"],
+                ["{0}日", "{0}時間", "{0}分", "{0}秒"],
+            ],
+        },
+    ),
+    (
+        "nl",
+        Patterns {
+            relative: [
+                [
+                    (
+                        "one:over {0} seconde|other:over {0} seconden",
+                        "one:{0} seconde geleden|other:{0} seconden geleden",
+                    ),
+                    (
+                        "one:over {0} minuut|other:over {0} minuten",
+                        "one:{0} minuut geleden|other:{0} minuten geleden",
+                    ),
+                    ("over {0} uur", "{0} uur geleden"),
+                    (
+                        "one:over {0} dag|other:over {0} dagen",
+                        "one:{0} dag geleden|other:{0} dagen geleden",
+                    ),
+                    (
+                        "one:over {0} week|other:over {0} weken",
+                        "one:{0} week geleden|other:{0} weken geleden",
+                    ),
+                    (
+                        "one:over {0} maand|other:over {0} maanden",
+                        "one:{0} maand geleden|other:{0} maanden geleden",
+                    ),
+                    ("over {0} jaar", "{0} jaar geleden"),
+                ],
+                [
+                    ("over {0} sec.", "{0} sec. geleden"),
+                    ("over {0} min.", "{0} min. geleden"),
+                    ("over {0} uur", "{0} uur geleden"),
+                    (
+                        "one:over {0} dag|other:over {0} dagen",
+                        "one:{0} dag geleden|other:{0} dagen geleden",
+                    ),
+                    ("over {0} wk.", "{0} wk. geleden"),
+                    ("over {0} mnd", "{0} mnd geleden"),
+                    ("over {0} jr.", "{0} jr. geleden"),
+                ],
+            ],
+            yesterday: "gisteren",
+            tomorrow: "morgen",
+            now: "nu",
+            units: [
+                [
+                    "one:{0} dag|other:{0} dagen",
+                    "{0} uur",
+                    "one:{0} minuut|other:{0} minuten",
+                    "one:{0} seconde|other:{0} seconden",
+                ],
+                ["one:{0} dag|other:{0} dgn", "{0} uur", "{0} min", "{0} sec"],
+                ["{0}d", "{0}u", "{0}m", "{0}s"],
+            ],
+        },
+    ),
+    (
+        "pl",
+        Patterns {
+            relative: [
+                [
+                    (
+                        "one:za {0} sekundę|few:za {0} sekundy|many:za {0} sekund|other:za {0} sekundy",
+                        "one:{0} sekundę temu|few:{0} sekundy temu|many:{0} sekund temu|other:{0} sekundy temu",
+                    ),
+                    (
+                        "one:za {0} minutę|few:za {0} minuty|many:za {0} minut|other:za {0} minuty",
+                        "one:{0} minutę temu|few:{0} minuty temu|many:{0} minut temu|other:{0} minuty temu",
+                    ),
+                    (
+                        "one:za {0} godzinę|few:za {0} godziny|many:za {0} godzin|other:za {0} godziny",
+                        "one:{0} godzinę temu|few:{0} godziny temu|many:{0} godzin temu|other:{0} godziny temu",
+                    ),
+                    (
+                        "one:za {0} dzień|few:za {0} dni|many:za {0} dni|other:za {0} dnia",
+                        "one:{0} dzień temu|few:{0} dni temu|many:{0} dni temu|other:{0} dnia temu",
+                    ),
+                    (
+                        "one:za {0} tydzień|few:za {0} tygodnie|many:za {0} tygodni|other:za {0} tygodnia",
+                        "one:{0} tydzień temu|few:{0} tygodnie temu|many:{0} tygodni temu|other:{0} tygodnia temu",
+                    ),
+                    (
+                        "one:za {0} miesiąc|few:za {0} miesiące|many:za {0} miesięcy|other:za {0} miesiąca",
+                        "one:{0} miesiąc temu|few:{0} miesiące temu|many:{0} miesięcy temu|other:{0} miesiąca temu",
+                    ),
+                    (
+                        "one:za {0} rok|few:za {0} lata|many:za {0} lat|other:za {0} roku",
+                        "one:{0} rok temu|few:{0} lata temu|many:{0} lat temu|other:{0} roku temu",
+                    ),
+                ],
+                [
+                    ("za {0} sek.", "{0} sek. temu"),
+                    ("za {0} min", "{0} min temu"),
+                    ("za {0} godz.", "{0} godz. temu"),
+                    (
+                        "one:za {0} dzień|few:za {0} dni|many:za {0} dni|other:za {0} dnia",
+                        "one:{0} dzień temu|few:{0} dni temu|many:{0} dni temu|other:{0} dnia temu",
+                    ),
+                    ("za {0} tydz.", "{0} tydz. temu"),
+                    ("za {0} mies.", "{0} mies. temu"),
+                    (
+                        "one:za {0} rok|few:za {0} lata|many:za {0} lat|other:za {0} roku",
+                        "one:{0} rok temu|few:{0} lata temu|many:{0} lat temu|other:{0} roku temu",
+                    ),
+                ],
+            ],
+            yesterday: "wczoraj",
+            tomorrow: "jutro",
+            now: "teraz",
+            units: [
+                [
+                    "one:{0} dzień|few:{0} dni|many:{0} dni|other:{0} dnia",
+                    "one:{0} godzina|few:{0} godziny|many:{0} godzin|other:{0} godziny",
+                    "one:{0} minuta|few:{0} minuty|many:{0} minut|other:{0} minuty",
+                    "one:{0} sekunda|few:{0} sekundy|many:{0} sekund|other:{0} sekundy",
+                ],
+                [
+                    "one:{0} dzień|few:{0} dni|many:{0} dni|other:{0} dnia",
+                    "{0} godz.",
+                    "{0} min",
+                    "{0} sek.",
+                ],
+                ["{0} d", "{0} g", "{0} min", "{0} s"],
+            ],
+        },
+    ),
+    (
+        "pt",
+        Patterns {
+            relative: [
+                [
+                    (
+                        "one:em {0} segundo|other:em {0} segundos",
+                        "one:há {0} segundo|other:há {0} segundos",
+                    ),
+                    (
+                        "one:em {0} minuto|other:em {0} minutos",
+                        "one:há {0} minuto|other:há {0} minutos",
+                    ),
+                    (
+                        "one:em {0} hora|other:em {0} horas",
+                        "one:há {0} hora|other:há {0} horas",
+                    ),
+                    (
+                        "one:em {0} dia|other:em {0} dias",
+                        "one:há {0} dia|other:há {0} dias",
+                    ),
+                    (
+                        "one:em {0} semana|other:em {0} semanas",
+                        "one:há {0} semana|other:há {0} semanas",
+                    ),
+                    (
+                        "one:em {0} mês|other:em {0} meses",
+                        "one:há {0} mês|other:há {0} meses",
+                    ),
+                    (
+                        "one:em {0} ano|other:em {0} anos",
+                        "one:há {0} ano|other:há {0} anos",
+                    ),
+                ],
+                [
+                    ("em {0} seg.", "há {0} seg."),
+                    ("em {0} min.", "há {0} min."),
+                    ("em {0} h", "há {0} h"),
+                    (
+                        "one:em {0} dia|other:em {0} dias",
+                        "one:há {0} dia|other:há {0} dias",
+                    ),
+                    ("em {0} sem.", "há {0} sem."),
+                    (
+                        "one:em {0} mês|other:em {0} meses",
+                        "one:há {0} mês|other:há {0} meses",
+                    ),
+                    (
+                        "one:em {0} ano|other:em {0} anos",
+                        "one:há {0} ano|other:há {0} anos",
+                    ),
+                ],
+            ],
+            yesterday: "ontem",
+            tomorrow: "amanhã",
+            now: "agora",
+            units: [
+                [
+                    "one:{0} dia|other:{0} dias",
+                    "one:{0} hora|other:{0} horas",
+                    "one:{0} minuto|other:{0} minutos",
+                    "one:{0} segundo|other:{0} segundos",
+                ],
+                ["one:{0} dia|other:{0} dias", "{0} h", "{0} min", "{0} s"],
+                ["{0}d", "{0}h", "{0}min", "{0}s"],
+            ],
+        },
+    ),
+    (
+        "ru",
+        Patterns {
+            relative: [
+                [
+                    (
+                        "one:через {0} секунду|few:через {0} секунды|many:через {0} секунд|other:через {0} секунды",
+                        "one:{0} секунду назад|few:{0} секунды назад|many:{0} секунд назад|other:{0} секунды назад",
+                    ),
+                    (
+                        "one:через {0} минуту|few:через {0} минуты|many:через {0} минут|other:через {0} минуты",
+                        "one:{0} минуту назад|few:{0} минуты назад|many:{0} минут назад|other:{0} минуты назад",
+                    ),
+                    (
+                        "one:через {0} час|few:через {0} часа|many:через {0} часов|other:через {0} часа",
+                        "one:{0} час назад|few:{0} часа назад|many:{0} часов назад|other:{0} часа назад",
+                    ),
+                    (
+                        "one:через {0} день|few:через {0} дня|many:через {0} дней|other:через {0} дня",
+                        "one:{0} день назад|few:{0} дня назад|many:{0} дней назад|other:{0} дня назад",
+                    ),
+                    (
+                        "one:через {0} неделю|few:через {0} недели|many:через {0} недель|other:через {0} недели",
+                        "one:{0} неделю назад|few:{0} недели назад|many:{0} недель назад|other:{0} недели назад",
+                    ),
+                    (
+                        "one:через {0} месяц|few:через {0} месяца|many:через {0} месяцев|other:через {0} месяца",
+                        "one:{0} месяц назад|few:{0} месяца назад|many:{0} месяцев назад|other:{0} месяца назад",
+                    ),
+                    (
+                        "one:через {0} год|few:через {0} года|many:через {0} лет|other:через {0} года",
+                        "one:{0} год назад|few:{0} года назад|many:{0} лет назад|other:{0} года назад",
+                    ),
+                ],
+                [
+                    ("через {0} сек.", "{0} сек. назад"),
+                    ("через {0} мин.", "{0} мин. назад"),
+                    ("через {0} ч", "{0} ч назад"),
+                    ("через {0} дн.", "{0} дн. назад"),
+                    ("через {0} нед.", "{0} нед. назад"),
+                    ("через {0} мес.", "{0} мес. назад"),
+                    (
+                        "many:через {0} л.|other:через {0} г.",
+                        "many:{0} л. назад|other:{0} г. назад",
+                    ),
+                ],
+            ],
+            yesterday: "вчера",
+            tomorrow: "завтра",
+            now: "сейчас",
+            units: [
+                [
+                    "one:{0} день|few:{0} дня|many:{0} дней|other:{0} дня",
+                    "one:{0} час|few:{0} часа|many:{0} часов|other:{0} часа",
+                    "one:{0} минута|few:{0} минуты|many:{0} минут|other:{0} минуты",
+                    "one:{0} секунда|few:{0} секунды|many:{0} секунд|other:{0} секунды",
+                ],
+                ["{0} дн.", "{0} ч", "{0} мин", "{0} с"],
+                ["{0} дн.", "{0} ч", "{0} мин", "{0} с"],
+            ],
+        },
+    ),
+    (
+        "zh",
+        Patterns {
+            relative: [
+                [
+                    ("{0}秒钟后", "{0}秒钟前"),
+                    ("{0}分钟后", "{0}分钟前"),
+                    ("{0}小时后", "{0}小时前"),
+                    ("{0}天后", "{0}天前"),
+                    ("{0}周后", "{0}周前"),
+                    ("{0}个月后", "{0}个月前"),
+                    ("{0}年后", "{0}年前"),
+                ],
+                [
+                    ("{0}秒后", "{0}秒前"),
+                    ("{0}分钟后", "{0}分钟前"),
+                    ("{0}小时后", "{0}小时前"),
+                    ("{0}天后", "{0}天前"),
+                    ("{0}周后", "{0}周前"),
+                    ("{0}个月后", "{0}个月前"),
+                    ("{0}年后", "{0}年前"),
+                ],
+            ],
+            yesterday: "昨天",
+            tomorrow: "明天",
+            now: "现在",
+            units: [
+                ["{0}天", "{0}小时", "{0}分钟", "{0}秒钟"],
+                ["{0}天", "{0}小时", "{0}分钟", "{0}秒"],
+                ["{0}天", "{0}小时", "{0}分钟", "{0}秒"],
+            ],
+        },
+    ),
+];
+
+/// Find the patterns of a language.
+fn patterns(lang: &str) -> &'static Patterns {
+    find_locale(lang, LOCALES, &EN)
+}
+
+/// Fill in the number of a pattern, choosing its plural form.
+fn apply(lang: &str, pattern: &str, value: u64) -> String {
+    let category = plural::cardinal(lang, &PluralOperands::from(value));
+    plural_form(pattern, category).replace(
+        "{0}",
+        &number::format(lang, value as f64, NumberStyle::Integer),
+    )
+}
+
+/// Pick the alternative of a `category:pattern|...` pattern for a plural category, falling
+/// back to `other` and to the whole pattern if it has no alternatives.
+pub(crate) fn plural_form(pattern: &str, category: PluralCategory) -> &str {
+    let mut fallback = pattern;
+    for alternative in pattern.split('|') {
+        match alternative.split_once(':') {
+            Some((name, text)) if name == category.as_str() => return text,
+            Some(("other", text)) => fallback = text,
+            _ => {}
+        }
+    }
+    fallback
+}
+
+/// Format a number of units relative to now, e.g. "in 3 days" or "3 days ago".
+///
+/// # Arguments
+///
+/// * `lang` - A language code, e.g. `"de"`.
+/// * `value` - The number of units; negative values are in the past.
+/// * `unit` - The unit of `value`.
+/// * `width` - The width of the pattern; `Narrow` uses the short patterns.
+///
+/// # Example
+///
+/// ```rust
+/// use lingua_i18n_rs::relative::{format_relative, RelativeUnit, Width};
+///
+/// assert_eq!(format_relative("en", 3, RelativeUnit::Day, Width::Long), "in 3 days");
+/// assert_eq!(format_relative("de", -1, RelativeUnit::Hour, Width::Long), "vor 1 Stunde");
+/// ```
+pub fn format_relative(lang: &str, value: i64, unit: RelativeUnit, width: Width) -> String {
+    let patterns = patterns(lang);
+    let width = width.index().min(1);
+    let (future, past) = patterns.relative[width][unit.index()];
+    let pattern = if value < 0 { past } else { future };
+    apply(lang, pattern, value.unsigned_abs())
+}
+
+/// Format a number of seconds relative to now in the most fitting unit, e.g.
+/// "3 minutes ago", "yesterday" or "in 2 months".
+///
+/// Seconds are rounded to minutes from 45 seconds, to hours from 45 minutes, to days from
+/// 22 hours, to weeks from 7 days, to months from 26 days and to years from 320 days.
+/// A day before or after now is "yesterday" or "tomorrow", and less than a second "now".
+///
+/// # Example
+///
+/// ```rust
+/// use lingua_i18n_rs::relative::{format_relative_seconds, Width};
+///
+/// assert_eq!(format_relative_seconds("en", -180, Width::Long), "3 minutes ago");
+/// assert_eq!(format_relative_seconds("en", 86_400, Width::Long), "tomorrow");
+/// assert_eq!(format_relative_seconds("fr", -7_200, Width::Short), "il y a 2 h");
+/// ```
+pub fn format_relative_seconds(lang: &str, seconds: i64, width: Width) -> String {
+    const MINUTE: f64 = 60.0;
+    const HOUR: f64 = 3600.0;
+    const DAY: f64 = 86_400.0;
+    const WEEK: f64 = 7.0 * DAY;
+    const MONTH: f64 = 30.436_875 * DAY;
+    const YEAR: f64 = 365.2425 * DAY;
+
+    let patterns = patterns(lang);
+    let abs = seconds.unsigned_abs() as f64;
+    let (unit, size) = if abs < 45.0 {
+        (RelativeUnit::Second, 1.0)
+    } else if abs < 45.0 * MINUTE {
+        (RelativeUnit::Minute, MINUTE)
+    } else if abs < 22.0 * HOUR {
+        (RelativeUnit::Hour, HOUR)
+    } else if abs < 7.0 * DAY {
+        (RelativeUnit::Day, DAY)
+    } else if abs < 26.0 * DAY {
+        (RelativeUnit::Week, WEEK)
+    } else if abs < 320.0 * DAY {
+        (RelativeUnit::Month, MONTH)
+    } else {
+        (RelativeUnit::Year, YEAR)
+    };
+
+    let value = (abs / size).round() as i64 * seconds.signum();
+    match (unit, value) {
+        (RelativeUnit::Second, 0) => patterns.now.to_string(),
+        (RelativeUnit::Day, -1) => patterns.yesterday.to_string(),
+        (RelativeUnit::Day, 1) => patterns.tomorrow.to_string(),
+        _ => format_relative(lang, value, unit, width),
+    }
+}
+
+/// Format a duration in days, hours, minutes and seconds, leaving out zero units, e.g.
+/// "1 hour, 20 minutes", "1 hr, 20 min" or "1h 20m". Fractions of a second are ignored.
+///
+/// # Example
+///
+/// ```rust
+/// use lingua_i18n_rs::relative::{format_duration, Width};
+/// use std::time::Duration;
+///
+/// let duration = Duration::from_secs(4_800);
+/// assert_eq!(format_duration("en", duration, Width::Long), "1 hour, 20 minutes");
+/// assert_eq!(format_duration("en", duration, Width::Narrow), "1h 20m");
+/// assert_eq!(format_duration("de", duration, Width::Long), "1 Stunde und 20 Minuten");
+/// ```
+pub fn format_duration(lang: &str, duration: Duration, width: Width) -> String {
+    let patterns = patterns(lang);
+    let units = &patterns.units[width.index()];
+    let seconds = duration.as_secs();
+    let parts = [
+        seconds / 86_400,
+        seconds / 3600 % 24,
+        seconds / 60 % 60,
+        seconds % 60,
+    ];
+
+    let mut formatted: Vec<String> = parts
+        .iter()
+        .zip(units)
+        .filter(|(value, _)| **value > 0)
+        .map(|(value, pattern)| apply(lang, pattern, *value))
+        .collect();
+    if formatted.is_empty() {
+        formatted.push(apply(lang, units[3], 0));
+    }
+
+    list::format_list(lang, &formatted, ListType::Unit, width)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_relative() {
+        assert_eq!(
+            format_relative("en", 1, RelativeUnit::Day, Width::Long),
+            "in 1 day"
+        );
+        assert_eq!(
+            format_relative("pl", -5, RelativeUnit::Minute, Width::Long),
+            "5 minut temu"
+        );
+        assert_eq!(
+            format_relative("pl", 22, RelativeUnit::Year, Width::Long),
+            "za 22 lata"
+        );
+        assert_eq!(
+            format_relative("ru", -21, RelativeUnit::Hour, Width::Long),
+            "21 час назад"
+        );
+        assert_eq!(
+            format_relative("fr-CA", 0, RelativeUnit::Day, Width::Long),
+            "dans 0 jour"
+        );
+        assert_eq!(
+            format_relative("xx", 2, RelativeUnit::Week, Width::Narrow),
+            "in 2 wk."
+        );
+    }
+
+    #[test]
+    fn test_relative_seconds() {
+        assert_eq!(format_relative_seconds("en", 0, Width::Long), "now");
+        assert_eq!(
+            format_relative_seconds("en", 30, Width::Long),
+            "in 30 seconds"
+        );
+        assert_eq!(
+            format_relative_seconds("en", -3_000, Width::Long),
+            "1 hour ago"
+        );
+        assert_eq!(
+            format_relative_seconds("de", -90_000, Width::Long),
+            "gestern"
+        );
+        assert_eq!(
+            format_relative_seconds("en", 10 * 86_400, Width::Long),
+            "in 1 week"
+        );
+        assert_eq!(
+            format_relative_seconds("en", -60 * 86_400, Width::Short),
+            "2 mo. ago"
+        );
+        assert_eq!(
+            format_relative_seconds("es", -800 * 86_400, Width::Long),
+            "hace 2 años"
+        );
+    }
+
+    #[test]
+    fn test_duration() {
+        let duration = Duration::from_secs(90_061);
+        assert_eq!(
+            format_duration("en", duration, Width::Long),
+            "1 day, 1 hour, 1 minute, 1 second"
+        );
+        assert_eq!(
+            format_duration("en", duration, Width::Short),
+            "1 day, 1 hr, 1 min, 1 sec"
+        );
+        assert_eq!(
+            format_duration("ja", duration, Width::Narrow),
+            "1日1時間1分1秒"
+        );
+        assert_eq!(
+            format_duration("ru", Duration::from_secs(7_500), Width::Long),
+            "2 часа и 5 минут"
+        );
+        assert_eq!(
+            format_duration("en", Duration::ZERO, Width::Long),
+            "0 seconds"
+        );
+    }
+}
@@ -0,0 +1,692 @@
+//! Measurement unit formatting and measurement systems.
+//!
+//! Distances, temperatures, byte sizes and speeds are formatted with the CLDR unit patterns
+//! of the language, in the plural form the number requires: "5 km", "5 Kilometer",
+//! "3,2 MB". Common languages are built in and matched by their full tag first (e.g.
+//! `en-GB`), then by their primary language subtag; unknown languages use the English
+//! patterns. [`measurement_system`] tells whether a language prefers metric, US or UK units.
+//!
+//! In translation strings, `{{name, unit:kilometer}}` formats a number in a unit, optionally
+//! followed by a width (`{{name, unit:kilometer-per-hour-long}}`); placeholders use the short
+//! width by default. ICU messages accept the skeleton `{name, number, ::unit/kilometer}`
+//! with the `unit-width-full-name` and `unit-width-narrow` options.
+use crate::locale::{Locale, find_locale};
+use crate::number::{self, NumberStyle};
+use crate::plural::{self, PluralCategory, PluralOperands};
+use crate::relative;
+pub use crate::relative::Width;
+use std::fmt;
+use std::str::FromStr;
+
+/// A unit of measurement.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MeasureUnit {
+    Centimeter,
+    Meter,
+    Kilometer,
+    Inch,
+    Foot,
+    Mile,
+    Celsius,
+    Fahrenheit,
+    Byte,
+    Kilobyte,
+    Megabyte,
+    Gigabyte,
+    Terabyte,
+    MeterPerSecond,
+    KilometerPerHour,
+    MilePerHour,
+}
+
+const UNITS: usize = 16;
+
+/// Unit identifiers, in the order of [`MeasureUnit`].
+const IDENTIFIERS: [&str; UNITS] = [
+    "centimeter",
+    "meter",
+    "kilometer",
+    "inch",
+    "foot",
+    "mile",
+    "celsius",
+    "fahrenheit",
+    "byte",
+    "kilobyte",
+    "megabyte",
+    "gigabyte",
+    "terabyte",
+    "meter-per-second",
+    "kilometer-per-hour",
+    "mile-per-hour",
+];
+
+const ALL: [MeasureUnit; UNITS] = [
+    MeasureUnit::Centimeter,
+    MeasureUnit::Meter,
+    MeasureUnit::Kilometer,
+    MeasureUnit::Inch,
+    MeasureUnit::Foot,
+    MeasureUnit::Mile,
+    MeasureUnit::Celsius,
+    MeasureUnit::Fahrenheit,
+    MeasureUnit::Byte,
+    MeasureUnit::Kilobyte,
+    MeasureUnit::Megabyte,
+    MeasureUnit::Gigabyte,
+    MeasureUnit::Terabyte,
+    MeasureUnit::MeterPerSecond,
+    MeasureUnit::KilometerPerHour,
+    MeasureUnit::MilePerHour,
+];
+
+impl MeasureUnit {
+    /// The CLDR identifier of the unit, e.g. `kilometer-per-hour`.
+    pub fn as_str(&self) -> &'static str {
+        IDENTIFIERS[self.index()]
+    }
+
+    /// Parse a CLDR unit identifier, with or without its type (`length-kilometer`).
+    pub fn from_keyword(keyword: &str) -> Option<Self> {
+        let keyword = keyword.trim();
+        let keyword = ["length-", "temperature-", "digital-", "speed-"]
+            .iter()
+            .find_map(|prefix| keyword.strip_prefix(prefix))
+            .unwrap_or(keyword);
+        IDENTIFIERS
+            .iter()
+            .position(|identifier| *identifier == keyword)
+            .map(|index| ALL[index])
+    }
+
+    fn index(self) -> usize {
+        self as usize
+    }
+}
+
+impl fmt::Display for MeasureUnit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for MeasureUnit {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_keyword(s).ok_or(())
+    }
+}
+
+/// The system of units a language prefers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MeasurementSystem {
+    #[default]
+    Metric,
+    /// US customary units: miles, feet, degrees Fahrenheit.
+    Us,
+    /// Imperial units for distances and speeds, metric units otherwise.
+    Uk,
+}
+
+impl MeasurementSystem {
+    /// The CLDR name of the system, as used by the `-u-ms-` locale extension.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MeasurementSystem::Metric => "metric",
+            MeasurementSystem::Us => "ussystem",
+            MeasurementSystem::Uk => "uksystem",
+        }
+    }
+}
+
+impl fmt::Display for MeasurementSystem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Find the measurement system of a language from its region, or from the region it is
+/// most likely spoken in if it has none. A `-u-ms-` extension overrides the region.
+///
+/// # Example
+///
+/// ```rust
+/// use lingua_i18n_rs::unit::{measurement_system, MeasurementSystem};
+///
+/// assert_eq!(measurement_system("en"), MeasurementSystem::Us);
+/// assert_eq!(measurement_system("en-GB"), MeasurementSystem::Uk);
+/// assert_eq!(measurement_system("es_US"), MeasurementSystem::Us);
+/// assert_eq!(measurement_system("de"), MeasurementSystem::Metric);
+/// assert_eq!(measurement_system("en-US-u-ms-metric"), MeasurementSystem::Metric);
+/// ```
+pub fn measurement_system(lang: &str) -> MeasurementSystem {
+    let Ok(locale) = Locale::parse(lang) else {
+        return MeasurementSystem::Metric;
+    };
+    match locale.keyword("ms") {
+        Some("metric") => return MeasurementSystem::Metric,
+        Some("ussystem") => return MeasurementSystem::Us,
+        Some("uksystem") => return MeasurementSystem::Uk,
+        _ => {}
+    }
+
+    let region = locale
+        .region
+        .as_deref()
+        .unwrap_or(match locale.language.as_str() {
+            "en" => "US",
+            "my" => "MM",
+            _ => "",
+        });
+    match region {
+        "US" | "LR" | "MM" | "PR" => MeasurementSystem::Us,
+        "GB" => MeasurementSystem::Uk,
+        _ => MeasurementSystem::Metric,
+    }
+}
+
+/// Unit patterns by width and unit.
+///
+/// Patterns hold `{0}` for the number and are either a single pattern for all plural
+/// categories or `category:pattern` alternatives separated by `|`.
+type Patterns = [[&'static str; UNITS]; 3];
+
+/// Short patterns shared by most languages that write units with Latin symbols.
+const SHORT: [&str; UNITS] = [
+    "{0} cm", "{0} m", "{0} km", "{0} in", "{0} ft", "{0} mi", "{0} °C", "{0} °F", "{0} B",
+    "{0} kB", "{0} MB", "{0} GB", "{0} TB", "{0} m/s", "{0} km/h", "{0} mi/h",
+];
+
+const NARROW: [&str; UNITS] = [
+    "{0}cm", "{0}m", "{0}km", "{0}in", "{0}ft", "{0}mi", "{0}°C", "{0}°F", "{0}B", "{0}kB",
+    "{0}MB", "{0}GB", "{0}TB", "{0}m/s", "{0}km/h", "{0}mi/h",
+];
+
+const EN: Patterns = [
+    [
+        "one:{0} centimeter|other:{0} centimeters",
+        "one:{0} meter|other:{0} meters",
+        "one:{0} kilometer|other:{0} kilometers",
+        "one:{0} inch|other:{0} inches",
+        "one:{0} foot|other:{0} feet",
+        "one:{0} mile|other:{0} miles",
+        "one:{0} degree Celsius|other:{0} degrees Celsius",
+        "one:{0} degree Fahrenheit|other:{0} degrees Fahrenheit",
+        "one:{0} byte|other:{0} bytes",
+        "one:{0} kilobyte|other:{0} kilobytes",
+        "one:{0} megabyte|other:{0} megabytes",
+        "one:{0} gigabyte|other:{0} gigabytes",
+        "one:{0} terabyte|other:{0} terabytes",
+        "one:{0} meter per second|other:{0} meters per second",
+        "one:{0} kilometer per hour|other:{0} kilometers per hour",
+        "one:{0} mile per hour|other:{0} miles per hour",
+    ],
+    [
+        "{0} cm", "{0} m", "{0} km", "{0} in", "{0} ft", "{0} mi", "{0}°C", "{0}°F", "{0} byte",
+        "{0} kB", "{0} MB", "{0} GB", "{0} TB", "{0} m/s", "{0} km/h", "{0} mph",
+    ],
+    [
+        "{0}cm", "{0}m", "{0}km", "{0}″", "{0}′", "{0}mi", "{0}°C", "{0}°", "{0}B", "{0}kB",
+        "{0}MB", "{0}GB", "{0}TB", "{0}m/s", "{0}km/h", "{0}mph",
+    ],
+];
+
+const EN_GB: Patterns = [
+    [
+        "one:{0} centimetre|other:{0} centimetres",
+        "one:{0} metre|other:{0} metres",
+        "one:{0} kilometre|other:{0} kilometres",
+        EN[0][3],
+        EN[0][4],
+        EN[0][5],
+        EN[0][6],
+        EN[0][7],
+        EN[0][8],
+        EN[0][9],
+        EN[0][10],
+        EN[0][11],
+        EN[0][12],
+        "one:{0} metre per second|other:{0} metres per second",
+        "one:{0} kilometre per hour|other:{0} kilometres per hour",
+        EN[0][15],
+    ],
+    EN[1],
+    EN[2],
+];
+
+/// Built-in languages, by lowercase tag.
+const LOCALES: &[(&str, Patterns)] = &[
+    (
+        "de",
+        [
+            [
+                "{0} Zentimeter",
+                "{0} Meter",
+                "{0} Kilometer",
+                "{0} Zoll",
+                "{0} Fuß",
+                "one:{0} Meile|other:{0} Meilen",
+                "{0} Grad Celsius",
+                "{0} Grad Fahrenheit",
+                "{0} Byte",
+                "{0} Kilobyte",
+                "{0} Megabyte",
+                "{0} Gigabyte",
+                "{0} Terabyte",
+                "{0} Meter pro Sekunde",
+                "{0} Kilometer pro Stunde",
+                "one:{0} Meile pro Stunde|other:{0} Meilen pro Stunde",
+            ],
+            [
+                "{0} cm", "{0} m", "{0} km", "{0} Zoll", "{0} ft", "{0} mi", "{0} °C", "{0} °F",
+                "{0} Byte", "{0} kB", "{0} MB", "{0} GB", "{0} TB", "{0} m/s", "{0} km/h",
+                "{0} mi/h",
+            ],
+            NARROW,
+        ],
+    ),
+    ("en", EN),
+    ("en-au", EN_GB),
+    ("en-gb", EN_GB),
+    ("en-ie", EN_GB),
+    ("en-nz", EN_GB),
+    (
+        "es",
+        [
+            [
+                "one:{0} centímetro|other:{0} centímetros",
+                "one:{0} metro|other:{0} metros",
+                "one:{0} kilómetro|other:{0} kilómetros",
+                "one:{0} pulgada|other:{0} pulgadas",
+                "one:{0} pie|other:{0} pies",
+                "one:{0} milla|other:{0} millas",
+                "one:{0} grado Celsius|other:{0} grados Celsius",
+                "one:{0} grado Fahrenheit|other:{0} grados Fahrenheit",
+                "one:{0} byte|other:{0} bytes",
+                "one:{0} kilobyte|other:{0} kilobytes",
+                "one:{0} megabyte|other:{0} megabytes",
+                "one:{0} gigabyte|other:{0} gigabytes",
+                "one:{0} terabyte|other:{0} terabytes",
+                "one:{0} metro por segundo|other:{0} metros por segundo",
+                "one:{0} kilómetro por hora|other:{0} kilómetros por hora",
+                "one:{0} milla por hora|other:{0} millas por hora",
+            ],
+            SHORT,
+            NARROW,
+        ],
+    ),
+    (
+        "fr",
+        [
+            [
+                "one:{0} centimètre|other:{0} centimètres",
+                "one:{0} mètre|other:{0} mètres",
+                "one:{0} kilomètre|other:{0} kilomètres",
+                "one:{0} pouce|other:{0} pouces",
+                "one:{0} pied|other:{0} pieds",
+                "one:{0} mile|other:{0} miles",
+                "one:{0} degré Celsius|other:{0} degrés Celsius",
+                "one:{0} degré Fahrenheit|other:{0} degrés Fahrenheit",
+                "one:{0} octet|other:{0} octets",
+                "one:{0} kilooctet|other:{0} kilooctets",
+                "one:{0} mégaoctet|other:{0} mégaoctets",
+                "one:{0} gigaoctet|other:{0} gigaoctets",
+                "one:{0} téraoctet|other:{0} téraoctets",
+                "one:{0} mètre par seconde|other:{0} mètres par seconde",
+                "one:{0} kilomètre à l’heure|other:{0} kilomètres à l’heure",
+                "one:{0} mile à l’heure|other:{0} miles à l’heure",
+            ],
+            [
+                "{0}\u{a0}cm",
+                "{0}\u{a0}m",
+                "{0}\u{a0}km",
+                "{0}\u{a0}po",
+                "{0}\u{a0}pi",
+                "{0}\u{a0}mi",
+                "{0}\u{a0}°C",
+                "{0}\u{a0}°F",
+                "{0}\u{a0}o",
+                "{0}\u{a0}ko",
+                "{0}\u{a0}Mo",
+                "{0}\u{a0}Go",
+                "{0}\u{a0}To",
+                "{0}\u{a0}m/s",
+                "{0}\u{a0}km/h",
+                "{0}\u{a0}mi/h",
+            ],
+            [
+                "{0}cm", "{0}m", "{0}km", "{0}po", "{0}pi", "{0}mi", "{0}°C", "{0}°F", "{0}o",
+                "{0}ko", "{0}Mo", "{0}Go", "{0}To", "{0}m/s", "{0}km/h", "{0}mi/h",
+            ],
+        ],
+    ),
+    (
+        "it",
+        [
+            [
+                "one:{0} centimetro|other:{0} centimetri",
+                "one:{0} metro|other:{0} metri",
+                "one:{0} chilometro|other:{0} chilometri",
+                "one:{0} pollice|other:{0} pollici",
+                "one:{0} piede|other:{0} piedi",
+                "one:{0} miglio|other:{0} miglia",
+                "one:{0} grado Celsius|other:{0} gradi Celsius",
+                "one:{0} grado Fahrenheit|other:{0} gradi Fahrenheit",
+                "{0} byte",
+                "{0} kilobyte",
+                "{0} megabyte",
+                "{0} gigabyte",
+                "{0} terabyte",
+                "one:{0} metro al secondo|other:{0} metri al secondo",
+                "one:{0} chilometro orario|other:{0} chilometri orari",
+                "one:{0} miglio orario|other:{0} miglia orarie",
+            ],
+            SHORT,
+            NARROW,
+        ],
+    ),
+    (
+        "ja",
+        [
+            [
+                "{0} センチメートル",
+                "{0} メートル",
+                "{0} キロメートル",
+                "{0} インチ",
+                "{0} フィート",
+                "{0} マイル",
+                "摂氏 {0} 度",
+                "華氏 {0} 度",
+                "{0} バイト",
+                "{0} キロバイト",
+                "{0} メガバイト",
+                "{0} ギガバイト",
+                "{0} テラバイト",
+                "秒速 {0} メートル",
+                "時速 {0} キロメートル",
+                "時速 {0} マイル",
+            ],
+            [
+                "{0} cm",
+                "{0} m",
+                "{0} km",
+                "{0} in",
+                "{0} ft",
+                "{0} マイル",
+                "{0}°C",
+                "{0}°F",
+                "{0} byte",
+                "{0} KB",
+                "{0} MB",
+                "{0} GB",
+                "{0} TB",
+                "{0} m/s",
+                "{0} km/h",
+                "{0} mph",
+            ],
+            NARROW,
+        ],
+    ),
+    (
+        "nl",
+        [
+            [
+                "{0} centimeter",
+                "{0} meter",
+                "{0} kilometer",
+                "{0} inch",
+                "{0} voet",
+                "{0} mijl",
+                "one:{0} graad Celsius|other:{0} graden Celsius",
+                "one:{0} graad Fahrenheit|other:{0} graden Fahrenheit",
+                "{0} byte",
+                "{0} kilobyte",
+                "{0} megabyte",
+                "{0} gigabyte",
+                "{0} terabyte",
+                "{0} meter per seconde",
+                "{0} kilometer per uur",
+                "{0} mijl per uur",
+            ],
+            SHORT,
+            NARROW,
+        ],
+    ),
+    (
+        "pt",
+        [
+            [
+                "one:{0} centímetro|other:{0} centímetros",
+                "one:{0} metro|other:{0} metros",
+                "one:{0} quilômetro|other:{0} quilômetros",
+                "one:{0} polegada|other:{0} polegadas",
+                "one:{0} pé|other:{0} pés",
+                "one:{0} milha|other:{0} milhas",
+                "one:{0} grau Celsius|other:{0} graus Celsius",
+                "one:{0} grau Fahrenheit|other:{0} graus Fahrenheit",
+                "one:{0} byte|other:{0} bytes",
+                "one:{0} kilobyte|other:{0} kilobytes",
+                "one:{0} megabyte|other:{0} megabytes",
+                "one:{0} gigabyte|other:{0} gigabytes",
+                "one:{0} terabyte|other:{0} terabytes",
+                "one:{0} metro por segundo|other:{0} metros por segundo",
+                "one:{0} quilômetro por hora|other:{0} quilômetros por hora",
+                "one:{0} milha por hora|other:{0} milhas por hora",
+            ],
+            SHORT,
+            NARROW,
+        ],
+    ),
+    (
+        "ru",
+        [
+            [
+                "one:{0} сантиметр|few:{0} сантиметра|many:{0} сантиметров|other:{0} сантиметра",
+                "one:{0} метр|few:{0} метра|many:{0} метров|other:{0} метра",
+                "one:{0} километр|few:{0} километра|many:{0} километров|other:{0} километра",
+                "one:{0} дюйм|few:{0} дюйма|many:{0} дюймов|other:{0} дюйма",
+                "one:{0} фут|few:{0} фута|many:{0} футов|other:{0} фута",
+                "one:{0} миля|few:{0} мили|many:{0} миль|other:{0} мили",
+                "one:{0} градус Цельсия|few:{0} градуса Цельсия|many:{0} градусов Цельсия|other:{0} градуса Цельсия",
+                "one:{0} градус Фаренгейта|few:{0} градуса Фаренгейта|many:{0} градусов Фаренгейта|other:{0} градуса Фаренгейта",
+                "one:{0} байт|few:{0} байта|many:{0} байт|other:{0} байта",
+                "one:{0} килобайт|few:{0} килобайта|many:{0} килобайт|other:{0} килобайта",
+                "one:{0} мегабайт|few:{0} мегабайта|many:{0} мегабайт|other:{0} мегабайта",
+                "one:{0} гигабайт|few:{0} гигабайта|many:{0} гигабайт|other:{0} гигабайта",
+                "one:{0} терабайт|few:{0} терабайта|many:{0} терабайт|other:{0} терабайта",
+                "one:{0} метр в секунду|few:{0} метра в секунду|many:{0} метров в секунду|other:{0} метра в секунду",
+                "one:{0} километр в час|few:{0} километра в час|many:{0} километров в час|other:{0} километра в час",
+                "one:{0} миля в час|few:{0} мили в час|many:{0} миль в час|other:{0} мили в час",
+            ],
+            [
+                "{0} см",
+                "{0} м",
+                "{0} км",
+                "{0} дюйм.",
+                "{0} фт",
+                "{0} мили",
+                "{0} °C",
+                "{0} °F",
+                "{0} Б",
+                "{0} кБ",
+                "{0} МБ",
+                "{0} ГБ",
+                "{0} ТБ",
+                "{0} м/с",
+                "{0} км/ч",
+                "{0} ми/ч",
+            ],
+            [
+                "{0}см",
+                "{0}м",
+                "{0}км",
+                "{0}″",
+                "{0}′",
+                "{0}мили",
+                "{0}°",
+                "{0}°F",
+                "{0}Б",
+                "{0}кБ",
+                "{0}МБ",
+                "{0}ГБ",
+                "{0}ТБ",
+                "{0}м/с",
+                "{0}км/ч",
+                "{0}ми/ч",
+            ],
+        ],
+    ),
+    (
+        "zh",
+        [
+            [
+                "{0}厘米",
+                "{0}米",
+                "{0}公里",
+                "{0}英寸",
+                "{0}英尺",
+                "{0}英里",
+                "{0}摄氏度",
+                "{0}华氏度",
+                "{0}字节",
+                "{0}千字节",
+                "{0}兆字节",
+                "{0}吉字节",
+                "{0}太字节",
+                "{0}米每秒",
+                "{0}公里每小时",
+                "{0}英里每小时",
+            ],
+            [
+                "{0}厘米",
+                "{0}米",
+                "{0}公里",
+                "{0}英寸",
+                "{0}英尺",
+                "{0}英里",
+                "{0}°C",
+                "{0}°F",
+                "{0} byte",
+                "{0} kB",
+                "{0} MB",
+                "{0} GB",
+                "{0} TB",
+                "{0}米/秒",
+                "{0}公里/小时",
+                "{0}英里/小时",
+            ],
+            [
+                "{0}cm",
+                "{0}m",
+                "{0}km",
+                "{0}″",
+                "{0}′",
+                "{0}英里",
+                "{0}°C",
+                "{0}°F",
+                "{0}B",
+                "{0}kB",
+                "{0}MB",
+                "{0}GB",
+                "{0}TB",
+                "{0}m/s",
+                "{0}km/h",
+                "{0}mph",
+            ],
+        ],
+    ),
+];
+
+/// Find the patterns of a language.
+fn patterns(lang: &str) -> &'static Patterns {
+    find_locale(lang, LOCALES, &EN)
+}
+
+/// Format a number in a unit for a language.
+///
+/// The number is formatted like [`NumberStyle::Decimal`], with up to three fraction digits.
+///
+/// # Arguments
+///
+/// * `lang` - A language code, e.g. `"de"`.
+/// * `value` - The number of units.
+/// * `unit` - The unit of `value`.
+/// * `width` - The width of the pattern.
+///
+/// # Example
+///
+/// ```rust
+/// use lingua_i18n_rs::unit::{format_unit, MeasureUnit, Width};
+///
+/// assert_eq!(format_unit("en", 5.0, MeasureUnit::Kilometer, Width::Short), "5 km");
+/// assert_eq!(format_unit("de", 5.0, MeasureUnit::Kilometer, Width::Long), "5 Kilometer");
+/// assert_eq!(format_unit("de", 3.2, MeasureUnit::Megabyte, Width::Short), "3,2 MB");
+/// assert_eq!(format_unit("en", 1.0, MeasureUnit::Foot, Width::Long), "1 foot");
+/// ```
+pub fn format_unit(lang: &str, value: f64, unit: MeasureUnit, width: Width) -> String {
+    let pattern = patterns(lang)[width.index()][unit.index()];
+    let category = number::fixed(value.abs(), 3)
+        .parse::<PluralOperands>()
+        .map(|operands| plural::cardinal(lang, &operands))
+        .unwrap_or(PluralCategory::Other);
+    relative::plural_form(pattern, category)
+        .replace("{0}", &number::format(lang, value, NumberStyle::Decimal))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_unit() {
+        let long = |lang, value, unit| format_unit(lang, value, unit, Width::Long);
+        assert_eq!(long("en", 2.5, MeasureUnit::Mile), "2.5 miles");
+        assert_eq!(long("en-GB", 1.0, MeasureUnit::Kilometer), "1 kilometre");
+        assert_eq!(long("fr", 1.5, MeasureUnit::Meter), "1,5 mètre");
+        assert_eq!(long("ru", 21.0, MeasureUnit::Kilometer), "21 километр");
+        assert_eq!(long("ru", 5.0, MeasureUnit::Kilometer), "5 километров");
+        assert_eq!(long("ru", 1.5, MeasureUnit::Kilometer), "1,5 километра");
+        assert_eq!(
+            long("ja", 60.0, MeasureUnit::KilometerPerHour),
+            "時速 60 キロメートル"
+        );
+        assert_eq!(
+            format_unit("en", -4.0, MeasureUnit::Celsius, Width::Short),
+            "-4°C"
+        );
+        assert_eq!(
+            format_unit("fr", 700.0, MeasureUnit::Megabyte, Width::Short),
+            "700\u{a0}Mo"
+        );
+        assert_eq!(
+            format_unit("xx", 1200.0, MeasureUnit::Meter, Width::Narrow),
+            "1,200m"
+        );
+    }
+
+    #[test]
+    fn test_measurement_system() {
+        assert_eq!(measurement_system("en_US"), MeasurementSystem::Us);
+        assert_eq!(measurement_system("en-IN"), MeasurementSystem::Metric);
+        assert_eq!(measurement_system("en-Latn-GB"), MeasurementSystem::Uk);
+        assert_eq!(measurement_system("my"), MeasurementSystem::Us);
+        assert_eq!(measurement_system("fr"), MeasurementSystem::Metric);
+        assert_eq!(measurement_system("es-419"), MeasurementSystem::Metric);
+        assert_eq!(
+            measurement_system("de-u-ms-uksystem"),
+            MeasurementSystem::Uk
+        );
+    }
+
+    #[test]
+    fn test_unit_keywords() {
+        assert_eq!(
+            MeasureUnit::from_keyword("speed-kilometer-per-hour"),
+            Some(MeasureUnit::KilometerPerHour)
+        );
+        assert_eq!("byte".parse(), Ok(MeasureUnit::Byte));
+        assert_eq!(MeasureUnit::from_keyword("parsec"), None);
+        assert_eq!(MeasureUnit::MilePerHour.to_string(), "mile-per-hour");
+    }
+}
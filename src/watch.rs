@@ -0,0 +1,158 @@
+//! Change detection for hot reloading, see [`LinguaBuilder::with_hot_reload`].
+//!
+//! [`LinguaBuilder::with_hot_reload`]: crate::prelude::LinguaBuilder::with_hot_reload
+use crate::error::LinguaError;
+use crate::format::CatalogFormat;
+use lingua_i18n_rs_shared::message::is_message;
+use serde_json::{Map, Value};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+/// Callback function type for reload events.
+pub type ReloadCallback = Box<dyn Fn(&ReloadEvent) + Send + Sync>;
+
+/// The changes applied by a hot reload.
+#[derive(Debug, Default)]
+pub struct ReloadEvent {
+    /// The languages whose translations changed.
+    pub languages: BTreeSet<String>,
+    /// The keys that were added, changed or removed in any of these languages.
+    pub keys: BTreeSet<String>,
+    /// Languages whose files failed to load, with the error. Their previous translations
+    /// are kept.
+    pub errors: Vec<(String, LinguaError)>,
+}
+
+impl ReloadEvent {
+    /// Whether the reload neither changed anything nor failed.
+    pub fn is_empty(&self) -> bool {
+        self.languages.is_empty() && self.errors.is_empty()
+    }
+}
+
+/// The modification times of the catalog files in a language directory.
+pub(crate) type Snapshot = HashMap<PathBuf, SystemTime>;
+
+pub(crate) fn snapshot(dir: &Path) -> Snapshot {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return Snapshot::new();
+    };
+    entries
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| {
+            path.extension()
+                .and_then(|ext| ext.to_str())
+                .and_then(CatalogFormat::from_extension)
+                .is_some()
+        })
+        .filter_map(|path| {
+            let modified = fs::metadata(&path).and_then(|meta| meta.modified()).ok()?;
+            Some((path, modified))
+        })
+        .collect()
+}
+
+/// The languages with files that were added, modified or removed between two snapshots.
+pub(crate) fn changed_languages(old: &Snapshot, new: &Snapshot) -> BTreeSet<String> {
+    let modified = new
+        .iter()
+        .filter(|(path, time)| old.get(*path) != Some(time))
+        .map(|(path, _)| path);
+    let removed = old.keys().filter(|path| !new.contains_key(*path));
+
+    modified
+        .chain(removed)
+        .filter_map(|path| path.file_stem()?.to_str().map(str::to_string))
+        .collect()
+}
+
+/// The keys whose messages differ between two catalogs of a language.
+pub(crate) fn changed_keys(old: &Map<String, Value>, new: &Map<String, Value>) -> BTreeSet<String> {
+    let mut old_messages = BTreeMap::new();
+    let mut new_messages = BTreeMap::new();
+    flatten(old, "", &mut old_messages);
+    flatten(new, "", &mut new_messages);
+
+    old_messages
+        .keys()
+        .chain(new_messages.keys())
+        .filter(|key| old_messages.get(*key) != new_messages.get(*key))
+        .cloned()
+        .collect()
+}
+
+/// Collect the messages of a catalog by key. Plural and select objects are messages of
+/// their own; `$value` entries belong to the key of their object.
+fn flatten<'a>(map: &'a Map<String, Value>, prefix: &str, out: &mut BTreeMap<String, &'a Value>) {
+    for (key, value) in map {
+        let path = match (prefix.is_empty(), key.as_str()) {
+            (_, "$value") => prefix.to_string(),
+            (true, _) => key.clone(),
+            (false, _) => format!("{}.{}", prefix, key),
+        };
+        match value {
+            Value::Object(children) if !is_message(children) => flatten(children, &path, out),
+            _ => {
+                out.insert(path, value);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_changed_keys() {
+        let old = json!({
+            "hello": "Hello",
+            "menu": {"file": "File", "edit": "Edit"},
+            "items": {"one": "{{count}} item", "other": "{{count}} items"},
+        });
+        let new = json!({
+            "hello": "Hello",
+            "menu": {"file": "File…", "view": "View"},
+            "items": {"one": "One item", "other": "{{count}} items"},
+        });
+        let keys = changed_keys(old.as_object().unwrap(), new.as_object().unwrap());
+        assert_eq!(
+            keys.into_iter().collect::<Vec<_>>(),
+            vec!["items", "menu.edit", "menu.file", "menu.view"]
+        );
+    }
+
+    #[test]
+    fn test_changed_keys_in_group_with_other() {
+        let old = json!({"menu": {"other": "Other", "file": "File"}});
+        let new = json!({"menu": {"other": "Other", "file": "File…"}});
+        let keys = changed_keys(old.as_object().unwrap(), new.as_object().unwrap());
+        assert_eq!(keys.into_iter().collect::<Vec<_>>(), vec!["menu.file"]);
+    }
+
+    #[test]
+    fn test_changed_languages() {
+        let now = SystemTime::now();
+        let later = now + std::time::Duration::from_secs(1);
+        let old = Snapshot::from([
+            (PathBuf::from("l/en.json"), now),
+            (PathBuf::from("l/de.json"), now),
+            (PathBuf::from("l/fr.ftl"), now),
+        ]);
+        let new = Snapshot::from([
+            (PathBuf::from("l/en.json"), now),
+            (PathBuf::from("l/de.json"), later),
+            (PathBuf::from("l/es.json"), now),
+        ]);
+        assert_eq!(
+            changed_languages(&old, &new)
+                .into_iter()
+                .collect::<Vec<_>>(),
+            vec!["de", "es", "fr"]
+        );
+    }
+}
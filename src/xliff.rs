@@ -0,0 +1,670 @@
+//! XLIFF 1.2 and 2.0 exchange files.
+//!
+//! Units are keyed by their `resname` (1.2) or `name` (2.0) attribute, falling back to the
+//! `id`, and `<group>` elements nest them, so a catalog exported with
+//! [`Lingua::export_xliff`] loads back with the same keys and nesting. Units from other
+//! tools without groups keep their id as a flat key, which lookups also resolve.
+//!
+//! [`Lingua::export_xliff`]: crate::prelude::Lingua::export_xliff
+use quick_xml::Reader;
+use quick_xml::events::{BytesStart, Event};
+use serde_json::{Map, Value};
+use std::fmt;
+
+/// Target states that mark a unit as not translated yet.
+const UNTRANSLATED_STATES: [&str; 3] = ["new", "needs-translation", "initial"];
+
+/// The XLIFF version of a document.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum XliffVersion {
+    /// XLIFF 1.2 (`<trans-unit>` elements).
+    V1_2,
+    /// XLIFF 2.0 (`<unit>` and `<segment>` elements).
+    #[default]
+    V2_0,
+}
+
+/// An error in an XLIFF document.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// Byte offset in the document where the error was detected.
+    pub position: u64,
+    /// Description of the error.
+    pub message: String,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at position {}", self.message, self.position)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// A translation unit.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Unit {
+    /// The key of the unit, one entry per nesting level.
+    pub key: Vec<String>,
+    /// The source text.
+    pub source: String,
+    /// The target text, if the unit has one.
+    pub target: Option<String>,
+    /// The `state` of the target (1.2) or segment (2.0), e.g. `translated` or `final`.
+    pub state: Option<String>,
+    /// Notes attached to the unit.
+    pub notes: Vec<String>,
+}
+
+impl Unit {
+    /// Whether the unit has a non-empty target that is not marked as untranslated.
+    pub fn is_translated(&self) -> bool {
+        self.target
+            .as_ref()
+            .is_some_and(|target| !target.is_empty())
+            && !self
+                .state
+                .as_deref()
+                .is_some_and(|state| UNTRANSLATED_STATES.contains(&state))
+    }
+}
+
+/// An XLIFF document with the units of all its files.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Document {
+    /// The XLIFF version.
+    pub version: XliffVersion,
+    /// The source language.
+    pub source_language: String,
+    /// The target language, if the document has one.
+    pub target_language: Option<String>,
+    /// The translation units in document order.
+    pub units: Vec<Unit>,
+}
+
+/// What the text currently read belongs to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Capture {
+    Source,
+    Target,
+    Note,
+}
+
+impl Document {
+    /// Parse an XLIFF 1.2 or 2.0 document.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use lingua_i18n_rs::xliff::Document;
+    ///
+    /// let document = Document::parse(
+    ///     r#"<xliff version="2.0" srcLang="en" trgLang="de"><file id="f1">
+    ///         <unit id="hello"><segment state="translated">
+    ///             <source>Hello</source><target>Hallo</target>
+    ///         </segment></unit>
+    ///     </file></xliff>"#,
+    /// )?;
+    /// assert_eq!(document.target_catalog()["hello"], "Hallo");
+    /// # Ok::<(), lingua_i18n_rs::xliff::ParseError>(())
+    /// ```
+    pub fn parse(content: &str) -> Result<Self, ParseError> {
+        let mut reader = Reader::from_str(content);
+        let mut document = Document::default();
+        let mut version_found = false;
+        let mut groups: Vec<Option<String>> = Vec::new();
+        let mut unit: Option<Unit> = None;
+        let mut capture: Option<(Capture, usize)> = None;
+        let mut depth = 0usize;
+        let mut text = String::new();
+
+        loop {
+            let position = reader.buffer_position();
+            let error = |message: String| ParseError { position, message };
+            let event = reader.read_event().map_err(|e| error(e.to_string()))?;
+
+            match event {
+                Event::Start(ref element) | Event::Empty(ref element) => {
+                    let empty = matches!(event, Event::Empty(_));
+                    let attr = |name: &str| attribute(element, name).map_err(&error);
+                    match element.local_name().as_ref() {
+                        b"xliff" => {
+                            document.version = match attr("version")?.as_deref() {
+                                Some("1.2") => XliffVersion::V1_2,
+                                Some(v) if v.starts_with('2') => XliffVersion::V2_0,
+                                other => {
+                                    return Err(error(format!(
+                                        "Unsupported XLIFF version {:?}",
+                                        other.unwrap_or_default()
+                                    )));
+                                }
+                            };
+                            version_found = true;
+                            if let Some(lang) = attr("srcLang")? {
+                                document.source_language = lang;
+                            }
+                            document.target_language = attr("trgLang")?;
+                        }
+                        b"file" if document.version == XliffVersion::V1_2 => {
+                            if let Some(lang) = attr("source-language")? {
+                                document.source_language = lang;
+                            }
+                            if let Some(lang) = attr("target-language")? {
+                                document.target_language = Some(lang);
+                            }
+                        }
+                        b"group" if !empty => {
+                            groups.push(name_or_id(element).map_err(&error)?);
+                        }
+                        b"trans-unit" | b"unit" => {
+                            if capture.is_some() {
+                                return Err(error(
+                                    "Unit inside a source, target or note".to_string(),
+                                ));
+                            }
+                            let mut key: Vec<String> = groups.iter().flatten().cloned().collect();
+                            key.push(
+                                name_or_id(element)
+                                    .map_err(&error)?
+                                    .ok_or_else(|| error("Unit without id".to_string()))?,
+                            );
+                            unit = Some(Unit {
+                                key,
+                                ..Unit::default()
+                            });
+                            if empty {
+                                document.units.extend(unit.take());
+                            }
+                        }
+                        name @ (b"source" | b"target" | b"note" | b"segment")
+                            if unit.is_some() && capture.is_none() =>
+                        {
+                            let current = unit.as_mut().expect("checked above");
+                            if matches!(name, b"target" | b"segment")
+                                && let Some(state) = attr("state")?
+                            {
+                                current.state = Some(state);
+                            }
+                            if name == b"target" && !empty {
+                                current.target.get_or_insert_default();
+                            }
+                            let kind = match name {
+                                b"source" => Some(Capture::Source),
+                                b"target" => Some(Capture::Target),
+                                b"note" => Some(Capture::Note),
+                                _ => None,
+                            };
+                            if let Some(kind) = kind
+                                && !empty
+                            {
+                                capture = Some((kind, depth));
+                                text.clear();
+                            }
+                        }
+                        _ => {}
+                    }
+                    if !empty {
+                        depth += 1;
+                    }
+                }
+                Event::End(ref element) => {
+                    depth -= 1;
+                    if let Some((kind, start)) = capture
+                        && start == depth
+                    {
+                        let current = unit
+                            .as_mut()
+                            .ok_or_else(|| error("Text outside of a unit".to_string()))?;
+                        let value = std::mem::take(&mut text);
+                        match kind {
+                            Capture::Source => current.source.push_str(&value),
+                            Capture::Target => {
+                                current.target.get_or_insert_default().push_str(&value)
+                            }
+                            Capture::Note => current.notes.push(value),
+                        }
+                        capture = None;
+                        continue;
+                    }
+                    match element.local_name().as_ref() {
+                        b"group" => {
+                            groups.pop();
+                        }
+                        b"trans-unit" | b"unit" => document.units.extend(unit.take()),
+                        _ => {}
+                    }
+                }
+                Event::Text(content) if capture.is_some() => {
+                    text.push_str(&content.decode().map_err(|e| error(e.to_string()))?);
+                }
+                Event::CData(content) if capture.is_some() => {
+                    text.push_str(&content.decode().map_err(|e| error(e.to_string()))?);
+                }
+                Event::GeneralRef(reference) if capture.is_some() => {
+                    if let Some(c) = reference
+                        .resolve_char_ref()
+                        .map_err(|e| error(e.to_string()))?
+                    {
+                        text.push(c);
+                    } else {
+                        let name = reference.decode().map_err(|e| error(e.to_string()))?;
+                        let value = quick_xml::escape::resolve_predefined_entity(&name)
+                            .ok_or_else(|| error(format!("Unknown entity '&{};'", name)))?;
+                        text.push_str(value);
+                    }
+                }
+                Event::Eof => break,
+                _ => {}
+            }
+        }
+
+        if !version_found {
+            return Err(ParseError {
+                position: 0,
+                message: "Missing <xliff> root element".to_string(),
+            });
+        }
+
+        Ok(document)
+    }
+
+    /// Build a document from a source catalog and an optional, possibly partial, target
+    /// catalog.
+    ///
+    /// Every key of either catalog becomes a unit; keys without a translation get no
+    /// target and the state `initial` (2.0) or `needs-translation` (1.2).
+    pub fn from_catalogs(
+        version: XliffVersion,
+        source_language: &str,
+        source: &Map<String, Value>,
+        target_language: Option<&str>,
+        target: Option<&Map<String, Value>>,
+    ) -> Self {
+        // The union of both catalogs, in source order.
+        let mut shape = source.clone();
+        if let Some(target) = target {
+            merge_missing(&mut shape, target);
+        }
+
+        let mut units = Vec::new();
+        collect_units(&shape, &mut Vec::new(), source, target, &mut units);
+
+        let untranslated = match version {
+            XliffVersion::V1_2 => "needs-translation",
+            XliffVersion::V2_0 => "initial",
+        };
+        for unit in &mut units {
+            unit.state = Some(
+                if unit.target.is_some() {
+                    "translated"
+                } else {
+                    untranslated
+                }
+                .to_string(),
+            );
+        }
+
+        Document {
+            version,
+            source_language: source_language.to_string(),
+            target_language: target_language.map(str::to_string),
+            units,
+        }
+    }
+
+    /// Get the translated targets as a nested translation map.
+    ///
+    /// Untranslated units are skipped, so the fallback chain applies to them.
+    pub fn target_catalog(&self) -> Map<String, Value> {
+        let mut map = Map::new();
+        for unit in self.units.iter().filter(|unit| unit.is_translated()) {
+            insert_path(&mut map, &unit.key, unit.target.clone().unwrap_or_default());
+        }
+        map
+    }
+
+    /// Get the source texts as a nested translation map.
+    pub fn source_catalog(&self) -> Map<String, Value> {
+        let mut map = Map::new();
+        for unit in &self.units {
+            insert_path(&mut map, &unit.key, unit.source.clone());
+        }
+        map
+    }
+
+    /// Get the catalog of the document's language: the targets of a bilingual document,
+    /// or the sources of a document without a target language.
+    pub fn catalog(&self) -> Map<String, Value> {
+        if self.target_language.is_some() {
+            self.target_catalog()
+        } else {
+            self.source_catalog()
+        }
+    }
+
+    /// Serialize the document, nesting units in `<group>` elements by key.
+    pub fn to_xml(&self) -> String {
+        let mut out = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+        let target_language = self.target_language.as_deref();
+
+        match self.version {
+            XliffVersion::V1_2 => {
+                out.push_str(
+                    "<xliff version=\"1.2\" xmlns=\"urn:oasis:names:tc:xliff:document:1.2\">\n",
+                );
+                out.push_str(&format!(
+                    "  <file original=\"lingua\" datatype=\"plaintext\" source-language=\"{}\"",
+                    escape(&self.source_language)
+                ));
+                if let Some(lang) = target_language {
+                    out.push_str(&format!(" target-language=\"{}\"", escape(lang)));
+                }
+                out.push_str(">\n    <body>\n");
+                let units: Vec<&Unit> = self.units.iter().collect();
+                self.write_units(&mut out, &units, 0, 3);
+                out.push_str("    </body>\n  </file>\n</xliff>\n");
+            }
+            XliffVersion::V2_0 => {
+                out.push_str(&format!(
+                    "<xliff version=\"2.0\" xmlns=\"urn:oasis:names:tc:xliff:document:2.0\" srcLang=\"{}\"",
+                    escape(&self.source_language)
+                ));
+                if let Some(lang) = target_language {
+                    out.push_str(&format!(" trgLang=\"{}\"", escape(lang)));
+                }
+                out.push_str(">\n  <file id=\"lingua\">\n");
+                let units: Vec<&Unit> = self.units.iter().collect();
+                self.write_units(&mut out, &units, 0, 2);
+                out.push_str("  </file>\n</xliff>\n");
+            }
+        }
+
+        out
+    }
+
+    /// Write units whose keys share the first `depth` segments, opening a group for every
+    /// run of units with a common next segment.
+    fn write_units(&self, out: &mut String, units: &[&Unit], depth: usize, indent: usize) {
+        let pad = "  ".repeat(indent);
+        let mut i = 0;
+        while i < units.len() {
+            let unit = units[i];
+            if unit.key.len() <= depth + 1 {
+                self.write_unit(out, unit, &pad);
+                i += 1;
+                continue;
+            }
+
+            let segment = &unit.key[depth];
+            let end = i + units[i..]
+                .iter()
+                .take_while(|other| other.key.len() > depth + 1 && &other.key[depth] == segment)
+                .count();
+            let id = escape(&unit.key[..=depth].join("."));
+            let name_attribute = match self.version {
+                XliffVersion::V1_2 => "resname",
+                XliffVersion::V2_0 => "name",
+            };
+            out.push_str(&format!(
+                "{}<group id=\"{}\" {}=\"{}\">\n",
+                pad,
+                id,
+                name_attribute,
+                escape(segment)
+            ));
+            self.write_units(out, &units[i..end], depth + 1, indent + 1);
+            out.push_str(&format!("{}</group>\n", pad));
+            i = end;
+        }
+    }
+
+    fn write_unit(&self, out: &mut String, unit: &Unit, pad: &str) {
+        let id = escape(&unit.key.join("."));
+        let name = escape(unit.key.last().map(String::as_str).unwrap_or_default());
+        let state = unit
+            .state
+            .as_deref()
+            .map(|state| format!(" state=\"{}\"", escape(state)))
+            .unwrap_or_default();
+
+        match self.version {
+            XliffVersion::V1_2 => {
+                out.push_str(&format!(
+                    "{}<trans-unit id=\"{}\" resname=\"{}\">\n",
+                    pad, id, name
+                ));
+                out.push_str(&format!(
+                    "{}  <source>{}</source>\n",
+                    pad,
+                    escape(&unit.source)
+                ));
+                match &unit.target {
+                    Some(target) => out.push_str(&format!(
+                        "{}  <target{}>{}</target>\n",
+                        pad,
+                        state,
+                        escape(target)
+                    )),
+                    None => out.push_str(&format!("{}  <target{}/>\n", pad, state)),
+                }
+                for note in &unit.notes {
+                    out.push_str(&format!("{}  <note>{}</note>\n", pad, escape(note)));
+                }
+                out.push_str(&format!("{}</trans-unit>\n", pad));
+            }
+            XliffVersion::V2_0 => {
+                out.push_str(&format!("{}<unit id=\"{}\" name=\"{}\">\n", pad, id, name));
+                if !unit.notes.is_empty() {
+                    out.push_str(&format!("{}  <notes>\n", pad));
+                    for note in &unit.notes {
+                        out.push_str(&format!("{}    <note>{}</note>\n", pad, escape(note)));
+                    }
+                    out.push_str(&format!("{}  </notes>\n", pad));
+                }
+                out.push_str(&format!("{}  <segment{}>\n", pad, state));
+                out.push_str(&format!(
+                    "{}    <source>{}</source>\n",
+                    pad,
+                    escape(&unit.source)
+                ));
+                if let Some(target) = &unit.target {
+                    out.push_str(&format!("{}    <target>{}</target>\n", pad, escape(target)));
+                }
+                out.push_str(&format!("{}  </segment>\n{}</unit>\n", pad, pad));
+            }
+        }
+    }
+}
+
+fn attribute(element: &BytesStart<'_>, name: &str) -> Result<Option<String>, String> {
+    element
+        .try_get_attribute(name)
+        .map_err(|e| e.to_string())?
+        .map(|attr| {
+            attr.unescape_value()
+                .map(|value| value.into_owned())
+                .map_err(|e| e.to_string())
+        })
+        .transpose()
+}
+
+/// The key segment of a unit or group: its `resname` or `name`, falling back to its `id`.
+fn name_or_id(element: &BytesStart<'_>) -> Result<Option<String>, String> {
+    for name in ["resname", "name", "id"] {
+        if let Some(value) = attribute(element, name)? {
+            return Ok(Some(value));
+        }
+    }
+    Ok(None)
+}
+
+fn escape(text: &str) -> String {
+    quick_xml::escape::escape(text).into_owned()
+}
+
+/// Add the keys of `source` that are missing in `target`, recursively.
+fn merge_missing(target: &mut Map<String, Value>, source: &Map<String, Value>) {
+    for (key, value) in source {
+        match (target.get_mut(key), value) {
+            (Some(Value::Object(existing)), Value::Object(incoming)) => {
+                merge_missing(existing, incoming)
+            }
+            (Some(_), _) => {}
+            (None, value) => {
+                target.insert(key.clone(), value.clone());
+            }
+        }
+    }
+}
+
+fn collect_units(
+    shape: &Map<String, Value>,
+    path: &mut Vec<String>,
+    source: &Map<String, Value>,
+    target: Option<&Map<String, Value>>,
+    units: &mut Vec<Unit>,
+) {
+    for (key, value) in shape {
+        path.push(key.clone());
+        if let Value::Object(children) = value {
+            collect_units(children, path, source, target, units);
+        } else {
+            units.push(Unit {
+                key: path.clone(),
+                source: leaf_text(source, path).unwrap_or_default(),
+                target: target.and_then(|target| leaf_text(target, path)),
+                ..Unit::default()
+            });
+        }
+        path.pop();
+    }
+}
+
+fn leaf_text(map: &Map<String, Value>, path: &[String]) -> Option<String> {
+    let (last, parents) = path.split_last()?;
+    let mut current = map;
+    for part in parents {
+        current = current.get(part)?.as_object()?;
+    }
+    match current.get(last)? {
+        Value::String(s) => Some(s.clone()),
+        Value::Object(_) => None,
+        other => Some(other.to_string()),
+    }
+}
+
+fn insert_path(map: &mut Map<String, Value>, path: &[String], value: String) {
+    let Some((last, parents)) = path.split_last() else {
+        return;
+    };
+    let mut current = map;
+    for part in parents {
+        let entry = current
+            .entry(part.clone())
+            .or_insert_with(|| Value::Object(Map::new()));
+        if !entry.is_object() {
+            // A message that also has children keeps its text under `$value`.
+            let text = entry.take();
+            *entry = Value::Object(Map::from_iter([("$value".to_string(), text)]));
+        }
+        current = entry.as_object_mut().expect("converted to an object above");
+    }
+    match current.get_mut(last) {
+        Some(Value::Object(children)) => {
+            children.insert("$value".to_string(), Value::String(value));
+        }
+        _ => {
+            current.insert(last.clone(), Value::String(value));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_parse_xliff_1_2() {
+        let document = Document::parse(
+            r#"<?xml version="1.0"?>
+<xliff version="1.2" xmlns="urn:oasis:names:tc:xliff:document:1.2">
+  <file source-language="en" target-language="de" datatype="plaintext" original="app">
+    <body>
+      <group id="menu" resname="menu">
+        <trans-unit id="menu.file" resname="file">
+          <source>File</source>
+          <target state="final">Datei</target>
+          <note>Top-level menu</note>
+        </trans-unit>
+        <trans-unit id="menu.edit" resname="edit">
+          <source>Edit</source>
+          <target state="new">Bearbeiten</target>
+        </trans-unit>
+      </group>
+      <trans-unit id="greeting.hello">
+        <source>Hello &amp; <g id="1">welcome</g></source>
+        <target>Hallo &amp; <g id="1">willkommen</g>&#33;</target>
+      </trans-unit>
+    </body>
+  </file>
+</xliff>"#,
+        )
+        .unwrap();
+
+        assert_eq!(document.version, XliffVersion::V1_2);
+        assert_eq!(document.source_language, "en");
+        assert_eq!(document.target_language.as_deref(), Some("de"));
+        assert_eq!(document.units[0].key, vec!["menu", "file"]);
+        assert_eq!(document.units[0].state.as_deref(), Some("final"));
+        assert_eq!(document.units[0].notes, vec!["Top-level menu"]);
+        assert!(!document.units[1].is_translated());
+
+        let catalog = document.catalog();
+        assert_eq!(catalog["menu"], json!({"file": "Datei"}));
+        assert_eq!(catalog["greeting.hello"], "Hallo & willkommen!");
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let source = json!({
+            "hello": "Hello",
+            "menu": {"file": "File", "edit": "Edit"},
+            "items": {"one": "{{count}} item", "other": "{{count}} items"},
+            "$context": {"button": {"open": "Open"}, "status": {"open": "Open"}},
+        });
+        let target = json!({
+            "menu": {"file": "Datei"},
+            "items": {"one": "{{count}} Element"},
+            "$context": {"button": {"open": "Öffnen"}, "status": {"open": "Offen"}},
+        });
+
+        for version in [XliffVersion::V1_2, XliffVersion::V2_0] {
+            let document = Document::from_catalogs(
+                version,
+                "en",
+                source.as_object().unwrap(),
+                Some("de"),
+                Some(target.as_object().unwrap()),
+            );
+            let parsed = Document::parse(&document.to_xml()).unwrap();
+
+            assert_eq!(parsed, document);
+            assert_eq!(Value::Object(parsed.source_catalog()), source);
+            assert_eq!(Value::Object(parsed.target_catalog()), target);
+        }
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(Document::parse("<root/>").is_err());
+        assert!(Document::parse(r#"<xliff version="3.0"/>"#).is_err());
+        assert!(Document::parse(r#"<xliff version="2.0"><file><unit></file>"#).is_err());
+        assert!(
+            Document::parse(
+                r#"<xliff version="2.0"><file><unit id="a"><segment><source>x<unit id="b"/></source></segment></unit></file></xliff>"#
+            )
+            .is_err()
+        );
+    }
+}
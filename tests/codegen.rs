@@ -0,0 +1,132 @@
+//! Compiles the typed keys generated for `fixtures/keys.json` and, in ICU message format,
+//! for `fixtures/icu_keys.json`.
+//!
+//! Run with `UPDATE_FIXTURES=1` to rewrite the `.rs` fixtures after changing the generator.
+use lingua_i18n_rs::codegen;
+use lingua_i18n_rs::prelude::*;
+use std::path::Path;
+
+#[allow(dead_code)]
+mod keys {
+    include!("fixtures/keys.rs");
+}
+
+#[allow(dead_code)]
+mod icu_keys {
+    include!("fixtures/icu_keys.rs");
+}
+
+fn fixture(name: &str) -> std::path::PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/fixtures")
+        .join(name)
+}
+
+#[test]
+fn test_fixtures_are_up_to_date() {
+    for (name, message_format, expected) in [
+        (
+            "keys",
+            MessageFormat::Simple,
+            include_str!("fixtures/keys.rs"),
+        ),
+        (
+            "icu_keys",
+            MessageFormat::Icu,
+            include_str!("fixtures/icu_keys.rs"),
+        ),
+    ] {
+        let content = std::fs::read_to_string(fixture(&format!("{}.json", name))).unwrap();
+        let catalog: serde_json::Map<String, serde_json::Value> =
+            serde_json::from_str(&content).unwrap();
+        let source = codegen::generate(&catalog, message_format);
+
+        if std::env::var_os("UPDATE_FIXTURES").is_some() {
+            std::fs::write(fixture(&format!("{}.rs", name)), &source).unwrap();
+        }
+        assert_eq!(
+            source, expected,
+            "run the tests with UPDATE_FIXTURES=1 to regenerate fixtures/{}.rs",
+            name
+        );
+    }
+}
+
+#[test]
+fn test_generated_keys_translate() -> Result<(), LinguaError> {
+    let lingua = Lingua::default();
+    lingua.load_translations_from_str(
+        "en",
+        &std::fs::read_to_string(fixture("keys.json")).unwrap(),
+    )?;
+    lingua.set_language("en")?;
+
+    assert_eq!(keys::r#type::SELF.translate(&lingua, ())?, "Self");
+    assert_eq!(keys::super_::SELF.translate(&lingua, ())?, "Super self");
+    assert_eq!(
+        keys::self_::HI.translate(
+            &lingua,
+            keys::self_::HiParams {
+                self_: "Ann".into()
+            }
+        )?,
+        "Hi, Ann"
+    );
+    assert_eq!(keys::_2fa::_404.key(), "2fa.404");
+    assert_eq!(keys::_2fa::__.translate(&lingua, ())?, "Enter the code");
+    assert_eq!(keys::__::__.key(), "_._");
+    assert_eq!(keys::__::___2.key(), "_.__");
+
+    let params = keys::ProfileParams {
+        username: "ann".into(),
+        username_2: "ann".into(),
+    };
+    assert_eq!(
+        keys::PROFILE.translate(&lingua, params)?,
+        "Profile of ann (ann)"
+    );
+    assert_eq!(
+        keys::profile::INVITE.translate(
+            &lingua,
+            keys::profile::InviteParams {
+                gender: "female".into(),
+                r#type: "Bob".into()
+            }
+        )?,
+        "She invited Bob"
+    );
+    Ok(())
+}
+
+#[test]
+fn test_generated_icu_keys_translate() -> Result<(), LinguaError> {
+    let lingua = Lingua::new("languages")
+        .with_message_format(MessageFormat::Icu)
+        .build();
+    lingua.load_translations_from_str(
+        "en",
+        &std::fs::read_to_string(fixture("icu_keys.json")).unwrap(),
+    )?;
+    lingua.set_language("en")?;
+
+    let params = icu_keys::inbox::SummaryParams {
+        count: "3".into(),
+        name: "Ann".into(),
+        sender: "Bob".into(),
+    };
+    assert_eq!(
+        icu_keys::inbox::SUMMARY.translate(&lingua, params)?,
+        "Ann has 3 messages from Bob"
+    );
+    let params = icu_keys::inbox::LegacyParams {
+        guest: "Cy".into(),
+        host: "Bob".into(),
+        name: "Ann".into(),
+    };
+    assert_eq!(
+        icu_keys::inbox::LEGACY.translate(&lingua, params)?,
+        "Ann and {quoted} Cy"
+    );
+    assert_eq!(icu_keys::IT_S.translate(&lingua, ())?, "It's {literal}");
+    Ok(())
+}
@@ -0,0 +1,45 @@
+// Generated by lingua_i18n_rs::codegen. Do not edit.
+#[doc = "Parameters of `greeting`."]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct GreetingParams {
+    pub name: String,
+}
+impl ::lingua_i18n_rs::codegen::Params for GreetingParams {
+    fn to_params(&self) -> Vec<(&'static str, String)> {
+        vec![("name", self.name.clone())]
+    }
+}
+#[doc = " `greeting`: Hello, {name}!"]
+pub const GREETING: ::lingua_i18n_rs::codegen::Key<GreetingParams> = ::lingua_i18n_rs::codegen::Key::new("greeting");
+pub mod inbox {
+    #[doc = "Parameters of `inbox.legacy`."]
+    #[derive(Debug, Clone, Default, PartialEq, Eq)]
+    pub struct LegacyParams {
+        pub guest: String,
+        pub host: String,
+        pub name: String,
+    }
+    impl ::lingua_i18n_rs::codegen::Params for LegacyParams {
+        fn to_params(&self) -> Vec<(&'static str, String)> {
+            vec![("guest", self.guest.clone()), ("host", self.host.clone()), ("name", self.name.clone())]
+        }
+    }
+    #[doc = " `inbox.legacy`: {{name}} and {host, select, other {'{quoted}' {guest}}}"]
+    pub const LEGACY: ::lingua_i18n_rs::codegen::Key<LegacyParams> = ::lingua_i18n_rs::codegen::Key::new("inbox.legacy");
+    #[doc = "Parameters of `inbox.summary`."]
+    #[derive(Debug, Clone, Default, PartialEq, Eq)]
+    pub struct SummaryParams {
+        pub count: String,
+        pub name: String,
+        pub sender: String,
+    }
+    impl ::lingua_i18n_rs::codegen::Params for SummaryParams {
+        fn to_params(&self) -> Vec<(&'static str, String)> {
+            vec![("count", self.count.clone()), ("name", self.name.clone()), ("sender", self.sender.clone())]
+        }
+    }
+    #[doc = " `inbox.summary`: {name} has {count, plural, =0 {no messages} one {# message} other {# messages from {sender}}}"]
+    pub const SUMMARY: ::lingua_i18n_rs::codegen::Key<SummaryParams> = ::lingua_i18n_rs::codegen::Key::new("inbox.summary");
+}
+#[doc = " `it's`: It''s '{literal}'"]
+pub const IT_S: ::lingua_i18n_rs::codegen::Key = ::lingua_i18n_rs::codegen::Key::new("it's");
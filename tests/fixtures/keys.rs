@@ -0,0 +1,93 @@
+// Generated by lingua_i18n_rs::codegen. Do not edit.
+pub mod _2fa {
+    #[doc = " `2fa.404`: Not found"]
+    pub const _404: ::lingua_i18n_rs::codegen::Key = ::lingua_i18n_rs::codegen::Key::new("2fa.404");
+    #[doc = " `2fa._`: Enter the code"]
+    pub const __: ::lingua_i18n_rs::codegen::Key = ::lingua_i18n_rs::codegen::Key::new("2fa._");
+}
+pub mod __ {
+    #[doc = " `_._`: Underscores"]
+    pub const __: ::lingua_i18n_rs::codegen::Key = ::lingua_i18n_rs::codegen::Key::new("_._");
+    #[doc = " `_.__`: More underscores"]
+    pub const ___2: ::lingua_i18n_rs::codegen::Key = ::lingua_i18n_rs::codegen::Key::new("_.__");
+}
+#[doc = " `fn`: Function"]
+pub const FN: ::lingua_i18n_rs::codegen::Key = ::lingua_i18n_rs::codegen::Key::new("fn");
+#[doc = "Parameters of `greeting`."]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct GreetingParams {
+    pub name: String,
+}
+impl ::lingua_i18n_rs::codegen::Params for GreetingParams {
+    fn to_params(&self) -> Vec<(&'static str, String)> {
+        vec![("name", self.name.clone())]
+    }
+}
+#[doc = " `greeting`: Hello, {{name}}!"]
+pub const GREETING: ::lingua_i18n_rs::codegen::Key<GreetingParams> = ::lingua_i18n_rs::codegen::Key::new("greeting");
+#[doc = "Parameters of `profile`."]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ProfileParams {
+    pub username: String,
+    pub username_2: String,
+}
+impl ::lingua_i18n_rs::codegen::Params for ProfileParams {
+    fn to_params(&self) -> Vec<(&'static str, String)> {
+        vec![("userName", self.username.clone()), ("username", self.username_2.clone())]
+    }
+}
+#[doc = " `profile`: Profile of {{userName}} ({{username}})"]
+pub const PROFILE: ::lingua_i18n_rs::codegen::Key<ProfileParams> = ::lingua_i18n_rs::codegen::Key::new("profile");
+pub mod profile {
+    #[doc = "Parameters of `profile.invite`."]
+    #[derive(Debug, Clone, Default, PartialEq, Eq)]
+    pub struct InviteParams {
+        pub gender: String,
+        pub r#type: String,
+    }
+    impl ::lingua_i18n_rs::codegen::Params for InviteParams {
+        fn to_params(&self) -> Vec<(&'static str, String)> {
+            vec![("gender", self.gender.clone()), ("type", self.r#type.clone())]
+        }
+    }
+    #[doc = " `profile.invite`"]
+    pub const INVITE: ::lingua_i18n_rs::codegen::Key<InviteParams> = ::lingua_i18n_rs::codegen::Key::new("profile.invite");
+    #[doc = "Parameters of `profile.items`."]
+    #[derive(Debug, Clone, Default, PartialEq, Eq)]
+    pub struct ItemsParams {
+        pub count: String,
+    }
+    impl ::lingua_i18n_rs::codegen::Params for ItemsParams {
+        fn to_params(&self) -> Vec<(&'static str, String)> {
+            vec![("count", self.count.clone())]
+        }
+    }
+    #[doc = " `profile.items`"]
+    pub const ITEMS: ::lingua_i18n_rs::codegen::Key<ItemsParams> = ::lingua_i18n_rs::codegen::Key::new("profile.items");
+}
+pub mod self_ {
+    #[doc = "Parameters of `self.hi`."]
+    #[derive(Debug, Clone, Default, PartialEq, Eq)]
+    pub struct HiParams {
+        pub self_: String,
+    }
+    impl ::lingua_i18n_rs::codegen::Params for HiParams {
+        fn to_params(&self) -> Vec<(&'static str, String)> {
+            vec![("self", self.self_.clone())]
+        }
+    }
+    #[doc = " `self.hi`: Hi, {{self}}"]
+    pub const HI: ::lingua_i18n_rs::codegen::Key<HiParams> = ::lingua_i18n_rs::codegen::Key::new("self.hi");
+}
+pub mod super_ {
+    #[doc = " `super.Self`: Super self"]
+    pub const SELF: ::lingua_i18n_rs::codegen::Key = ::lingua_i18n_rs::codegen::Key::new("super.Self");
+}
+pub mod r#type {
+    #[doc = " `type.crate`: Crate"]
+    pub const CRATE: ::lingua_i18n_rs::codegen::Key = ::lingua_i18n_rs::codegen::Key::new("type.crate");
+    #[doc = " `type.new`: New"]
+    pub const NEW: ::lingua_i18n_rs::codegen::Key = ::lingua_i18n_rs::codegen::Key::new("type.new");
+    #[doc = " `type.self`: Self"]
+    pub const SELF: ::lingua_i18n_rs::codegen::Key = ::lingua_i18n_rs::codegen::Key::new("type.self");
+}